@@ -0,0 +1,94 @@
+use crate::formatters::TempUnit;
+use crate::health::{HealthIssue, HealthReport, HealthStatus, HealthThresholds, IssueCategory};
+use asic_rs::data::miner::MinerData;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Remembers when each miner last alerted for each issue category so a miner
+/// flapping between healthy and critical doesn't alert on every fetch.
+#[derive(Debug, Default)]
+pub struct AlertTracker {
+    /// Unix seconds of the last alert, per miner and issue category
+    last_alert_time: HashMap<IpAddr, HashMap<IssueCategory, u64>>,
+}
+
+impl AlertTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Critical issues in `miner` that are due an alert, using the current time.
+    pub fn evaluate(
+        &mut self,
+        miner: &MinerData,
+        thresholds: &HealthThresholds,
+        unit: TempUnit,
+        cooldown_seconds: u64,
+    ) -> Vec<HealthIssue> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        HealthReport::from_miner_data(miner, thresholds, unit)
+            .issues
+            .into_iter()
+            .filter(|issue| issue.severity == HealthStatus::Critical)
+            .filter(|issue| self.should_alert(miner.ip, issue.category, cooldown_seconds, now))
+            .collect()
+    }
+
+    /// Records an alert and returns true unless the same miner alerted for
+    /// `category` within the last `cooldown_seconds`.
+    fn should_alert(
+        &mut self,
+        ip: IpAddr,
+        category: IssueCategory,
+        cooldown_seconds: u64,
+        now: u64,
+    ) -> bool {
+        let last_alerts = self.last_alert_time.entry(ip).or_default();
+        match last_alerts.get(&category) {
+            Some(&last) if now.saturating_sub(last) <= cooldown_seconds => false,
+            _ => {
+                last_alerts.insert(category, now);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooldown_suppresses_then_reactivates() {
+        let mut tracker = AlertTracker::new();
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+
+        assert!(tracker.should_alert(ip, IssueCategory::Temperature, 3600, 1_000));
+        // Flapping back to critical within the cooldown stays quiet
+        assert!(!tracker.should_alert(ip, IssueCategory::Temperature, 3600, 2_000));
+        assert!(!tracker.should_alert(ip, IssueCategory::Temperature, 3600, 4_600));
+        // Other issues and other miners have their own cooldowns
+        assert!(tracker.should_alert(ip, IssueCategory::Fans, 3600, 2_000));
+        assert!(tracker.should_alert(
+            "10.0.0.6".parse().unwrap(),
+            IssueCategory::Temperature,
+            3600,
+            2_000
+        ));
+        // Once the cooldown has passed the alert fires again
+        assert!(tracker.should_alert(ip, IssueCategory::Temperature, 3600, 4_601));
+    }
+
+    #[test]
+    fn test_zero_cooldown_always_alerts() {
+        let mut tracker = AlertTracker::new();
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+
+        assert!(tracker.should_alert(ip, IssueCategory::NotMining, 0, 10));
+        assert!(tracker.should_alert(ip, IssueCategory::NotMining, 0, 11));
+    }
+}
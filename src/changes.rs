@@ -0,0 +1,103 @@
+use crate::formatters::{format_hashes_per_second, hashes_per_second};
+use asic_rs::data::miner::MinerData;
+
+/// Relative hashrate difference below which a change is considered noise
+const HASHRATE_CHANGE_THRESHOLD: f64 = 0.10;
+
+/// The identifying values of a miner that are compared between scans
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinerSnapshot {
+    pub model: String,
+    pub firmware: String,
+    pub firmware_version: Option<String>,
+    /// Hashrate in H/s, when the scan collected it
+    pub hashrate: Option<f64>,
+}
+
+impl MinerSnapshot {
+    pub fn from_miner_data(miner: &MinerData) -> Self {
+        Self {
+            model: miner.device_info.model.to_string(),
+            firmware: miner.device_info.firmware.to_string(),
+            firmware_version: miner.firmware_version.clone(),
+            hashrate: miner.hashrate.as_ref().map(hashes_per_second),
+        }
+    }
+}
+
+/// Describes what differs between two snapshots of the same IP, e.g.
+/// "Firmware version: 2024.1 → 2023.9". Empty if nothing notable changed.
+pub fn describe_changes(previous: &MinerSnapshot, current: &MinerSnapshot) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    if previous.model != current.model {
+        changes.push(format!("Model: {} → {}", previous.model, current.model));
+    }
+
+    if previous.firmware != current.firmware {
+        changes.push(format!(
+            "Firmware: {} → {}",
+            previous.firmware, current.firmware
+        ));
+    }
+
+    // Only compare versions both scans actually collected
+    if let (Some(old), Some(new)) = (&previous.firmware_version, &current.firmware_version)
+        && old != new
+    {
+        changes.push(format!("Firmware version: {old} → {new}"));
+    }
+
+    if let (Some(old), Some(new)) = (previous.hashrate, current.hashrate)
+        && old > 0.0
+        && ((new - old) / old).abs() >= HASHRATE_CHANGE_THRESHOLD
+    {
+        changes.push(format!(
+            "Hashrate: {} → {}",
+            format_hashes_per_second(old),
+            format_hashes_per_second(new)
+        ));
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(firmware_version: Option<&str>, hashrate: Option<f64>) -> MinerSnapshot {
+        MinerSnapshot {
+            model: "S19 Pro".to_string(),
+            firmware: "Stock".to_string(),
+            firmware_version: firmware_version.map(str::to_string),
+            hashrate,
+        }
+    }
+
+    #[test]
+    fn test_describe_changes() {
+        let previous = snapshot(Some("2024.1"), Some(100e12));
+
+        assert!(describe_changes(&previous, &previous).is_empty());
+        // Small hashrate drift and missing fields aren't reported
+        assert!(describe_changes(&previous, &snapshot(Some("2024.1"), Some(95e12))).is_empty());
+        assert!(describe_changes(&previous, &snapshot(None, None)).is_empty());
+
+        let changes = describe_changes(&previous, &snapshot(Some("2023.9"), Some(60e12)));
+        assert_eq!(
+            changes,
+            vec![
+                "Firmware version: 2024.1 → 2023.9",
+                "Hashrate: 100.00 TH/s → 60.00 TH/s"
+            ]
+        );
+
+        let mut replaced = previous.clone();
+        replaced.model = "S21".to_string();
+        assert_eq!(
+            describe_changes(&previous, &replaced),
+            vec!["Model: S19 Pro → S21"]
+        );
+    }
+}
@@ -1,18 +1,57 @@
 use crate::errors::{ConfigError, ConfigResult};
+use crate::export;
+use crate::formatters::{TempUnit, hashes_per_second};
+use crate::health::HealthThresholds;
 use crate::network::scanner::ScanConfig;
+use crate::network::{create_miner_factory, validate_network_range};
+use crate::power_schedule::PowerWindow;
+use crate::sorting::{SortColumn, SortDirection};
+use asic_rs::data::device::{MinerFirmware, MinerMake};
 use asic_rs::data::miner::MinerData;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
-use std::path::Path;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::{Duration, SystemTime};
+
+/// Config file location, relative to the working directory
+pub const CONFIG_FILE: &str = "btc_toolkit_config.json";
+
+/// Layout version of the config file written by this build
+pub const CONFIG_SCHEMA_VERSION: u32 = 2;
+
+/// What each schema version changed, shown once after an upgrade
+const SCHEMA_CHANGES: [(u32, &str); 1] = [(
+    2,
+    "Version 2: added schema_version; settings missing from older files were filled in with their defaults",
+)];
+
+/// User agent sent when `custom_user_agent` isn't set
+pub const DEFAULT_USER_AGENT: &str = concat!("btc-toolkit/", env!("CARGO_PKG_VERSION"));
 
 /// Represents a scan group with name, network range, and scan configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScanGroup {
     pub name: String,
     pub network_range: String, // CIDR or range notation
     pub scan_config: ScanConfig,
     pub enabled: bool,
+    /// Non-standard web/API port for this group's miners (None = miner default).
+    ///
+    /// asic-rs probes each make's standard API ports during discovery and has no
+    /// factory option for a custom one, so this currently only affects web UI links.
+    #[serde(default)]
+    pub management_port: Option<u16>,
+    /// Windows that stop, resume or power-limit this group's miners on a
+    /// cron schedule, e.g. for demand response
+    #[serde(default)]
+    pub power_schedule: Vec<PowerWindow>,
+    /// Hardware this group's miners are standardized on
+    #[serde(default)]
+    pub expected_device: DeviceExpectation,
 }
 
 impl ScanGroup {
@@ -22,27 +61,505 @@ impl ScanGroup {
             network_range,
             scan_config: ScanConfig::default(),
             enabled: true,
+            management_port: None,
+            power_schedule: Vec::new(),
+            expected_device: DeviceExpectation::default(),
+        }
+    }
+
+    /// Rough network traffic of scanning this group, in megabytes; 0 for an
+    /// invalid range.
+    pub fn estimated_bandwidth_mb(&self, bytes_per_miner_probe: u64) -> f64 {
+        validate_network_range(&self.network_range)
+            .map(|info| estimated_bandwidth_mb(info.ip_count, bytes_per_miner_probe))
+            .unwrap_or(0.0)
+    }
+
+    /// Serializes this group as a pretty JSON snippet suitable for sharing.
+    pub fn to_snippet(&self) -> ConfigResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| ConfigError::Serialization(e.to_string()))
+    }
+
+    /// Parses and validates a group snippet produced by `to_snippet`.
+    pub fn from_snippet(snippet: &str) -> ConfigResult<Self> {
+        let group: Self = serde_json::from_str(snippet.trim())
+            .map_err(|e| ConfigError::InvalidGroup(format!("not a group definition ({e})")))?;
+
+        if group.name.trim().is_empty() {
+            return Err(ConfigError::InvalidGroup(
+                "group name cannot be empty".to_string(),
+            ));
+        }
+
+        create_miner_factory(&group.network_range)
+            .map_err(|e| ConfigError::InvalidGroup(e.to_string()))?;
+
+        Ok(group)
+    }
+}
+
+/// Make, model and firmware a group is expected to hold. A discovered miner
+/// that differs points at a misplaced or swapped device. Empty fields accept
+/// anything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceExpectation {
+    /// Any of these makes
+    #[serde(default)]
+    pub makes: Vec<MinerMake>,
+    /// Matched case-insensitively against the model name
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Any of these firmwares
+    #[serde(default)]
+    pub firmwares: Vec<MinerFirmware>,
+}
+
+/// Which part of a miner differs from its group's `DeviceExpectation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceMismatch {
+    Make,
+    Model,
+    Firmware,
+}
+
+impl DeviceMismatch {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Make => "make",
+            Self::Model => "model",
+            Self::Firmware => "firmware",
         }
     }
 }
 
+impl DeviceExpectation {
+    pub fn is_empty(&self) -> bool {
+        self.makes.is_empty() && self.model.is_none() && self.firmwares.is_empty()
+    }
+
+    /// Parts of `miner` that don't match, empty when it's as expected.
+    pub fn mismatches(&self, miner: &MinerData) -> Vec<DeviceMismatch> {
+        let info = &miner.device_info;
+        self.mismatches_for(&info.make, &info.model.to_string(), &info.firmware)
+    }
+
+    fn mismatches_for(
+        &self,
+        make: &MinerMake,
+        model: &str,
+        firmware: &MinerFirmware,
+    ) -> Vec<DeviceMismatch> {
+        let mut mismatches = Vec::new();
+        if !self.makes.is_empty() && !self.makes.contains(make) {
+            mismatches.push(DeviceMismatch::Make);
+        }
+        if let Some(expected) = &self.model
+            && !expected.trim().eq_ignore_ascii_case(model.trim())
+        {
+            mismatches.push(DeviceMismatch::Model);
+        }
+        if !self.firmwares.is_empty() && !self.firmwares.contains(firmware) {
+            mismatches.push(DeviceMismatch::Firmware);
+        }
+        mismatches
+    }
+}
+
+/// Megabytes of traffic for probing `ip_count` addresses.
+pub fn estimated_bandwidth_mb(ip_count: usize, bytes_per_miner_probe: u64) -> f64 {
+    ip_count as f64 * bytes_per_miner_probe as f64 / 1_000_000.0
+}
+
 /// Main application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub version: String,
+    /// Layout version of the file; files from before versioning count as 1
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+    /// Summary of the migration applied on load, for a one-time notice
+    #[serde(skip)]
+    pub schema_upgrade_message: Option<String>,
+    /// Show what changed when an app update migrates the config file
+    #[serde(default = "default_schema_upgrade_notify")]
+    pub schema_upgrade_notify: bool,
     pub scan_groups: Vec<ScanGroup>,
     pub last_scan_results: HashMap<String, Vec<MinerData>>, // Group name -> miners
+    /// Write scan results as compact JSON; groups stay pretty-printed for hand editing
+    #[serde(default)]
+    pub compact_results: bool,
+    /// Write scan results to disk. When off, results, scan history and API
+    /// versions only live for the session; scan groups and settings still save.
+    #[serde(default = "default_persist_results")]
+    pub persist_results: bool,
+    /// API versions reported by miners on their last full fetch, keyed by IP
+    #[serde(default)]
+    pub miner_api_versions: HashMap<String, String>,
+    /// Keep rolling copies of the previous file (`<file>.bak.1` is the newest).
+    /// Off unless enabled, so upgrading doesn't start writing files next to the config
+    #[serde(default)]
+    pub backup_on_save: bool,
+    #[serde(default = "default_backup_count")]
+    pub backup_count: usize,
+    /// Per-group scan outcomes, oldest first, capped at `MAX_SCAN_HISTORY` entries
+    #[serde(default)]
+    pub scan_history_summary: HashMap<String, Vec<GroupScanSummary>>,
+    /// Every completed scan run, oldest first, capped at `MAX_SCAN_RUNS`.
+    /// Only metadata, so it's saved even without `persist_results`.
+    #[serde(default)]
+    pub scan_runs: Vec<ScanRun>,
+    /// Hashrate of every miner seen by each completed scan, oldest first,
+    /// capped at `MAX_SCAN_HISTORY` entries
+    #[serde(default)]
+    pub hashrate_history: Vec<ScanRecord>,
+    /// When each miner IP first showed up in a completed scan. Unlike
+    /// `hashrate_history` this isn't capped, so it outlives old scans.
+    #[serde(default)]
+    pub first_seen: HashMap<IpAddr, DateTime<Utc>>,
+    /// Miners found by each group's completed scans, oldest first, capped at
+    /// `MAX_MINER_COUNT_HISTORY` entries
+    #[serde(default)]
+    pub miner_count_history: HashMap<String, VecDeque<u32>>,
+    /// Error of each group whose latest scan failed, cleared by its next
+    /// successful scan. Saved even without `persist_results` so a chronic
+    /// failure shows before the next scan.
+    #[serde(default)]
+    pub last_errors: HashMap<String, String>,
+    #[serde(default)]
+    pub temperature_unit: TempUnit,
+    /// Command template used instead of the system opener, e.g. `wslview %u`
+    #[serde(default)]
+    pub browser_command: Option<String>,
+    /// Typical time for a miner to reboot and resume hashing, for restart impact estimates
+    #[serde(default = "default_average_restart_seconds")]
+    pub average_restart_seconds: u64,
+    /// Typical time for a firmware upgrade to flash and reboot, for upgrade progress
+    #[serde(default = "default_estimated_upgrade_seconds")]
+    pub estimated_upgrade_seconds: u64,
+    /// Health classification thresholds
+    #[serde(default)]
+    pub health_thresholds: HealthThresholds,
+    /// How long the device detail view waits for a miner's full data
+    #[serde(default = "default_detail_fetch_timeout_seconds")]
+    pub detail_fetch_timeout_seconds: u64,
+    /// Minimum time between repeated alerts for the same miner and issue
+    #[serde(default = "default_alert_cooldown_seconds")]
+    pub alert_cooldown_seconds: u64,
+    /// URL that receives a JSON scan summary after every completed scan; off when `None`
+    #[serde(default)]
+    pub completion_webhook: Option<String>,
+    /// Command run after every completed scan with the results JSON on its
+    /// stdin; off when `None`. Runs anything the user enters, so it's opt-in.
+    #[serde(default)]
+    pub post_scan_command: Option<String>,
+    /// Bytes one probed IP costs on the wire, for scan bandwidth estimates
+    #[serde(default = "default_bytes_per_miner_probe")]
+    pub bytes_per_miner_probe: u64,
+    /// User agent sent on HTTP requests instead of `DEFAULT_USER_AGENT`
+    #[serde(default)]
+    pub custom_user_agent: Option<String>,
+    /// Proxy URL for the app's own HTTP requests, e.g. `socks5://jump-host:1080`
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// Serial numbers of test/demo miners kept out of the dashboard and reports
+    #[serde(default)]
+    pub ignored_serial_numbers: HashSet<String>,
+    /// Firmware versions operators have marked end-of-life; an entry also
+    /// covers releases under it, see `is_eol_firmware`
+    #[serde(default)]
+    pub eol_firmwares: Vec<String>,
+    /// Device detail sections the user opened or closed, keyed by section
+    /// name; sections not listed are open
+    #[serde(default)]
+    pub detail_section_states: HashMap<String, bool>,
+    /// Miner table sort chosen on each screen, keyed by screen name so
+    /// screens don't overwrite each other's order
+    #[serde(default)]
+    pub sort_states: HashMap<String, (SortColumn, SortDirection)>,
+    /// Unix minute each power schedule boundary last fired, keyed by
+    /// `DueAction::key`, so a restart doesn't repeat it
+    #[serde(default)]
+    pub power_schedule_fired: HashMap<String, i64>,
+    /// After a scan, expand collapsed groups that contain a Critical miner
+    #[serde(default)]
+    pub expand_problem_groups: bool,
+    /// After a scan, collapse groups whose miners are all Healthy
+    #[serde(default)]
+    pub collapse_healthy_groups: bool,
+    /// Look up PTR names of discovered miners after each group's scan
+    #[serde(default)]
+    pub reverse_dns: bool,
+    /// Raise a notification, in the app and on the desktop, when a scan
+    /// finds its first miner
+    #[serde(default)]
+    pub notify_first_discovery: bool,
+    /// Changelogs keyed by firmware version, loaded from the bundled
+    /// `assets/firmware_changelogs.json` rather than the config file
+    #[serde(skip, default = "bundled_firmware_changelogs")]
+    pub firmware_changelogs: HashMap<String, String>,
+}
+
+/// Scan-derived fields that stay in memory when `persist_results` is off.
+/// All but `last_scan_results` have serde defaults and are simply left out.
+const SESSION_ONLY_FIELDS: [&str; 6] = [
+    "last_scan_results",
+    "scan_history_summary",
+    "miner_api_versions",
+    "hashrate_history",
+    "first_seen",
+    "miner_count_history",
+];
+
+/// A serialized config waiting to be written
+struct PendingSave {
+    content: String,
+    /// Backups to keep when this write backs up the old file
+    backup_count: Option<usize>,
+}
+
+/// Newest unwritten content per config path. A save replaces anything older
+/// still queued for its path, so a burst of saves is written once or twice
+/// rather than once per request.
+static PENDING_SAVES: OnceLock<Mutex<HashMap<PathBuf, PendingSave>>> = OnceLock::new();
+
+/// Held while a save backs up and writes a file, so writes never interleave
+static SAVE_WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+fn pending_saves() -> &'static Mutex<HashMap<PathBuf, PendingSave>> {
+    PENDING_SAVES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Maximum scan summaries kept per group
+const MAX_SCAN_HISTORY: usize = 50;
+
+/// Miner counts kept per group for the dashboard sparkline
+const MAX_MINER_COUNT_HISTORY: usize = 10;
+
+/// Maximum scan runs kept for the history screen
+const MAX_SCAN_RUNS: usize = 500;
+
+/// Outcome of one scan of a group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupScanSummary {
+    pub timestamp: DateTime<Utc>,
+    pub miners_found: usize,
+    pub duration_seconds: u64,
+    pub error: Option<String>,
+}
+
+/// One completed scan run, for the history screen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanRun {
+    pub started_at: DateTime<Utc>,
+    pub duration_seconds: u64,
+    /// Groups scanned in this run, sorted by name
+    pub groups: Vec<ScanRunGroup>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanRunGroup {
+    pub name: String,
+    pub miners_found: usize,
+    pub error: Option<String>,
+}
+
+impl ScanRun {
+    pub fn miners_found(&self) -> usize {
+        self.groups.iter().map(|group| group.miners_found).sum()
+    }
+
+    pub fn failed_groups(&self) -> usize {
+        self.groups
+            .iter()
+            .filter(|group| group.error.is_some())
+            .count()
+    }
+}
+
+/// Hashrates seen by one completed scan, in H/s and keyed by miner IP
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanRecord {
+    pub timestamp: DateTime<Utc>,
+    pub hashrates: HashMap<IpAddr, f64>,
+}
+
+impl ScanRecord {
+    pub fn from_miners<'a>(
+        timestamp: DateTime<Utc>,
+        miners: impl IntoIterator<Item = &'a MinerData>,
+    ) -> Self {
+        let hashrates = miners
+            .into_iter()
+            .filter_map(|miner| Some((miner.ip, hashes_per_second(miner.hashrate.as_ref()?))))
+            .collect();
+        Self {
+            timestamp,
+            hashrates,
+        }
+    }
+}
+
+/// Most recent scans of a miner considered for its hashrate trend
+const HASHRATE_TREND_SCANS: usize = 5;
+/// Fewest scans of a miner needed before a trend is reported
+const MIN_HASHRATE_TREND_SCANS: usize = 3;
+/// Change across the trend window, in percent, below which hashrate counts as flat
+const HASHRATE_TREND_THRESHOLD_PCT: f64 = 5.0;
+
+/// Direction of a miner's hashrate over its last few scans, as a percentage of
+/// its average hashrate. Scans that didn't see the miner are skipped; too few
+/// sightings give `Trend::Flat`.
+pub fn hashrate_trend(history: &[ScanRecord], ip: IpAddr) -> Trend {
+    let mut samples: Vec<f64> = history
+        .iter()
+        .rev()
+        .filter_map(|record| record.hashrates.get(&ip).copied())
+        .take(HASHRATE_TREND_SCANS)
+        .collect();
+    if samples.len() < MIN_HASHRATE_TREND_SCANS {
+        return Trend::Flat;
+    }
+    samples.reverse();
+
+    // Least-squares slope, so one noisy scan doesn't decide the trend
+    let n = samples.len() as f64;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = samples.iter().sum::<f64>() / n;
+    if mean_y <= 0.0 {
+        return Trend::Flat;
+    }
+    let (covariance, variance) =
+        samples
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(cov, var), (i, y)| {
+                let dx = i as f64 - mean_x;
+                (cov + dx * (y - mean_y), var + dx * dx)
+            });
+    let change_pct = covariance / variance * (n - 1.0) / mean_y * 100.0;
+
+    if change_pct <= -HASHRATE_TREND_THRESHOLD_PCT {
+        Trend::Down(change_pct.abs().round() as usize)
+    } else if change_pct >= HASHRATE_TREND_THRESHOLD_PCT {
+        Trend::Up(change_pct.round() as usize)
+    } else {
+        Trend::Flat
+    }
+}
+
+/// Direction and size of a change between scans: a miner count difference,
+/// or a hashrate change in percent for `hashrate_trend`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Up(usize),
+    Down(usize),
+    Flat,
+}
+
+impl Trend {
+    pub fn between(previous: usize, current: usize) -> Self {
+        match current.cmp(&previous) {
+            std::cmp::Ordering::Greater => Self::Up(current - previous),
+            std::cmp::Ordering::Less => Self::Down(previous - current),
+            std::cmp::Ordering::Equal => Self::Flat,
+        }
+    }
+}
+
+fn legacy_schema_version() -> u32 {
+    1
+}
+
+fn default_schema_upgrade_notify() -> bool {
+    true
+}
+
+fn default_persist_results() -> bool {
+    true
+}
+
+fn default_backup_count() -> usize {
+    5
+}
+
+fn default_estimated_upgrade_seconds() -> u64 {
+    600
+}
+
+fn default_average_restart_seconds() -> u64 {
+    180
+}
+
+fn default_bytes_per_miner_probe() -> u64 {
+    2048
+}
+
+fn default_detail_fetch_timeout_seconds() -> u64 {
+    15
+}
+
+fn default_alert_cooldown_seconds() -> u64 {
+    3600
+}
+
+fn bundled_firmware_changelogs() -> HashMap<String, String> {
+    serde_json::from_str(include_str!("../assets/firmware_changelogs.json"))
+        .expect("bundled firmware changelogs are valid JSON")
+}
+
+/// A rolling backup of the config file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigBackup {
+    pub path: PathBuf,
+    pub index: usize,
+    pub modified: Option<SystemTime>,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version: CONFIG_SCHEMA_VERSION,
+            schema_upgrade_message: None,
+            schema_upgrade_notify: default_schema_upgrade_notify(),
             scan_groups: vec![ScanGroup::new(
                 "Default".to_string(),
                 "192.168.1.0/24".to_string(),
             )],
             last_scan_results: HashMap::new(),
+            compact_results: false,
+            persist_results: default_persist_results(),
+            miner_api_versions: HashMap::new(),
+            backup_on_save: false,
+            backup_count: default_backup_count(),
+            scan_history_summary: HashMap::new(),
+            scan_runs: Vec::new(),
+            hashrate_history: Vec::new(),
+            first_seen: HashMap::new(),
+            miner_count_history: HashMap::new(),
+            last_errors: HashMap::new(),
+            temperature_unit: TempUnit::default(),
+            browser_command: None,
+            average_restart_seconds: default_average_restart_seconds(),
+            estimated_upgrade_seconds: default_estimated_upgrade_seconds(),
+            detail_fetch_timeout_seconds: default_detail_fetch_timeout_seconds(),
+            alert_cooldown_seconds: default_alert_cooldown_seconds(),
+            completion_webhook: None,
+            post_scan_command: None,
+            bytes_per_miner_probe: default_bytes_per_miner_probe(),
+            custom_user_agent: None,
+            proxy: None,
+            health_thresholds: HealthThresholds::default(),
+            ignored_serial_numbers: HashSet::new(),
+            eol_firmwares: Vec::new(),
+            detail_section_states: HashMap::new(),
+            sort_states: HashMap::new(),
+            power_schedule_fired: HashMap::new(),
+            expand_problem_groups: false,
+            collapse_healthy_groups: false,
+            reverse_dns: false,
+            notify_first_discovery: false,
+            firmware_changelogs: bundled_firmware_changelogs(),
         }
     }
 }
@@ -58,38 +575,232 @@ impl AppConfig {
             }
         })?;
 
-        serde_json::from_str(&content).map_err(|e| ConfigError::Serialization(e.to_string()))
+        let mut config: Self = serde_json::from_str(&content)
+            .map_err(|e| ConfigError::Serialization(e.to_string()))?;
+        config.migrate();
+        // Hand-edited files can repeat a name, which lookups by name can't tell apart
+        let renamed = config.rename_duplicate_scan_groups();
+        if renamed > 0 {
+            tracing::warn!(
+                "Renamed {renamed} scan groups that repeated another group's name in the config"
+            );
+        }
+        Ok(config)
     }
 
+    /// Brings a config from an older schema up to `CONFIG_SCHEMA_VERSION`,
+    /// describing the changes in `schema_upgrade_message` unless notices are off.
+    fn migrate(&mut self) {
+        if self.schema_version >= CONFIG_SCHEMA_VERSION {
+            return;
+        }
+        let changes: Vec<&str> = SCHEMA_CHANGES
+            .iter()
+            .filter(|(version, _)| *version > self.schema_version)
+            .map(|(_, change)| *change)
+            .collect();
+        self.schema_version = CONFIG_SCHEMA_VERSION;
+        if self.schema_upgrade_notify && !changes.is_empty() {
+            self.schema_upgrade_message = Some(changes.join("\n"));
+        }
+    }
+
+    /// Writes the config to `path`. Saves from any thread are serialized, and
+    /// one that finds a newer save queued for the same path leaves the write
+    /// to it, so concurrent saves never interleave and the newest one wins.
     pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> ConfigResult<()> {
-        let content = serde_json::to_string_pretty(self)
-            .map_err(|e| ConfigError::Serialization(e.to_string()))?;
+        let path = path.as_ref();
+        let save = PendingSave {
+            content: self.to_json()?,
+            backup_count: (self.backup_on_save && self.backup_count > 0)
+                .then_some(self.backup_count),
+        };
+        pending_saves()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.to_path_buf(), save);
+
+        let _writing = SAVE_WRITE_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        // Whoever held the lock meanwhile already wrote this content or newer
+        let Some(save) = pending_saves()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(path)
+        else {
+            return Ok(());
+        };
+
+        if let Some(count) = save.backup_count
+            && path.exists()
+        {
+            // A failed backup shouldn't prevent saving the current config
+            if let Err(e) = rotate_backups(path, count) {
+                tracing::warn!("Failed to back up config: {e}");
+            }
+        }
+
+        export::write_atomic(path, save.content.as_bytes())
+            .map_err(|e| ConfigError::Io(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Serializes the config, honoring `compact_results` and `persist_results`.
+    ///
+    /// In compact mode everything except `last_scan_results` is pretty-printed and
+    /// the results are appended as a single compact line, since they dominate the
+    /// file size for large farms and are never edited by hand. Without
+    /// `persist_results` the scan-derived fields are written empty.
+    fn to_json(&self) -> ConfigResult<String> {
+        let to_err = |e: serde_json::Error| ConfigError::Serialization(e.to_string());
+
+        if self.persist_results && !self.compact_results {
+            return serde_json::to_string_pretty(self).map_err(to_err);
+        }
+
+        let mut value = serde_json::to_value(self).map_err(to_err)?;
+        if !self.persist_results
+            && let Some(obj) = value.as_object_mut()
+        {
+            for field in SESSION_ONLY_FIELDS {
+                obj.remove(field);
+            }
+            obj.insert(
+                "last_scan_results".to_string(),
+                serde_json::Value::Object(Default::default()),
+            );
+        }
+
+        if !self.compact_results {
+            return serde_json::to_string_pretty(&value).map_err(to_err);
+        }
+
+        let mut fields = match value {
+            serde_json::Value::Object(fields) => fields,
+            _ => return serde_json::to_string_pretty(&value).map_err(to_err),
+        };
+        let results = fields.remove("last_scan_results").unwrap_or_default();
+        let results = serde_json::value::to_raw_value(&results).map_err(to_err)?;
+
+        // The pretty printer writes raw values verbatim, keeping the results on one line
+        #[derive(Serialize)]
+        struct CompactFile<'a> {
+            #[serde(flatten)]
+            fields: &'a serde_json::Map<String, serde_json::Value>,
+            last_scan_results: &'a serde_json::value::RawValue,
+        }
+
+        serde_json::to_string_pretty(&CompactFile {
+            fields: &fields,
+            last_scan_results: &results,
+        })
+        .map_err(to_err)
+    }
 
-        fs::write(path.as_ref(), content)
-            .map_err(|e| ConfigError::Io(format!("{}: {}", path.as_ref().display(), e)))
+    /// Copies the file at `path` to the newest backup, shifting older ones like
+    /// `backup_on_save` does. Returns the backup's path.
+    pub fn back_up_file<P: AsRef<Path>>(&self, path: P) -> ConfigResult<PathBuf> {
+        rotate_backups(path.as_ref(), self.backup_count.max(1))?;
+        Ok(backup_path(path.as_ref(), 1))
+    }
+
+    /// A default config for "Reset to Defaults", keeping this one's scan
+    /// results and history when `keep_results` is set.
+    pub fn reset_to_defaults(&self, keep_results: bool) -> Self {
+        let defaults = Self::default();
+        if !keep_results {
+            return defaults;
+        }
+        Self {
+            last_scan_results: self.last_scan_results.clone(),
+            scan_history_summary: self.scan_history_summary.clone(),
+            miner_api_versions: self.miner_api_versions.clone(),
+            scan_runs: self.scan_runs.clone(),
+            hashrate_history: self.hashrate_history.clone(),
+            first_seen: self.first_seen.clone(),
+            miner_count_history: self.miner_count_history.clone(),
+            last_errors: self.last_errors.clone(),
+            ..defaults
+        }
+    }
+
+    /// Lists existing backups of `path`, newest first.
+    pub fn list_backups<P: AsRef<Path>>(path: P) -> Vec<ConfigBackup> {
+        (1..)
+            .map(|index| (index, backup_path(path.as_ref(), index)))
+            .take_while(|(_, backup)| backup.exists())
+            .map(|(index, path)| ConfigBackup {
+                modified: fs::metadata(&path).and_then(|m| m.modified()).ok(),
+                path,
+                index,
+            })
+            .collect()
     }
 
     pub fn load() -> Self {
         // Load config or create default if file missing/invalid
-        Self::load_from_file("btc_toolkit_config.json").unwrap_or_else(|e| {
-            eprintln!("Warning: Failed to load config file: {e}");
+        Self::load_from_file(CONFIG_FILE).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load config file: {e}");
 
             let config = Self::default();
-            if let Err(e) = config.save_to_file("btc_toolkit_config.json") {
-                eprintln!("Warning: Failed to save default config: {e}");
+            if let Err(e) = config.save_to_file(CONFIG_FILE) {
+                tracing::warn!("Failed to save default config: {e}");
             }
             config
         })
     }
 
     pub fn save(&self) -> ConfigResult<()> {
-        self.save_to_file("btc_toolkit_config.json")
+        self.save_to_file(CONFIG_FILE)
     }
 
     pub fn add_scan_group(&mut self, group: ScanGroup) {
         self.scan_groups.push(group);
     }
 
+    /// Adds a group from an external source, renaming it ("Name (2)", ...) if the
+    /// name is already taken. Returns the name the group was stored under.
+    pub fn merge_scan_group(&mut self, mut group: ScanGroup) -> String {
+        if self.get_group(&group.name).is_some() {
+            let base = group.name.clone();
+            group.name = (2..)
+                .map(|n| format!("{base} ({n})"))
+                .find(|candidate| self.get_group(candidate).is_none())
+                .expect("unbounded range always yields a free name");
+        }
+
+        let name = group.name.clone();
+        self.add_scan_group(group);
+        name
+    }
+
+    /// Gives every group that repeats an earlier group's name a free
+    /// "Name (2)" style name, as `merge_scan_group` does, so no group is lost.
+    /// Returns how many were renamed.
+    pub fn rename_duplicate_scan_groups(&mut self) -> usize {
+        let mut taken: HashSet<String> = self
+            .scan_groups
+            .iter()
+            .map(|group| group.name.clone())
+            .collect();
+        let mut seen = HashSet::new();
+        let mut renamed = 0;
+        for group in &mut self.scan_groups {
+            if seen.insert(group.name.clone()) {
+                continue;
+            }
+            let base = group.name.clone();
+            group.name = (2..)
+                .map(|n| format!("{base} ({n})"))
+                .find(|candidate| !taken.contains(candidate))
+                .expect("unbounded range always yields a free name");
+            taken.insert(group.name.clone());
+            seen.insert(group.name.clone());
+            renamed += 1;
+        }
+        renamed
+    }
+
     pub fn remove_scan_group(&mut self, name: &str) -> bool {
         let initial_len = self.scan_groups.len();
         self.scan_groups.retain(|group| group.name != name);
@@ -107,6 +818,77 @@ impl AppConfig {
             .unwrap_or(false)
     }
 
+    /// Moves a group's stored results and scan history to its new name.
+    pub fn rename_group_data(&mut self, old_name: &str, new_name: &str) {
+        if let Some(results) = self.last_scan_results.remove(old_name) {
+            self.last_scan_results.insert(new_name.to_string(), results);
+        }
+        if let Some(history) = self.scan_history_summary.remove(old_name) {
+            self.scan_history_summary
+                .insert(new_name.to_string(), history);
+        }
+        if let Some(counts) = self.miner_count_history.remove(old_name) {
+            self.miner_count_history
+                .insert(new_name.to_string(), counts);
+        }
+        if let Some(error) = self.last_errors.remove(old_name) {
+            self.last_errors.insert(new_name.to_string(), error);
+        }
+    }
+
+    /// Opens a closed device detail section or closes an open one.
+    pub fn toggle_detail_section(&mut self, section: &str) {
+        let open = self
+            .detail_section_states
+            .entry(section.to_string())
+            .or_insert(true);
+        *open = !*open;
+    }
+
+    /// The sort last chosen on `screen`, or ascending by IP for a screen
+    /// that has none stored.
+    pub fn sort_state_for(&self, screen: &str) -> (SortColumn, SortDirection) {
+        self.sort_states
+            .get(screen)
+            .copied()
+            .unwrap_or((SortColumn::IpAddress, SortDirection::Ascending))
+    }
+
+    pub fn set_sort_state(&mut self, screen: &str, column: SortColumn, direction: SortDirection) {
+        self.sort_states
+            .insert(screen.to_string(), (column, direction));
+    }
+
+    /// Time allowed for a full data fetch, at least a second.
+    pub fn detail_fetch_timeout(&self) -> Duration {
+        Duration::from_secs(self.detail_fetch_timeout_seconds.max(1))
+    }
+
+    /// User agent for outgoing HTTP requests, falling back to `DEFAULT_USER_AGENT`.
+    pub fn user_agent(&self) -> &str {
+        self.custom_user_agent
+            .as_deref()
+            .map(str::trim)
+            .filter(|agent| !agent.is_empty())
+            .unwrap_or(DEFAULT_USER_AGENT)
+    }
+
+    /// True if the miner's serial number is on the ignore list.
+    pub fn is_ignored(&self, miner: &MinerData) -> bool {
+        miner
+            .serial_number
+            .as_ref()
+            .is_some_and(|serial| self.ignored_serial_numbers.contains(serial.trim()))
+    }
+
+    /// True if the miner runs a firmware version on the EOL list.
+    pub fn has_eol_firmware(&self, miner: &MinerData) -> bool {
+        miner
+            .firmware_version
+            .as_deref()
+            .is_some_and(|version| is_eol_firmware(version, &self.eol_firmwares))
+    }
+
     pub fn get_enabled_groups(&self) -> Vec<&ScanGroup> {
         self.scan_groups.iter().filter(|g| g.enabled).collect()
     }
@@ -124,18 +906,239 @@ impl AppConfig {
             .insert(group_name.to_string(), miners);
     }
 
+    /// Replaces the stored result for `miner`'s IP with this newer data, in
+    /// whichever groups found it. Returns whether any group had it.
+    pub fn update_scan_result(&mut self, miner: &MinerData) -> bool {
+        let mut updated = false;
+        for stored in self.last_scan_results.values_mut().flatten() {
+            if stored.ip == miner.ip {
+                *stored = miner.clone();
+                updated = true;
+            }
+        }
+        updated
+    }
+
     pub fn get_all_scan_results(&self) -> &HashMap<String, Vec<MinerData>> {
         &self.last_scan_results
     }
 
+    /// Adds a group's scan outcome to its history, and remembers or clears
+    /// its last error. A scan the user stopped (`cancelled`) says nothing
+    /// about the group, so it leaves the last error as it was.
+    pub fn record_scan_summary(
+        &mut self,
+        group_name: &str,
+        summary: GroupScanSummary,
+        cancelled: bool,
+    ) {
+        match &summary.error {
+            _ if cancelled => {}
+            Some(error) => {
+                self.last_errors
+                    .insert(group_name.to_string(), error.clone());
+            }
+            None => {
+                self.last_errors.remove(group_name);
+            }
+        }
+
+        let history = self
+            .scan_history_summary
+            .entry(group_name.to_string())
+            .or_default();
+        history.push(summary);
+        if history.len() > MAX_SCAN_HISTORY {
+            history.drain(..history.len() - MAX_SCAN_HISTORY);
+        }
+    }
+
+    pub fn record_miner_count(&mut self, group_name: &str, count: u32) {
+        let counts = self
+            .miner_count_history
+            .entry(group_name.to_string())
+            .or_default();
+        counts.push_back(count);
+        if counts.len() > MAX_MINER_COUNT_HISTORY {
+            counts.pop_front();
+        }
+    }
+
+    pub fn record_scan_run(&mut self, run: ScanRun) {
+        self.scan_runs.push(run);
+        if self.scan_runs.len() > MAX_SCAN_RUNS {
+            self.scan_runs.drain(..self.scan_runs.len() - MAX_SCAN_RUNS);
+        }
+    }
+
+    pub fn record_hashrates(&mut self, record: ScanRecord) {
+        self.hashrate_history.push(record);
+        if self.hashrate_history.len() > MAX_SCAN_HISTORY {
+            self.hashrate_history
+                .drain(..self.hashrate_history.len() - MAX_SCAN_HISTORY);
+        }
+    }
+
+    /// Remembers `timestamp` as the first sighting of each miner not seen before.
+    pub fn record_first_seen<'a>(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        miners: impl IntoIterator<Item = &'a MinerData>,
+    ) {
+        for miner in miners {
+            self.first_seen.entry(miner.ip).or_insert(timestamp);
+        }
+    }
+
+    pub fn scan_history(&self, group_name: &str) -> &[GroupScanSummary] {
+        self.scan_history_summary
+            .get(group_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Management port of the group whose last scan found `ip`, if one is configured.
+    pub fn management_port_for(&self, ip: IpAddr) -> Option<u16> {
+        self.last_scan_results
+            .iter()
+            .find(|(_, miners)| miners.iter().any(|m| m.ip == ip))
+            .and_then(|(group_name, _)| self.get_group(group_name))
+            .and_then(|group| group.management_port)
+    }
+
+    /// Changelog for a firmware version. Falls back to the longest known
+    /// version that prefixes it, so "24.09.1" finds the "24.09" notes.
+    pub fn firmware_changelog(&self, version: &str) -> Option<&str> {
+        self.firmware_changelogs
+            .get(version)
+            .or_else(|| {
+                self.firmware_changelogs
+                    .iter()
+                    .filter(|(known, _)| version.starts_with(known.as_str()))
+                    .max_by_key(|(known, _)| known.len())
+                    .map(|(_, changelog)| changelog)
+            })
+            .map(String::as_str)
+    }
+
     pub fn clear_scan_results(&mut self) {
         self.last_scan_results.clear();
     }
+
+    pub fn api_version(&self, ip: IpAddr) -> Option<&str> {
+        self.miner_api_versions
+            .get(&ip.to_string())
+            .map(String::as_str)
+    }
+
+    pub fn record_api_version(&mut self, ip: IpAddr, version: &str) {
+        self.miner_api_versions
+            .insert(ip.to_string(), version.to_string());
+    }
+}
+
+/// Path of the `index`-th backup of `path`, e.g. `btc_toolkit_config.json.bak.1`
+pub fn backup_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".bak.{index}"));
+    PathBuf::from(name)
+}
+
+/// Shifts `.bak.1..count-1` up by one (dropping the oldest) and copies `path` to `.bak.1`.
+fn rotate_backups(path: &Path, count: usize) -> ConfigResult<()> {
+    let io_err = |p: &Path, e: std::io::Error| ConfigError::Io(format!("{}: {}", p.display(), e));
+
+    let oldest = backup_path(path, count);
+    if oldest.exists() {
+        fs::remove_file(&oldest).map_err(|e| io_err(&oldest, e))?;
+    }
+
+    for index in (1..count).rev() {
+        let from = backup_path(path, index);
+        if from.exists() {
+            fs::rename(&from, backup_path(path, index + 1)).map_err(|e| io_err(&from, e))?;
+        }
+    }
+
+    fs::copy(path, backup_path(path, 1)).map_err(|e| io_err(path, e))?;
+    Ok(())
+}
+
+/// True if `version` is on the `eol` list, or is a release under an entry:
+/// "2022.1" covers "2022.1" and "2022.1.3" but not "2022.10". Case and
+/// surrounding whitespace are ignored.
+pub fn is_eol_firmware(version: &str, eol: &[String]) -> bool {
+    let version = version.trim().to_lowercase();
+    eol.iter()
+        .map(|entry| entry.trim().to_lowercase())
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| {
+            version.strip_prefix(&entry).is_some_and(|rest| {
+                !rest
+                    .chars()
+                    .next()
+                    .is_some_and(|next| next.is_ascii_alphanumeric())
+            })
+        })
+}
+
+/// Differences in scan groups between two configurations, keyed by group name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl ConfigDiff {
+    pub fn between(old: &[ScanGroup], new: &[ScanGroup]) -> Self {
+        let mut diff = Self::default();
+
+        for group in new {
+            match old.iter().find(|g| g.name == group.name) {
+                None => diff.added.push(group.name.clone()),
+                Some(previous) if previous != group => diff.changed.push(group.name.clone()),
+                Some(_) => {}
+            }
+        }
+
+        diff.removed = old
+            .iter()
+            .filter(|g| !new.iter().any(|n| n.name == g.name))
+            .map(|g| g.name.clone())
+            .collect();
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Human-readable one-line summary, e.g. "Added: Farm B | Removed: Farm A"
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "No scan group changes".to_string();
+        }
+
+        [
+            ("Added", &self.added),
+            ("Removed", &self.removed),
+            ("Changed", &self.changed),
+        ]
+        .iter()
+        .filter(|(_, names)| !names.is_empty())
+        .map(|(label, names)| format!("{label}: {}", names.join(", ")))
+        .collect::<Vec<_>>()
+        .join(" | ")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::ScannerError;
+    use crate::test_support::test_miner;
 
     #[test]
     fn test_config_serialization() {
@@ -160,4 +1163,613 @@ mod tests {
         assert_eq!(config.scan_groups.len(), 1);
         assert!(!config.remove_scan_group("Non-existent"));
     }
+
+    #[test]
+    fn test_compact_results_serialization() {
+        let mut config = AppConfig::default();
+        for i in 0..50 {
+            let miners = (1..=4)
+                .map(|host| test_miner(format!("10.{i}.0.{host}").parse().unwrap()))
+                .collect();
+            config.store_scan_results(&format!("Group {i}"), miners);
+        }
+
+        let pretty = config.to_json().unwrap();
+        config.compact_results = true;
+        let compact = config.to_json().unwrap();
+
+        assert!(compact.len() < pretty.len());
+        // Group definitions stay human-readable
+        assert!(compact.contains("\n      \"name\": \"Default\""));
+        // ...while the results take a single line
+        let results_line = compact
+            .lines()
+            .find(|line| line.contains("\"last_scan_results\""))
+            .unwrap();
+        assert!(results_line.contains("10.49.0.4"));
+
+        let parsed: AppConfig = serde_json::from_str(&compact).unwrap();
+        assert!(parsed.compact_results);
+        assert_eq!(parsed.scan_groups, config.scan_groups);
+        assert_eq!(parsed.last_scan_results.len(), 50);
+        assert_eq!(parsed.last_scan_results["Group 7"].len(), 4);
+        assert_eq!(
+            parsed.last_scan_results["Group 7"][3].ip.to_string(),
+            "10.7.0.4"
+        );
+    }
+
+    #[test]
+    fn test_group_snippet_round_trip() {
+        let group = ScanGroup::new("Farm A".to_string(), "10.0.1.0/24".to_string());
+        let snippet = group.to_snippet().unwrap();
+        assert_eq!(ScanGroup::from_snippet(&snippet).unwrap(), group);
+
+        assert!(matches!(
+            ScanGroup::from_snippet("not json"),
+            Err(ConfigError::InvalidGroup(_))
+        ));
+
+        let unnamed = ScanGroup::new("  ".to_string(), "10.0.1.0/24".to_string());
+        assert!(matches!(
+            ScanGroup::from_snippet(&unnamed.to_snippet().unwrap()),
+            Err(ConfigError::InvalidGroup(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_scan_group_dedups_names() {
+        let mut config = AppConfig::default();
+        let group = ScanGroup::new("Farm A".to_string(), "10.0.1.0/24".to_string());
+
+        assert_eq!(config.merge_scan_group(group.clone()), "Farm A");
+        assert_eq!(config.merge_scan_group(group.clone()), "Farm A (2)");
+        assert_eq!(config.merge_scan_group(group), "Farm A (3)");
+        assert_eq!(config.scan_groups.len(), 4);
+    }
+
+    #[test]
+    fn test_concurrent_saves() {
+        let dir =
+            std::env::temp_dir().join(format!("btc_toolkit_concurrent_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+
+        let versions: Vec<String> = (0..16).map(|n| format!("1.0.{n}")).collect();
+        std::thread::scope(|scope| {
+            for version in &versions {
+                let path = &path;
+                scope.spawn(move || {
+                    for _ in 0..5 {
+                        let config = AppConfig {
+                            version: version.clone(),
+                            backup_on_save: false,
+                            ..AppConfig::default()
+                        };
+                        config.save_to_file(path).unwrap();
+                    }
+                });
+            }
+        });
+
+        // One complete file from one of the writers, and no temp file left behind
+        let saved = AppConfig::load_from_file(&path).unwrap();
+        assert!(versions.contains(&saved.version));
+        let leftovers: Vec<_> = fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(leftovers.len(), 1);
+        assert!(pending_saves().lock().unwrap().get(&path).is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reset_to_defaults() {
+        let mut config = AppConfig {
+            reverse_dns: !AppConfig::default().reverse_dns,
+            ..AppConfig::default()
+        };
+        config.add_scan_group(ScanGroup::new(
+            "Farm B".to_string(),
+            "10.0.1.0/24".to_string(),
+        ));
+        config.store_scan_results("Farm B", Vec::new());
+        config.record_miner_count("Farm B", 3);
+
+        let cleared = config.reset_to_defaults(false);
+        assert_eq!(cleared.scan_groups, AppConfig::default().scan_groups);
+        assert_eq!(cleared.reverse_dns, AppConfig::default().reverse_dns);
+        assert!(cleared.last_scan_results.is_empty());
+
+        let kept = config.reset_to_defaults(true);
+        assert_eq!(kept.scan_groups, AppConfig::default().scan_groups);
+        assert!(kept.last_scan_results.contains_key("Farm B"));
+        assert_eq!(kept.miner_count_history, config.miner_count_history);
+    }
+
+    #[test]
+    fn test_rename_duplicate_scan_groups() {
+        let group = |name: &str, range: &str| ScanGroup::new(name.to_string(), range.to_string());
+        let ranges = |config: &AppConfig| -> Vec<String> {
+            config
+                .scan_groups
+                .iter()
+                .map(|g| format!("{}={}", g.name, g.network_range))
+                .collect()
+        };
+
+        let mut config = AppConfig {
+            scan_groups: vec![group("A", "10.0.0.0/24"), group("B", "10.0.1.0/24")],
+            ..AppConfig::default()
+        };
+        assert_eq!(config.rename_duplicate_scan_groups(), 0);
+        assert_eq!(config.scan_groups.len(), 2);
+
+        // Later copies get a free name and keep their place and range
+        config.scan_groups = vec![
+            group("A", "10.0.0.0/24"),
+            group("B", "10.0.1.0/24"),
+            group("A", "10.0.2.0/24"),
+        ];
+        assert_eq!(config.rename_duplicate_scan_groups(), 1);
+        assert_eq!(
+            ranges(&config),
+            ["A=10.0.0.0/24", "B=10.0.1.0/24", "A (2)=10.0.2.0/24"]
+        );
+
+        // Names already in use later in the list are skipped
+        config.scan_groups = vec![
+            group("C", "10.0.3.0/24"),
+            group("C", "10.0.4.0/24"),
+            group("C (2)", "10.0.5.0/24"),
+            group("C", "10.0.6.0/24"),
+        ];
+        assert_eq!(config.rename_duplicate_scan_groups(), 2);
+        assert_eq!(
+            ranges(&config),
+            [
+                "C=10.0.3.0/24",
+                "C (3)=10.0.4.0/24",
+                "C (2)=10.0.5.0/24",
+                "C (4)=10.0.6.0/24"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_device_mismatches() {
+        let anything = DeviceExpectation::default();
+        assert!(anything.is_empty());
+        assert!(
+            anything
+                .mismatches_for(&MinerMake::WhatsMiner, "M30S", &MinerFirmware::Stock)
+                .is_empty()
+        );
+
+        let expected = DeviceExpectation {
+            makes: vec![MinerMake::AntMiner],
+            model: Some("s19".to_string()),
+            firmwares: vec![MinerFirmware::BraiinsOS, MinerFirmware::Stock],
+        };
+        assert!(
+            expected
+                .mismatches_for(&MinerMake::AntMiner, "S19", &MinerFirmware::Stock)
+                .is_empty()
+        );
+        assert_eq!(
+            expected.mismatches_for(&MinerMake::AntMiner, "S19 Pro", &MinerFirmware::BraiinsOS),
+            [DeviceMismatch::Model]
+        );
+        assert_eq!(
+            expected.mismatches_for(&MinerMake::WhatsMiner, "M30S", &MinerFirmware::VNish),
+            [
+                DeviceMismatch::Make,
+                DeviceMismatch::Model,
+                DeviceMismatch::Firmware
+            ]
+        );
+
+        // Groups saved before expectations existed accept any device
+        let group: ScanGroup =
+            serde_json::from_str(r#"{"name":"A","network_range":"10.0.0.0/24","scan_config":{"search_makes":null,"search_firmwares":null},"enabled":true}"#)
+                .unwrap();
+        assert!(group.expected_device.is_empty());
+    }
+
+    #[test]
+    fn test_eol_firmware_matching() {
+        let eol = vec![
+            "2022.1".to_string(),
+            " BOS 21.09 ".to_string(),
+            String::new(),
+        ];
+        assert!(is_eol_firmware("2022.1", &eol));
+        assert!(is_eol_firmware("2022.1.3", &eol));
+        assert!(is_eol_firmware("2022.1-beta", &eol));
+        assert!(!is_eol_firmware("2022.10", &eol));
+        assert!(!is_eol_firmware("2022", &eol));
+        assert!(is_eol_firmware("bos 21.09.1", &eol));
+        // A blank entry must not match everything
+        assert!(!is_eol_firmware("2024.3", &eol));
+        assert!(!is_eol_firmware("2022.1", &[]));
+    }
+
+    #[test]
+    fn test_record_api_version() {
+        let mut config = AppConfig::default();
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+        assert_eq!(config.api_version(ip), None);
+        config.record_api_version(ip, "3.7");
+        assert_eq!(config.api_version(ip), Some("3.7"));
+    }
+
+    #[test]
+    fn test_record_first_seen() {
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+        let first = Utc::now() - chrono::Duration::days(90);
+        let mut config = AppConfig::default();
+        config.record_first_seen(first, [&test_miner(ip)]);
+        config.record_first_seen(Utc::now(), [&test_miner(ip)]);
+        assert_eq!(config.first_seen[&ip], first);
+
+        // Kept even after the hashrate history rolled over
+        for _ in 0..=MAX_SCAN_HISTORY {
+            config.record_hashrates(ScanRecord::from_miners(Utc::now(), [&test_miner(ip)]));
+        }
+        assert_eq!(config.first_seen[&ip], first);
+    }
+
+    #[test]
+    fn test_update_scan_result() {
+        let shared: IpAddr = "10.0.0.5".parse().unwrap();
+        let other: IpAddr = "10.0.0.6".parse().unwrap();
+        let mut config = AppConfig::default();
+        config.last_scan_results.insert(
+            "Farm A".to_string(),
+            vec![test_miner(shared), test_miner(other)],
+        );
+        config
+            .last_scan_results
+            .insert("Farm B".to_string(), vec![test_miner(shared)]);
+
+        let mut fresh = test_miner(shared);
+        fresh.firmware_version = Some("2024.1".to_string());
+        assert!(config.update_scan_result(&fresh));
+
+        // Replaced in both groups, and no group gained or lost a miner
+        let ips = |group: &str| -> Vec<IpAddr> {
+            config.last_scan_results[group]
+                .iter()
+                .map(|miner| miner.ip)
+                .collect()
+        };
+        assert_eq!(ips("Farm A"), [shared, other]);
+        assert_eq!(ips("Farm B"), [shared]);
+        let firmware = |group: &str| -> Vec<Option<&str>> {
+            config.last_scan_results[group]
+                .iter()
+                .map(|miner| miner.firmware_version.as_deref())
+                .collect()
+        };
+        assert_eq!(firmware("Farm A"), [Some("2024.1"), None]);
+        assert_eq!(firmware("Farm B"), [Some("2024.1")]);
+
+        assert!(!config.update_scan_result(&test_miner("10.0.0.7".parse().unwrap())));
+        assert_eq!(config.last_scan_results.len(), 2);
+    }
+
+    #[test]
+    fn test_firmware_changelog_lookup() {
+        let config = AppConfig::default();
+        assert!(config.firmware_changelog("24.09").is_some());
+        assert_eq!(
+            config.firmware_changelog("24.09.1"),
+            config.firmware_changelog("24.09")
+        );
+        assert_eq!(config.firmware_changelog("0.0.1"), None);
+
+        // Changelogs come from the bundle and are never written to the config file
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(!json.contains("firmware_changelogs"));
+        let loaded: AppConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.firmware_changelogs, config.firmware_changelogs);
+    }
+
+    #[test]
+    fn test_results_not_persisted_when_disabled() {
+        let mut config = AppConfig {
+            persist_results: false,
+            ..AppConfig::default()
+        };
+        config.record_api_version("10.0.0.5".parse().unwrap(), "3.7");
+        config.record_scan_summary(
+            "Default",
+            GroupScanSummary {
+                timestamp: Utc::now(),
+                miners_found: 1,
+                duration_seconds: 10,
+                error: None,
+            },
+            false,
+        );
+        config.record_scan_run(ScanRun {
+            started_at: Utc::now(),
+            duration_seconds: 10,
+            groups: vec![ScanRunGroup {
+                name: "Default".to_string(),
+                miners_found: 1,
+                error: None,
+            }],
+        });
+
+        for compact in [false, true] {
+            config.compact_results = compact;
+            let loaded: AppConfig = serde_json::from_str(&config.to_json().unwrap()).unwrap();
+            assert!(loaded.miner_api_versions.is_empty());
+            assert!(loaded.scan_history("Default").is_empty());
+            // The history screen's run log is metadata and is always kept
+            assert_eq!(loaded.scan_runs, config.scan_runs);
+            assert_eq!(loaded.scan_groups.len(), 1);
+            assert!(!loaded.persist_results);
+        }
+    }
+
+    #[test]
+    fn test_backup_rotation() {
+        let dir = std::env::temp_dir().join(format!("btc_toolkit_backup_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+
+        let mut config = AppConfig {
+            backup_on_save: true,
+            backup_count: 2,
+            ..AppConfig::default()
+        };
+        for i in 0..4 {
+            config.version = format!("v{i}");
+            config.save_to_file(&path).unwrap();
+        }
+
+        let backups = AppConfig::list_backups(&path);
+        assert_eq!(backups.len(), 2);
+        assert_eq!(backups[0].index, 1);
+        assert_eq!(
+            AppConfig::load_from_file(&backups[0].path).unwrap().version,
+            "v2"
+        );
+        assert_eq!(
+            AppConfig::load_from_file(&backups[1].path).unwrap().version,
+            "v1"
+        );
+
+        config.backup_on_save = false;
+        config.save_to_file(&path).unwrap();
+        assert_eq!(
+            AppConfig::load_from_file(&backups[0].path).unwrap().version,
+            "v2"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_estimated_bandwidth() {
+        let config = AppConfig::default();
+        let group = |range: &str| ScanGroup::new("Farm".to_string(), range.to_string());
+
+        let slash_24 = group("10.0.0.0/24").estimated_bandwidth_mb(config.bytes_per_miner_probe);
+        assert!((slash_24 - 254.0 * 2048.0 / 1e6).abs() < 1e-9);
+        let slash_16 = group("10.0.0.0/16").estimated_bandwidth_mb(config.bytes_per_miner_probe);
+        assert!((slash_16 - 134.213_632).abs() < 1e-6);
+        assert_eq!(group("not a range").estimated_bandwidth_mb(2048), 0.0);
+    }
+
+    #[test]
+    fn test_user_agent_falls_back_to_default() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.user_agent(), DEFAULT_USER_AGENT);
+        assert!(DEFAULT_USER_AGENT.starts_with("btc-toolkit/"));
+
+        config.custom_user_agent = Some("   ".to_string());
+        assert_eq!(config.user_agent(), DEFAULT_USER_AGENT);
+
+        config.custom_user_agent = Some(" Mozilla/5.0 ".to_string());
+        assert_eq!(config.user_agent(), "Mozilla/5.0");
+    }
+
+    #[test]
+    fn test_toggle_detail_section() {
+        let mut config = AppConfig::default();
+
+        // Sections start open, so the first toggle closes them
+        config.toggle_detail_section("pools");
+        assert_eq!(config.detail_section_states.get("pools"), Some(&false));
+        config.toggle_detail_section("pools");
+        assert_eq!(config.detail_section_states.get("pools"), Some(&true));
+        assert!(!config.detail_section_states.contains_key("cooling"));
+    }
+
+    #[test]
+    fn test_sort_state_per_screen() {
+        let mut config = AppConfig::default();
+        assert_eq!(
+            config.sort_state_for("main"),
+            (SortColumn::IpAddress, SortDirection::Ascending)
+        );
+
+        config.set_sort_state("main", SortColumn::Model, SortDirection::Descending);
+        assert_eq!(
+            config.sort_state_for("main"),
+            (SortColumn::Model, SortDirection::Descending)
+        );
+        // Other screens keep their own order
+        assert_eq!(
+            config.sort_state_for("history"),
+            (SortColumn::IpAddress, SortDirection::Ascending)
+        );
+
+        let json = serde_json::to_string(&config).unwrap();
+        let restored: AppConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            restored.sort_state_for("main"),
+            (SortColumn::Model, SortDirection::Descending)
+        );
+    }
+
+    #[test]
+    fn test_scan_history_is_capped() {
+        let mut config = AppConfig::default();
+        for miners_found in 0..MAX_SCAN_HISTORY + 5 {
+            config.record_scan_summary(
+                "Default",
+                GroupScanSummary {
+                    timestamp: Utc::now(),
+                    miners_found,
+                    duration_seconds: 10,
+                    error: None,
+                },
+                false,
+            );
+        }
+
+        let history = config.scan_history("Default");
+        assert_eq!(history.len(), MAX_SCAN_HISTORY);
+        assert_eq!(history[0].miners_found, 5);
+        assert!(config.scan_history("Missing").is_empty());
+
+        for count in 0..MAX_MINER_COUNT_HISTORY as u32 + 3 {
+            config.record_miner_count("Default", count);
+        }
+        let counts = &config.miner_count_history["Default"];
+        assert_eq!(counts.len(), MAX_MINER_COUNT_HISTORY);
+        assert_eq!(counts.front(), Some(&3));
+    }
+
+    #[test]
+    fn test_last_errors() {
+        let summary = |error: Option<&str>| GroupScanSummary {
+            timestamp: Utc::now(),
+            miners_found: 0,
+            duration_seconds: 10,
+            error: error.map(str::to_string),
+        };
+        let mut config = AppConfig::default();
+        config.record_scan_summary("Default", summary(Some("Timed out")), false);
+        config.record_scan_summary("Default", summary(Some("Connection refused")), false);
+        assert_eq!(config.last_errors["Default"], "Connection refused");
+
+        // Stopping the scan keeps the previous error instead of replacing it
+        let stopped = ScannerError::Cancelled.to_string();
+        config.record_scan_summary("Default", summary(Some(&stopped)), true);
+        assert_eq!(config.last_errors["Default"], "Connection refused");
+        config.record_scan_summary("Farm B", summary(Some(&stopped)), true);
+        assert!(!config.last_errors.contains_key("Farm B"));
+        assert_eq!(config.scan_history_summary["Default"].len(), 3);
+
+        // Survives a reload even when results aren't persisted
+        config.persist_results = false;
+        let reloaded: AppConfig = serde_json::from_str(&config.to_json().unwrap()).unwrap();
+        assert_eq!(reloaded.last_errors, config.last_errors);
+
+        config.rename_group_data("Default", "Farm A");
+        assert_eq!(config.last_errors["Farm A"], "Connection refused");
+        config.record_scan_summary("Farm A", summary(None), false);
+        assert!(config.last_errors.is_empty());
+    }
+
+    #[test]
+    fn test_hashrate_trend() {
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+        let other: IpAddr = "10.0.0.6".parse().unwrap();
+        let history_of = |scans: &[Option<f64>]| -> Vec<ScanRecord> {
+            scans
+                .iter()
+                .map(|hashrate| ScanRecord {
+                    timestamp: Utc::now(),
+                    hashrates: hashrate
+                        .map(|h| HashMap::from([(ip, h)]))
+                        .unwrap_or_else(|| HashMap::from([(other, 100.0)])),
+                })
+                .collect()
+        };
+
+        // Scans that missed the miner don't break up the trend
+        let declining = history_of(&[Some(100.0), None, Some(95.0), Some(90.0), None, Some(85.0)]);
+        assert!(matches!(
+            hashrate_trend(&declining, ip),
+            Trend::Down(16..=18)
+        ));
+
+        let noisy = history_of(&[Some(100.0), Some(102.0), Some(99.0), Some(101.0)]);
+        assert_eq!(hashrate_trend(&noisy, ip), Trend::Flat);
+
+        let rising = history_of(&[Some(80.0), Some(90.0), Some(100.0)]);
+        assert!(matches!(hashrate_trend(&rising, ip), Trend::Up(_)));
+
+        // Too few sightings to call a trend
+        let sparse = history_of(&[Some(100.0), None, Some(50.0)]);
+        assert_eq!(hashrate_trend(&sparse, ip), Trend::Flat);
+
+        let json = serde_json::to_string(&declining).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<ScanRecord>>(&json).unwrap(),
+            declining
+        );
+    }
+
+    #[test]
+    fn test_trend_between() {
+        assert_eq!(Trend::between(10, 13), Trend::Up(3));
+        assert_eq!(Trend::between(10, 8), Trend::Down(2));
+        assert_eq!(Trend::between(10, 10), Trend::Flat);
+    }
+
+    #[test]
+    fn test_config_diff() {
+        let old = vec![
+            ScanGroup::new("Farm A".to_string(), "10.0.1.0/24".to_string()),
+            ScanGroup::new("Farm B".to_string(), "10.0.2.0/24".to_string()),
+        ];
+        let new = vec![
+            ScanGroup::new("Farm B".to_string(), "10.0.3.0/24".to_string()),
+            ScanGroup::new("Farm C".to_string(), "10.0.4.0/24".to_string()),
+        ];
+
+        let diff = ConfigDiff::between(&old, &new);
+        assert_eq!(diff.added, vec!["Farm C"]);
+        assert_eq!(diff.removed, vec!["Farm A"]);
+        assert_eq!(diff.changed, vec!["Farm B"]);
+        assert!(ConfigDiff::between(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_schema_migration() {
+        let mut legacy: AppConfig = serde_json::from_str(
+            r#"{"version": "0.1.0", "scan_groups": [], "last_scan_results": {}}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.schema_version, 1);
+        // Upgrading must not start writing backups next to the file
+        assert!(!legacy.backup_on_save);
+        legacy.migrate();
+        assert_eq!(legacy.schema_version, CONFIG_SCHEMA_VERSION);
+        assert!(
+            legacy
+                .schema_upgrade_message
+                .as_deref()
+                .is_some_and(|message| message.starts_with("Version 2:"))
+        );
+
+        let mut quiet = AppConfig {
+            schema_version: 1,
+            schema_upgrade_notify: false,
+            ..AppConfig::default()
+        };
+        quiet.migrate();
+        assert_eq!(quiet.schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(quiet.schema_upgrade_message, None);
+
+        let mut current = AppConfig::default();
+        current.migrate();
+        assert_eq!(current.schema_upgrade_message, None);
+    }
 }
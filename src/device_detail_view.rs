@@ -1,11 +1,32 @@
+use crate::config::{Trend, is_eol_firmware};
 use crate::errors::FetchError;
+use crate::formatters::{TempUnit, format_hashrate, format_temperature, hashes_per_second};
+use crate::health::{HealthStatus, is_psu_fault, pool_rejection_pct, psu_load_pct};
 use crate::theme;
 use crate::ui_helpers::{danger_button, format_duration, secondary_button};
+use crate::widgets::CollapsibleSection;
+use asic_rs::data::board::BoardData;
 use asic_rs::data::miner::MinerData;
 use iced::Element;
 use iced::Length;
-use iced::widget::{Space, column, container, row, scrollable, text};
+use iced::widget::{
+    Space, button, center, column, container, mouse_area, opaque, progress_bar, row, scrollable,
+    stack, text, tooltip,
+};
+use std::collections::HashMap;
 use std::net::IpAddr;
+use std::time::Instant;
+
+/// Multiple of the estimated upgrade time after which a miner that hasn't
+/// come back is reported as stuck
+const UPGRADE_GIVE_UP_FACTOR: u64 = 3;
+
+/// Keys of the collapsible sections in `AppConfig::detail_section_states`
+pub const SECTION_HASHBOARDS: &str = "hashboards";
+pub const SECTION_CHIPS: &str = "chips";
+pub const SECTION_COOLING: &str = "cooling";
+pub const SECTION_POOLS: &str = "pools";
+pub const SECTION_MESSAGES: &str = "messages";
 
 #[derive(Debug, Clone)]
 pub enum DeviceDetailMessage {
@@ -14,37 +35,275 @@ pub enum DeviceDetailMessage {
     PauseMining,
     ResumeMining,
     ToggleFaultLight,
+    /// Flashes the fault light for a while so the miner can be found
+    Identify,
+    /// The identify light went off again, or couldn't be turned on
+    IdentifyFinished(Result<bool, FetchError>),
     Restart,
+    /// Refetches miner data without changing the current view
+    Refresh,
+    OpenInNewWindow,
+    /// Shows or hides the firmware changelog overlay
+    SetChangelogOpen(bool),
+    /// Shows or hides the raw error on the failure screen
+    SetConnectionDetailsOpen(bool),
+    /// Opens or closes one of the `SECTION_*` sections
+    ToggleSection(&'static str),
+    /// Watches a firmware upgrade started from the miner's web UI until the
+    /// miner comes back
+    TrackFirmwareUpgrade,
+    CancelFirmwareUpgrade,
+    /// Share of the estimated upgrade time that has passed
+    FirmwareUpdateProgress(f32),
+    /// A poll made while an upgrade is tracked, tagged with the tracking's
+    /// generation so polls from a cancelled tracking are dropped
+    FirmwareUpgradePolled(u64, Result<MinerData, FetchError>),
     DataFetched(Result<MinerData, FetchError>),
 }
 
+/// What a poll during a tracked firmware upgrade found
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpgradePoll {
+    /// The miner is back; holds its firmware version
+    Complete(Option<String>),
+    /// Still upgrading, at this share of the estimated time
+    Pending(f32),
+    /// Not back after `UPGRADE_GIVE_UP_FACTOR` times the estimate
+    TimedOut,
+    /// Tracking was cancelled or restarted while the poll ran
+    NotTracking,
+}
+
+/// A firmware upgrade being watched until the miner answers again
+struct FirmwareUpgrade {
+    /// Tags this tracking's polls
+    generation: u64,
+    started: Instant,
+    estimated_seconds: u64,
+    /// Version before the upgrade, to tell when the new one is running
+    from_version: Option<String>,
+    /// The miner stopped answering at some poll, i.e. it flashed or rebooted
+    went_offline: bool,
+    progress: f32,
+}
+
 pub enum DeviceDetailState {
     Loading(IpAddr),
-    Loaded { miner: MinerData },
-    Error(String),
+    Loaded {
+        miner: MinerData,
+    },
+    Error {
+        ip: Option<IpAddr>,
+        error: FetchError,
+    },
 }
 
 pub struct DeviceDetailView {
     state: DeviceDetailState,
+    scan_in_progress: bool,
+    /// Shown in its own window: "Back" closes the window instead of navigating
+    standalone: bool,
+    /// Changelog for the loaded miner's firmware version, if one is known
+    firmware_changelog: Option<String>,
+    changelog_open: bool,
+    connection_details_open: bool,
+    temperature_unit: TempUnit,
+    /// Hashrate direction across this miner's recent scans
+    hashrate_trend: Trend,
+    /// Open/closed state of the collapsible sections; missing means open
+    section_states: HashMap<String, bool>,
+    /// Last refresh of already loaded data failed; the old data stays shown
+    refresh_error: Option<FetchError>,
+    firmware_upgrade: Option<FirmwareUpgrade>,
+    /// Generation of the latest upgrade tracking
+    upgrade_generation: u64,
+    /// `AppConfig::eol_firmwares`, for the EOL badge
+    eol_firmwares: Vec<String>,
+    /// The fault light is flashing from "Identify"
+    identifying: bool,
 }
 
 impl DeviceDetailView {
     pub fn new_loading(ip: IpAddr) -> Self {
         Self {
             state: DeviceDetailState::Loading(ip),
+            scan_in_progress: false,
+            standalone: false,
+            firmware_changelog: None,
+            changelog_open: false,
+            connection_details_open: false,
+            temperature_unit: TempUnit::default(),
+            hashrate_trend: Trend::Flat,
+            section_states: HashMap::new(),
+            refresh_error: None,
+            firmware_upgrade: None,
+            upgrade_generation: 0,
+            eol_firmwares: Vec::new(),
+            identifying: false,
         }
     }
 
     pub fn new_loaded(miner: MinerData) -> Self {
         Self {
             state: DeviceDetailState::Loaded { miner },
+            scan_in_progress: false,
+            standalone: false,
+            firmware_changelog: None,
+            changelog_open: false,
+            connection_details_open: false,
+            temperature_unit: TempUnit::default(),
+            hashrate_trend: Trend::Flat,
+            section_states: HashMap::new(),
+            refresh_error: None,
+            firmware_upgrade: None,
+            upgrade_generation: 0,
+            eol_firmwares: Vec::new(),
+            identifying: false,
+        }
+    }
+
+    /// Marks whether a network scan is running alongside this page's requests.
+    pub fn set_scan_in_progress(&mut self, scanning: bool) {
+        self.scan_in_progress = scanning;
+    }
+
+    /// Marks this view as the content of a dedicated detail window.
+    pub fn set_standalone(&mut self, standalone: bool) {
+        self.standalone = standalone;
+    }
+
+    pub fn set_firmware_changelog(&mut self, changelog: Option<String>) {
+        self.firmware_changelog = changelog;
+    }
+
+    pub fn set_temperature_unit(&mut self, unit: TempUnit) {
+        self.temperature_unit = unit;
+    }
+
+    pub fn set_hashrate_trend(&mut self, trend: Trend) {
+        self.hashrate_trend = trend;
+    }
+
+    pub fn set_section_states(&mut self, states: HashMap<String, bool>) {
+        self.section_states = states;
+    }
+
+    pub fn set_eol_firmwares(&mut self, eol_firmwares: Vec<String>) {
+        self.eol_firmwares = eol_firmwares;
+    }
+
+    fn is_section_open(&self, section: &str) -> bool {
+        self.section_states.get(section).copied().unwrap_or(true)
+    }
+
+    pub fn set_identifying(&mut self, identifying: bool) {
+        self.identifying = identifying;
+    }
+
+    pub fn set_changelog_open(&mut self, open: bool) {
+        self.changelog_open = open;
+    }
+
+    pub fn set_connection_details_open(&mut self, open: bool) {
+        self.connection_details_open = open;
+    }
+
+    /// Starts watching for the miner to come back from a firmware upgrade.
+    /// Returns the generation to tag its polls with, or `None` if no miner is
+    /// loaded or an upgrade is already tracked.
+    pub fn start_upgrade_tracking(&mut self, estimated_seconds: u64) -> Option<u64> {
+        let DeviceDetailState::Loaded { miner } = &self.state else {
+            return None;
+        };
+        if self.firmware_upgrade.is_some() {
+            return None;
+        }
+        self.upgrade_generation += 1;
+        self.firmware_upgrade = Some(FirmwareUpgrade {
+            generation: self.upgrade_generation,
+            started: Instant::now(),
+            estimated_seconds: estimated_seconds.max(1),
+            from_version: miner.firmware_version.clone(),
+            went_offline: false,
+            progress: 0.0,
+        });
+        Some(self.upgrade_generation)
+    }
+
+    pub fn cancel_upgrade_tracking(&mut self) {
+        self.firmware_upgrade = None;
+    }
+
+    pub fn set_upgrade_progress(&mut self, progress: f32) {
+        if let Some(upgrade) = &mut self.firmware_upgrade {
+            upgrade.progress = progress;
+        }
+    }
+
+    /// Applies a poll made during a tracked upgrade. The upgrade is complete
+    /// once the miner answers after having gone offline, or answers with a
+    /// different firmware version; the new data is shown then. Polls from an
+    /// earlier `generation` of tracking are ignored.
+    pub fn upgrade_polled(
+        &mut self,
+        generation: u64,
+        result: Result<MinerData, FetchError>,
+    ) -> UpgradePoll {
+        let Some(upgrade) = self
+            .firmware_upgrade
+            .as_mut()
+            .filter(|upgrade| upgrade.generation == generation)
+        else {
+            return UpgradePoll::NotTracking;
+        };
+        match result {
+            Ok(miner) if upgrade.went_offline || miner.firmware_version != upgrade.from_version => {
+                let version = miner.firmware_version.clone();
+                self.firmware_upgrade = None;
+                self.update_with_data(Ok(miner));
+                return UpgradePoll::Complete(version);
+            }
+            Ok(_) => {}
+            Err(_) => upgrade.went_offline = true,
         }
+
+        let elapsed = upgrade.started.elapsed().as_secs();
+        if elapsed > upgrade.estimated_seconds * UPGRADE_GIVE_UP_FACTOR {
+            self.firmware_upgrade = None;
+            return UpgradePoll::TimedOut;
+        }
+        // Held just short of full until the miner actually answers
+        UpgradePoll::Pending((elapsed as f32 / upgrade.estimated_seconds as f32).min(0.99))
+    }
+
+    fn back_button(&self) -> Element<'_, DeviceDetailMessage> {
+        let label = if self.standalone { "Close" } else { "Back" };
+        secondary_button(
+            label,
+            Some(theme::icons::back().into()),
+            Some(DeviceDetailMessage::Back),
+        )
+        .into()
     }
 
+    /// Applies a fetch result. Only a failed initial load replaces the page
+    /// with the error screen; once data is shown, a failed refresh keeps it
+    /// and flags the header until a later fetch succeeds.
     pub fn update_with_data(&mut self, result: Result<MinerData, FetchError>) {
+        if let (DeviceDetailState::Loaded { .. }, Err(error)) = (&self.state, &result) {
+            self.refresh_error = Some(error.clone());
+            return;
+        }
+        self.refresh_error = None;
+
+        let ip = match &self.state {
+            DeviceDetailState::Loading(ip) => Some(*ip),
+            DeviceDetailState::Loaded { miner } => Some(miner.ip),
+            DeviceDetailState::Error { ip, .. } => *ip,
+        };
         self.state = match result {
             Ok(miner) => DeviceDetailState::Loaded { miner },
-            Err(error) => DeviceDetailState::Error(error.to_string()),
+            Err(error) => DeviceDetailState::Error { ip, error },
         };
     }
 
@@ -80,6 +339,8 @@ impl DeviceDetailView {
                 let content = scrollable(
                     column![
                         self.view_header(miner),
+                        self.view_scan_notice(),
+                        self.view_upgrade_progress(),
                         // Hardware + Performance side by side
                         row![
                             self.view_hardware_section(miner),
@@ -88,6 +349,7 @@ impl DeviceDetailView {
                         .spacing(theme::spacing::SM),
                         // Hashboards full width
                         self.view_hashboards_section(miner),
+                        self.view_chip_statistics(miner),
                         // Cooling + Power side by side
                         row![
                             self.view_cooling_section(miner),
@@ -106,28 +368,24 @@ impl DeviceDetailView {
                     .padding(theme::padding::SM),
                 );
 
-                container(content)
-                    .width(Length::Fill)
-                    .height(Length::Fill)
-                    .into()
+                let page = container(content).width(Length::Fill).height(Length::Fill);
+
+                match &self.firmware_changelog {
+                    Some(changelog) if self.changelog_open => {
+                        stack![page, self.view_changelog_modal(miner, changelog)].into()
+                    }
+                    _ => page.into(),
+                }
             }
 
-            DeviceDetailState::Error(error) => {
+            DeviceDetailState::Error { ip, error } => {
                 let content = column![
                     self.view_error_header(),
-                    container(
-                        column![
-                            theme::icons::icon_size(theme::icons::ERROR, 64.0),
-                            theme::typography::heading("Failed to load miner data"),
-                            theme::typography::body(error),
-                        ]
-                        .spacing(theme::spacing::MD)
-                        .align_x(iced::Alignment::Center)
-                    )
-                    .width(Length::Fill)
-                    .height(Length::Fill)
-                    .align_x(iced::alignment::Horizontal::Center)
-                    .align_y(iced::alignment::Vertical::Center)
+                    container(self.view_error_body(*ip, error))
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .align_x(iced::alignment::Horizontal::Center)
+                        .align_y(iced::alignment::Vertical::Center)
                 ]
                 .spacing(theme::spacing::LG)
                 .padding(theme::padding::LG);
@@ -141,11 +399,7 @@ impl DeviceDetailView {
     }
 
     fn view_loading_header(&self, ip: &IpAddr) -> Element<'_, DeviceDetailMessage> {
-        let back_button = secondary_button(
-            "Back",
-            Some(theme::icons::back().into()),
-            Some(DeviceDetailMessage::Back),
-        );
+        let back_button = self.back_button();
 
         container(
             row![
@@ -156,36 +410,143 @@ impl DeviceDetailView {
             ]
             .align_y(iced::Alignment::Center),
         )
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::SM)
         .width(Length::Fill)
         .into()
     }
 
+    /// Failure category with guidance, the actions that make sense for it, and
+    /// an expandable section with the raw error.
+    fn view_error_body(
+        &self,
+        ip: Option<IpAddr>,
+        error: &FetchError,
+    ) -> Element<'_, DeviceDetailMessage> {
+        let (heading, guidance) = error_guidance(error);
+
+        let mut actions = row![secondary_button(
+            "Retry",
+            Some(theme::icons::refresh().into()),
+            ip.map(|_| DeviceDetailMessage::Refresh),
+        )]
+        .spacing(theme::spacing::SM);
+        if matches!(
+            error,
+            FetchError::MinerDataError(_) | FetchError::Timeout { .. }
+        ) {
+            actions = actions.push(secondary_button(
+                "Open Web UI",
+                None,
+                ip.map(|_| DeviceDetailMessage::OpenInBrowser),
+            ));
+        }
+
+        let details_open = self.connection_details_open;
+        let toggle = button(theme::typography::small(if details_open {
+            "Hide connection details"
+        } else {
+            "Show connection details"
+        }))
+        .style(button::text)
+        .padding(0)
+        .on_press(DeviceDetailMessage::SetConnectionDetailsOpen(!details_open));
+
+        let mut body = column![
+            theme::icons::icon_size(theme::icons::ERROR, 64.0),
+            theme::typography::heading(heading),
+            theme::typography::body(guidance),
+            actions,
+            toggle,
+        ]
+        .spacing(theme::spacing::MD)
+        .align_x(iced::Alignment::Center);
+
+        if details_open {
+            let mut details = column![].spacing(theme::spacing::XS);
+            if let Some(ip) = ip {
+                details = details.push(theme::typography::mono(format!("Address: {ip}")));
+            }
+            details = details.push(theme::typography::mono(error.to_string()));
+            body = body.push(
+                container(details)
+                    .style(theme::containers::card_adaptive)
+                    .padding(theme::padding::MD),
+            );
+        }
+
+        body.into()
+    }
+
     fn view_error_header(&self) -> Element<'_, DeviceDetailMessage> {
         let title = theme::typography::title("Error Loading Device");
-        let back_button = secondary_button(
-            "Back",
-            Some(theme::icons::back().into()),
-            Some(DeviceDetailMessage::Back),
-        );
+        let back_button = self.back_button();
 
         container(
             row![title, Space::new().width(Length::Fill), back_button]
                 .align_y(iced::Alignment::Center),
         )
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::MD)
         .width(Length::Fill)
         .into()
     }
 
+    fn view_scan_notice(&self) -> Element<'_, DeviceDetailMessage> {
+        if !self.scan_in_progress {
+            return column![].into();
+        }
+
+        container(theme::typography::small(
+            "A network scan is running. This page queries the miner directly; \
+             scan progress and results are not affected.",
+        ))
+        .style(theme::containers::warning_adaptive)
+        .padding([theme::padding::XS, theme::padding::SM])
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// "Upgrading…" banner with a progress bar while an upgrade is tracked.
+    fn view_upgrade_progress(&self) -> Element<'_, DeviceDetailMessage> {
+        let Some(upgrade) = &self.firmware_upgrade else {
+            return column![].into();
+        };
+        let status = if upgrade.went_offline {
+            "Upgrading… waiting for the miner to come back"
+        } else {
+            "Upgrading… waiting for the miner to reboot"
+        };
+
+        container(
+            row![
+                theme::typography::small(status),
+                progress_bar(0.0..=1.0, upgrade.progress)
+                    .girth(theme::progress_bars::CHIP_BAR_HEIGHT)
+                    .style(theme::progress_bars::scanning)
+                    .length(Length::Fill),
+                theme::typography::small(format!(
+                    "{:.0}% of ~{}",
+                    upgrade.progress * 100.0,
+                    format_duration(upgrade.estimated_seconds)
+                )),
+                secondary_button(
+                    "Stop Tracking",
+                    None,
+                    Some(DeviceDetailMessage::CancelFirmwareUpgrade)
+                ),
+            ]
+            .spacing(theme::spacing::SM)
+            .align_y(iced::Alignment::Center),
+        )
+        .style(theme::containers::card_adaptive)
+        .padding([theme::padding::XS, theme::padding::SM])
+        .width(Length::Fill)
+        .into()
+    }
+
     fn view_header(&self, miner: &MinerData) -> Element<'_, DeviceDetailMessage> {
-        let back_button = secondary_button(
-            "Back",
-            Some(theme::icons::back().into()),
-            Some(DeviceDetailMessage::Back),
-        );
+        let back_button = self.back_button();
         let browser_button = secondary_button(
             "Open Web UI",
             Some(theme::icons::network().into()),
@@ -206,6 +567,24 @@ impl DeviceDetailView {
             Some(theme::icons::light_bulb().into()),
             Some(DeviceDetailMessage::ToggleFaultLight),
         );
+        let identify_button = secondary_button(
+            "Identify",
+            Some(theme::icons::light_bulb().into()),
+            (!self.identifying).then_some(DeviceDetailMessage::Identify),
+        );
+        let identifying: Option<Element<'_, DeviceDetailMessage>> = self.identifying.then(|| {
+            container(theme::typography::small("Fault light flashing"))
+                .style(theme::containers::warning_adaptive)
+                .padding([0.0, theme::padding::XS])
+                .into()
+        });
+        let new_window_button = (!self.standalone).then(|| {
+            secondary_button(
+                "New Window",
+                Some(theme::icons::add().into()),
+                Some(DeviceDetailMessage::OpenInNewWindow),
+            )
+        });
         let restart_button = danger_button(
             "Restart",
             Some(theme::icons::refresh().into()),
@@ -216,25 +595,58 @@ impl DeviceDetailView {
             row![
                 back_button,
                 Space::new().width(theme::spacing::SM),
+                theme::widgets::status_dot(HealthStatus::from_miner_data(miner)),
+                Space::new().width(theme::spacing::XS),
                 theme::typography::mono(format!("{}", miner.ip)),
-                Space::new().width(Length::Fill),
+                Space::new().width(theme::spacing::SM),
+                self.view_refresh_error(),
+            ]
+            .push(identifying)
+            .push(Space::new().width(Length::Fill))
+            .push(
                 row![
                     pause_button,
                     resume_button,
+                    identify_button,
                     fault_light_button,
                     browser_button,
-                    restart_button
                 ]
-                .spacing(theme::spacing::XS)
-            ]
+                .push(new_window_button)
+                .push(restart_button)
+                .spacing(theme::spacing::XS),
+            )
             .align_y(iced::Alignment::Center),
         )
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::SM)
         .width(Length::Fill)
         .into()
     }
 
+    /// Small badge saying the shown data is stale, with the error on hover.
+    fn view_refresh_error(&self) -> Element<'_, DeviceDetailMessage> {
+        let Some(error) = &self.refresh_error else {
+            return Space::new().into();
+        };
+        // Detail windows poll on a timer; the page only refetches on request
+        let label = if self.standalone {
+            "Refresh failed, retrying"
+        } else {
+            "Refresh failed, showing last data"
+        };
+
+        tooltip(
+            container(theme::typography::small(label))
+                .style(theme::containers::warning_adaptive)
+                .padding([0.0, theme::padding::XS]),
+            container(theme::typography::small(error.to_string()))
+                .style(theme::containers::tooltip_adaptive)
+                .padding(theme::padding::SM),
+            tooltip::Position::Bottom,
+        )
+        .into()
+    }
+
     fn view_hardware_section(&self, miner: &MinerData) -> Element<'_, DeviceDetailMessage> {
         let info = &miner.device_info;
 
@@ -271,12 +683,13 @@ impl DeviceDetailView {
                     .unwrap_or_else(|| "N/A".to_string())
             ),
             self.info_row(
-                "Firmware Version",
+                "API Version",
                 miner
-                    .firmware_version
+                    .api_version
                     .clone()
                     .unwrap_or_else(|| "N/A".to_string())
             ),
+            self.view_firmware_version_row(miner),
             self.info_row(
                 "Uptime",
                 miner
@@ -296,7 +709,7 @@ impl DeviceDetailView {
 
         container(items)
             .padding(theme::padding::SM)
-            .style(theme::containers::card)
+            .style(theme::containers::card_adaptive)
             .width(Length::Fill)
             .into()
     }
@@ -305,13 +718,13 @@ impl DeviceDetailView {
         let hashrate_str = miner
             .hashrate
             .as_ref()
-            .map(|hr| format!("{:.2}", hr))
+            .map(format_hashrate)
             .unwrap_or_else(|| "N/A".to_string());
 
         let expected_hashrate_str = miner
             .expected_hashrate
             .as_ref()
-            .map(|hr| format!("{:.2}", hr))
+            .map(format_hashrate)
             .unwrap_or_else(|| "N/A".to_string());
 
         let hashrate_percentage = miner
@@ -319,7 +732,7 @@ impl DeviceDetailView {
             .as_ref()
             .zip(miner.expected_hashrate.as_ref())
             .map(|(current, expected)| {
-                let pct = (current.value / expected.value * 100.0) as u32;
+                let pct = (hashes_per_second(current) / hashes_per_second(expected) * 100.0) as u32;
                 format!("{}%", pct)
             })
             .unwrap_or_else(|| "N/A".to_string());
@@ -335,19 +748,37 @@ impl DeviceDetailView {
             "Inactive"
         };
 
-        let items = column![
+        let trend_str = match self.hashrate_trend {
+            Trend::Up(pct) => format!("▲ {pct}% over recent scans"),
+            Trend::Down(pct) => format!("▼ {pct}% over recent scans"),
+            Trend::Flat => "Steady".to_string(),
+        };
+
+        let mut items = column![
             theme::typography::heading("Performance"),
             self.info_row("Status", mining_status.to_string()),
             self.info_row("Hashrate", hashrate_str),
+            self.info_row("Hashrate Trend", trend_str),
             self.info_row("Expected Hashrate", expected_hashrate_str),
             self.info_row("Efficiency", hashrate_percentage),
             self.info_row("Power Efficiency", efficiency_str),
         ]
         .spacing(theme::spacing::XS);
 
+        if let Trend::Down(pct) = self.hashrate_trend {
+            let warning: Element<'static, DeviceDetailMessage> = container(theme::typography::small(format!(
+                "Declining hashrate: down {pct}% across recent scans. Check hashboards, cooling and power before it fails."
+            )))
+            .style(theme::containers::warning_adaptive)
+            .padding([theme::padding::XS, theme::padding::SM])
+            .width(Length::Fill)
+            .into();
+            items = items.push(warning);
+        }
+
         container(items)
             .padding(theme::padding::SM)
-            .style(theme::containers::card)
+            .style(theme::containers::card_adaptive)
             .width(Length::Fill)
             .into()
     }
@@ -359,29 +790,19 @@ impl DeviceDetailView {
             miner.expected_hashboards.unwrap_or(0)
         );
 
-        let mut items = column![
-            theme::typography::heading("Hashboards"),
-            self.info_row("Detected", board_count),
-        ]
-        .spacing(theme::spacing::XS);
+        let mut items = column![self.info_row("Detected", board_count)].spacing(theme::spacing::XS);
 
         for (idx, board) in miner.hashboards.iter().enumerate() {
             let board_label = format!("Board {}", board.position);
 
             let board_info = column![
                 text(board_label).size(14),
-                self.info_row(
-                    "Working Chips",
-                    board
-                        .working_chips
-                        .map(|c| c.to_string())
-                        .unwrap_or_else(|| "N/A".to_string())
-                ),
+                self.view_chip_ratio(board),
                 self.info_row(
                     "Board Temp",
                     board
                         .board_temperature
-                        .map(|t| format!("{:.1}°C", t.as_celsius()))
+                        .map(|t| format_temperature(&t, self.temperature_unit))
                         .unwrap_or_else(|| "N/A".to_string())
                 ),
                 self.info_row(
@@ -389,7 +810,7 @@ impl DeviceDetailView {
                     board
                         .hashrate
                         .as_ref()
-                        .map(|hr| format!("{:.2}", hr))
+                        .map(format_hashrate)
                         .unwrap_or_else(|| "N/A".to_string())
                 ),
             ]
@@ -423,30 +844,125 @@ impl DeviceDetailView {
 
         items = items.push(self.info_row("Total Working Chips", total_chips_str));
 
-        container(items)
-            .padding(theme::padding::SM)
-            .style(theme::containers::card)
-            .width(Length::Fill)
-            .into()
+        container(CollapsibleSection::new(
+            theme::typography::heading("Hashboards"),
+            items,
+            self.is_section_open(SECTION_HASHBOARDS),
+            DeviceDetailMessage::ToggleSection(SECTION_HASHBOARDS),
+        ))
+        .padding(theme::padding::SM)
+        .style(theme::containers::card_adaptive)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// Working chips against expected, overall and as one bar per board, so
+    /// boards with dead chips stand out.
+    fn view_chip_statistics(&self, miner: &MinerData) -> Element<'_, DeviceDetailMessage> {
+        // Fall back to the boards' own counts when the miner reports no totals
+        let sum_boards = |chips: fn(&BoardData) -> Option<u16>| {
+            miner
+                .hashboards
+                .iter()
+                .map(chips)
+                .sum::<Option<u16>>()
+                .filter(|_| !miner.hashboards.is_empty())
+        };
+        let total_chips = miner
+            .total_chips
+            .or_else(|| sum_boards(|board| board.working_chips));
+        let expected_chips = miner
+            .expected_chips
+            .or_else(|| sum_boards(|board| board.expected_chips));
+        let chip_ratio = total_chips
+            .zip(expected_chips)
+            .filter(|&(_, expected)| expected > 0)
+            .map(|(total, expected)| f32::from(total) / f32::from(expected));
+
+        let format_count = |count: Option<u16>| {
+            count
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| "N/A".to_string())
+        };
+        let mut items = column![
+            self.info_row("Working Chips", format_count(total_chips)),
+            self.info_row("Expected Chips", format_count(expected_chips)),
+            self.info_row(
+                "Chip Ratio",
+                chip_ratio
+                    .map(|ratio| format!("{:.1}%", ratio * 100.0))
+                    .unwrap_or_else(|| "N/A".to_string())
+            ),
+        ]
+        .spacing(theme::spacing::XS);
+
+        if miner.hashboards.is_empty() {
+            let none: Element<'_, DeviceDetailMessage> =
+                theme::typography::small("No hashboards reported").into();
+            items = items.push(none);
+        }
+        for board in &miner.hashboards {
+            let (bar, label) = chip_ratio_bar(board);
+            items = items.push(self.bar_row(&format!("Board {}", board.position), bar, label));
+        }
+
+        container(CollapsibleSection::new(
+            theme::typography::heading("Chip Statistics"),
+            items,
+            self.is_section_open(SECTION_CHIPS),
+            DeviceDetailMessage::ToggleSection(SECTION_CHIPS),
+        ))
+        .padding(theme::padding::SM)
+        .style(theme::containers::card_adaptive)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// Working chips as a bar colored by the working/expected ratio.
+    fn view_chip_ratio(&self, board: &BoardData) -> Element<'_, DeviceDetailMessage> {
+        let (bar, label) = chip_ratio_bar(board);
+        self.bar_row("Working Chips", bar, label)
+    }
+
+    /// An `info_row` whose value is a bar followed by its reading.
+    fn bar_row<'a>(
+        &self,
+        label: &str,
+        bar: Element<'a, DeviceDetailMessage>,
+        value: String,
+    ) -> Element<'a, DeviceDetailMessage> {
+        row![
+            text(format!("{label}:"))
+                .width(Length::FillPortion(1))
+                .style(|_theme: &iced::Theme| text::Style {
+                    color: Some(theme::colors::TEXT_SECONDARY),
+                }),
+            row![container(bar).width(Length::Fill), text(value)]
+                .spacing(theme::spacing::SM)
+                .align_y(iced::alignment::Vertical::Center)
+                .width(Length::FillPortion(2)),
+        ]
+        .spacing(theme::spacing::XS)
+        .align_y(iced::alignment::Vertical::Center)
+        .into()
     }
 
     fn view_cooling_section(&self, miner: &MinerData) -> Element<'_, DeviceDetailMessage> {
         let mut items = column![
-            theme::typography::heading("Cooling"),
             self.info_row(
                 "Average Temperature",
                 miner
                     .average_temperature
-                    .map(|t| format!("{:.1}°C", t.as_celsius()))
+                    .map(|t| format_temperature(&t, self.temperature_unit))
                     .unwrap_or_else(|| "N/A".to_string()),
-            ),
+            )
         ]
         .spacing(theme::spacing::XS);
 
         if let Some(fluid_temp) = miner.fluid_temperature {
             items = items.push(self.info_row(
                 "Fluid Temperature",
-                format!("{:.1}°C", fluid_temp.as_celsius()),
+                format_temperature(&fluid_temp, self.temperature_unit),
             ));
         }
 
@@ -474,15 +990,46 @@ impl DeviceDetailView {
             }
         }
 
-        container(items)
-            .padding(theme::padding::SM)
-            .style(theme::containers::card)
-            .width(Length::Fill)
-            .into()
+        container(CollapsibleSection::new(
+            theme::typography::heading("Cooling"),
+            items,
+            self.is_section_open(SECTION_COOLING),
+            DeviceDetailMessage::ToggleSection(SECTION_COOLING),
+        ))
+        .padding(theme::padding::SM)
+        .style(theme::containers::card_adaptive)
+        .width(Length::Fill)
+        .into()
     }
 
     fn view_power_section(&self, miner: &MinerData) -> Element<'_, DeviceDetailMessage> {
-        let mut items = column![theme::typography::heading("Power"),].spacing(theme::spacing::XS);
+        let psu_faults: Vec<&str> = miner
+            .messages
+            .iter()
+            .map(|msg| msg.message.as_str())
+            .filter(|message| is_psu_fault(message))
+            .collect();
+
+        let psu_alert: Option<Element<'static, DeviceDetailMessage>> = (!psu_faults.is_empty())
+            .then(|| {
+                tooltip(
+                    container(theme::typography::small("PSU Alert"))
+                        .style(theme::containers::error_adaptive)
+                        .padding([0.0, theme::padding::XS]),
+                    container(theme::typography::small(psu_faults.join("\n")))
+                        .style(theme::containers::tooltip_adaptive)
+                        .padding(theme::padding::SM),
+                    tooltip::Position::Top,
+                )
+                .into()
+            });
+
+        let mut items = column![
+            row![theme::typography::heading("Power"), psu_alert]
+                .spacing(theme::spacing::SM)
+                .align_y(iced::alignment::Vertical::Center)
+        ]
+        .spacing(theme::spacing::XS);
 
         items = items.push(
             self.info_row(
@@ -504,6 +1051,20 @@ impl DeviceDetailView {
             ),
         );
 
+        if let Some(load) = psu_load_pct(miner) {
+            let fraction = (load / 100.0) as f32;
+            items = items.push(
+                self.bar_row(
+                    "PSU Load",
+                    progress_bar(0.0..=1.0, fraction)
+                        .girth(theme::progress_bars::CHIP_BAR_HEIGHT)
+                        .style(theme::progress_bars::psu_load(fraction))
+                        .into(),
+                    format!("{load:.0}%"),
+                ),
+            );
+        }
+
         items = items.push(
             self.info_row(
                 "Efficiency",
@@ -516,14 +1077,13 @@ impl DeviceDetailView {
 
         container(items)
             .padding(theme::padding::SM)
-            .style(theme::containers::card)
+            .style(theme::containers::card_adaptive)
             .width(Length::Fill)
             .into()
     }
 
     fn view_pools_section(&self, miner: &MinerData) -> Element<'_, DeviceDetailMessage> {
-        let mut items =
-            column![theme::typography::heading("Mining Pools"),].spacing(theme::spacing::XS);
+        let mut items = column![].spacing(theme::spacing::XS);
 
         for (idx, pool) in miner.pools.iter().enumerate() {
             let pool_info = column![
@@ -548,6 +1108,22 @@ impl DeviceDetailView {
                     }
                     .to_string()
                 ),
+                self.info_row(
+                    "Rejected",
+                    match (
+                        pool_rejection_pct(pool),
+                        pool.accepted_shares,
+                        pool.rejected_shares
+                    ) {
+                        (Some(pct), Some(accepted), Some(rejected)) =>
+                            format!("{pct:.2}% ({rejected} of {} shares)", accepted + rejected),
+                        // Too few shares for a meaningful rate yet
+                        (None, Some(accepted), Some(rejected)) => {
+                            format!("{rejected} of {} shares", accepted + rejected)
+                        }
+                        _ => "N/A".to_string(),
+                    }
+                ),
             ]
             .spacing(theme::spacing::XS);
 
@@ -573,16 +1149,22 @@ impl DeviceDetailView {
             items = items.push(text("No pools configured"));
         }
 
-        container(items)
+        let section = CollapsibleSection::new(
+            theme::typography::heading("Mining Pools"),
+            items,
+            self.is_section_open(SECTION_POOLS),
+            DeviceDetailMessage::ToggleSection(SECTION_POOLS),
+        );
+
+        container(section)
             .padding(theme::padding::SM)
-            .style(theme::containers::card)
+            .style(theme::containers::card_adaptive)
             .width(Length::Fill)
             .into()
     }
 
     fn view_messages_section<'a>(&self, miner: &'a MinerData) -> Element<'a, DeviceDetailMessage> {
-        let mut items =
-            column![theme::typography::heading("Messages & Alerts"),].spacing(theme::spacing::XS);
+        let mut items = column![].spacing(theme::spacing::XS);
 
         for msg in &miner.messages {
             items = items.push(
@@ -590,11 +1172,110 @@ impl DeviceDetailView {
             );
         }
 
-        container(items)
-            .padding(theme::padding::SM)
-            .style(theme::containers::card)
-            .width(Length::Fill)
-            .into()
+        container(CollapsibleSection::new(
+            theme::typography::heading("Messages & Alerts"),
+            items,
+            self.is_section_open(SECTION_MESSAGES),
+            DeviceDetailMessage::ToggleSection(SECTION_MESSAGES),
+        ))
+        .padding(theme::padding::SM)
+        .style(theme::containers::card_adaptive)
+        .width(Length::Fill)
+        .into()
+    }
+
+    fn view_firmware_version_row(&self, miner: &MinerData) -> Element<'_, DeviceDetailMessage> {
+        let version = miner
+            .firmware_version
+            .clone()
+            .unwrap_or_else(|| "N/A".to_string());
+
+        let link = |label, message| {
+            button(theme::typography::small(label))
+                .style(button::text)
+                .padding(0)
+                .on_press(message)
+        };
+
+        row![
+            text("Firmware Version:")
+                .width(Length::FillPortion(1))
+                .style(|_theme: &iced::Theme| text::Style {
+                    color: Some(theme::colors::TEXT_SECONDARY),
+                }),
+            row![text(version)]
+                .push(
+                    miner
+                        .firmware_version
+                        .as_deref()
+                        .is_some_and(|version| is_eol_firmware(version, &self.eol_firmwares))
+                        .then(|| {
+                            container(theme::typography::tiny("EOL firmware"))
+                                .style(theme::containers::error_adaptive)
+                                .padding([0.0, theme::padding::XS])
+                        })
+                )
+                .push(self.firmware_changelog.is_some().then(|| {
+                    link(
+                        "View Changelog",
+                        DeviceDetailMessage::SetChangelogOpen(true),
+                    )
+                }))
+                .push(
+                    self.firmware_upgrade.is_none().then(|| {
+                        link("Track Upgrade", DeviceDetailMessage::TrackFirmwareUpgrade)
+                    })
+                )
+                .spacing(theme::spacing::SM)
+                .align_y(iced::Alignment::Center)
+                .width(Length::FillPortion(2)),
+        ]
+        .spacing(theme::spacing::XS)
+        .into()
+    }
+
+    /// Scrollable changelog card over a dimmed page; clicking outside closes it.
+    fn view_changelog_modal<'a>(
+        &'a self,
+        miner: &MinerData,
+        changelog: &'a str,
+    ) -> Element<'a, DeviceDetailMessage> {
+        let version = miner.firmware_version.clone().unwrap_or_default();
+        let card = container(
+            column![
+                row![
+                    theme::typography::heading(format!(
+                        "{} {} Changelog",
+                        miner.device_info.firmware, version
+                    )),
+                    Space::new().width(Length::Fill),
+                    secondary_button(
+                        "Close",
+                        None,
+                        Some(DeviceDetailMessage::SetChangelogOpen(false)),
+                    ),
+                ]
+                .align_y(iced::Alignment::Center),
+                scrollable(text(changelog)).height(Length::Fixed(320.0)),
+            ]
+            .spacing(theme::spacing::SM),
+        )
+        .style(theme::containers::card_adaptive)
+        .padding(theme::padding::MD)
+        .max_width(560);
+
+        let backdrop = center(opaque(card)).style(|_theme: &iced::Theme| container::Style {
+            background: Some(
+                iced::Color {
+                    a: 0.6,
+                    ..iced::Color::BLACK
+                }
+                .into(),
+            ),
+            ..container::Style::default()
+        });
+
+        opaque(mouse_area(backdrop).on_press(DeviceDetailMessage::SetChangelogOpen(false)))
     }
 
     fn info_row(
@@ -616,3 +1297,117 @@ impl DeviceDetailView {
         .into()
     }
 }
+
+/// Heading and next-step advice for a failed fetch, split by whether the
+/// device answered at all.
+fn error_guidance(error: &FetchError) -> (&'static str, &'static str) {
+    match error {
+        FetchError::MinerNotFound(_) => (
+            "Device unreachable",
+            "No miner answered at this address. The device may be offline, rebooting, or have a new IP since the last scan.",
+        ),
+        FetchError::MinerDataError(_) => (
+            "Device responded but its data couldn't be read",
+            "The firmware may be unsupported or mid-update. Try opening the web UI to check the miner directly.",
+        ),
+        FetchError::Timeout { .. } => (
+            "Device took too long to respond",
+            "The miner answered but didn't send its data in time. It may be overloaded or mid-reboot; retry, or raise the detail fetch timeout in Settings.",
+        ),
+        FetchError::InvalidPoolConfig(_) | FetchError::Unsupported(_) => (
+            "Request rejected",
+            "The miner didn't accept the change. Check the values and try again, or use the web UI.",
+        ),
+        FetchError::RuntimeCreation(_) | FetchError::FactoryCreation(_) => (
+            "Failed to load miner data",
+            "An internal error prevented contacting the miner. Retry, and restart the app if it persists.",
+        ),
+    }
+}
+
+/// A board's working chips as a bar colored by the working/expected ratio,
+/// and the reading shown next to it.
+fn chip_ratio_bar(board: &BoardData) -> (Element<'static, DeviceDetailMessage>, String) {
+    match (board.working_chips, board.expected_chips) {
+        (Some(working), Some(expected)) if expected > 0 => {
+            let ratio = f32::from(working) / f32::from(expected);
+            (
+                progress_bar(0.0..=1.0, ratio)
+                    .girth(theme::progress_bars::CHIP_BAR_HEIGHT)
+                    .style(theme::progress_bars::chip_ratio(ratio))
+                    .into(),
+                format!("{working}/{expected}"),
+            )
+        }
+        (working, _) => (
+            theme::progress_bars::indeterminate(theme::progress_bars::CHIP_BAR_HEIGHT),
+            working
+                .map(|c| format!("{c}/?"))
+                .unwrap_or_else(|| "N/A".to_string()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_miner;
+
+    fn miner(version: &str) -> MinerData {
+        let mut miner = test_miner("10.0.0.5".parse().unwrap());
+        miner.firmware_version = Some(version.to_string());
+        miner
+    }
+
+    fn loaded_view() -> DeviceDetailView {
+        let mut view = DeviceDetailView::new_loading("10.0.0.5".parse().unwrap());
+        view.update_with_data(Ok(miner("1.0")));
+        view
+    }
+
+    #[test]
+    fn test_upgrade_polled() {
+        let mut view = loaded_view();
+        let generation = view.start_upgrade_tracking(600).unwrap();
+        assert_eq!(view.start_upgrade_tracking(600), None);
+
+        // Same version and still answering: not flashed yet
+        assert!(matches!(
+            view.upgrade_polled(generation, Ok(miner("1.0"))),
+            UpgradePoll::Pending(_)
+        ));
+        // Offline while flashing, then back on the same version
+        let offline = Err(FetchError::MinerNotFound("10.0.0.5".to_string()));
+        assert!(matches!(
+            view.upgrade_polled(generation, offline),
+            UpgradePoll::Pending(_)
+        ));
+        assert_eq!(
+            view.upgrade_polled(generation, Ok(miner("1.0"))),
+            UpgradePoll::Complete(Some("1.0".to_string()))
+        );
+        assert_eq!(
+            view.upgrade_polled(generation, Ok(miner("1.0"))),
+            UpgradePoll::NotTracking
+        );
+    }
+
+    #[test]
+    fn test_stale_upgrade_polls_are_ignored() {
+        let mut view = loaded_view();
+        let stale = view.start_upgrade_tracking(600).unwrap();
+        view.cancel_upgrade_tracking();
+        let current = view.start_upgrade_tracking(600).unwrap();
+        assert_ne!(stale, current);
+
+        // The cancelled tracking's poll neither completes nor keeps polling
+        assert_eq!(
+            view.upgrade_polled(stale, Ok(miner("2.0"))),
+            UpgradePoll::NotTracking
+        );
+        assert_eq!(
+            view.upgrade_polled(current, Ok(miner("2.0"))),
+            UpgradePoll::Complete(Some("2.0".to_string()))
+        );
+    }
+}
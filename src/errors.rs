@@ -10,6 +10,9 @@ pub enum ConfigError {
 
     #[error("IO error: {0}")]
     Io(String),
+
+    #[error("Invalid scan group: {0}")]
+    InvalidGroup(String),
 }
 
 #[derive(Debug, Clone, Error)]
@@ -22,6 +25,50 @@ pub enum ScannerError {
 
     #[error("Thread execution error: {0}")]
     ThreadError(String),
+
+    #[error(
+        "Aborted after {0} unresponsive IPs with no miners found; check the network range and firewall"
+    )]
+    TooManyFailures(usize),
+
+    #[error("Stopped before finishing")]
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum NetworkRangeError {
+    #[error("Network range cannot be empty")]
+    Empty,
+
+    #[error("Invalid CIDR '{input}': {reason}")]
+    InvalidCidr { input: String, reason: CidrError },
+
+    #[error("'{0}' is not a valid IP address")]
+    InvalidIp(String),
+
+    #[error("Range '{input}' starts after it ends")]
+    StartAfterEnd { input: String },
+
+    #[error("'{input}' covers {ip_count} addresses; split it into ranges no larger than a /8")]
+    TooLarge { input: String, ip_count: u128 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CidrError {
+    #[error("'{0}' is not an IP address")]
+    InvalidAddress(String),
+
+    #[error("prefix length '{0}' is not a number")]
+    InvalidPrefix(String),
+
+    #[error("prefix /{prefix} is longer than the {max} bits of the address")]
+    PrefixTooLong { prefix: u8, max: u8 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScheduleError {
+    #[error("Invalid cron expression '{expr}': {reason}")]
+    InvalidCron { expr: String, reason: String },
 }
 
 #[derive(Debug, Clone, Error)]
@@ -37,6 +84,15 @@ pub enum FetchError {
 
     #[error("Failed to get miner data: {0}")]
     MinerDataError(String),
+
+    #[error("Invalid pool configuration: {0}")]
+    InvalidPoolConfig(String),
+
+    #[error("{0} is not supported for this miner")]
+    Unsupported(String),
+
+    #[error("{ip} didn't respond within {seconds}s")]
+    Timeout { ip: String, seconds: u64 },
 }
 
 pub type ConfigResult<T> = Result<T, ConfigError>;
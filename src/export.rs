@@ -0,0 +1,395 @@
+use crate::config::AppConfig;
+use crate::formatters::hashes_per_second;
+use crate::health::HealthReport;
+use asic_rs::data::miner::MinerData;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+
+/// Columns of the asset register, in order
+const ASSET_REGISTER_COLUMNS: [&str; 17] = [
+    "ip",
+    "group",
+    "make",
+    "model",
+    "firmware",
+    "firmware_version",
+    "serial_number",
+    "mac",
+    "rack",
+    "row",
+    "unit",
+    "first_seen_date",
+    "last_seen_date",
+    "notes",
+    "health_status",
+    "avg_hashrate",
+    "avg_wattage",
+];
+
+/// Columns of the scan results export, in order
+const SCAN_RESULTS_COLUMNS: [&str; 6] = [
+    "IP",
+    "Make",
+    "Model",
+    "Firmware",
+    "FirmwareVersion",
+    "GroupName",
+];
+
+/// The given scan results as CSV, one row per miner, sorted by group and IP,
+/// leaving out miners on the config's ignore list. The header is always
+/// written, even when no group found any miners.
+pub fn export_scan_results(
+    results: &HashMap<String, Vec<MinerData>>,
+    config: &AppConfig,
+) -> String {
+    let mut groups: Vec<_> = results.iter().collect();
+    groups.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut csv = SCAN_RESULTS_COLUMNS.join(",");
+    csv.push('\n');
+    for (group, miners) in groups {
+        let mut miners: Vec<_> = miners
+            .iter()
+            .filter(|miner| !config.is_ignored(miner))
+            .collect();
+        miners.sort_by_key(|miner| miner.ip);
+        for miner in miners {
+            let fields = [
+                miner.ip.to_string(),
+                miner.device_info.make.to_string(),
+                miner.device_info.model.to_string(),
+                miner.device_info.firmware.to_string(),
+                miner.firmware_version.clone().unwrap_or_default(),
+                group.clone(),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+    }
+    csv
+}
+
+/// Scans of one miner in the hashrate history
+struct Sightings {
+    first: DateTime<Utc>,
+    last: DateTime<Utc>,
+    hashrates: Vec<f64>,
+}
+
+/// Every miner from the last scan of each group as CSV, for asset audits.
+/// Miners on the ignore list are left out, as in the other reports.
+///
+/// The first seen date comes from `AppConfig::first_seen`, which outlives
+/// old scans. The last seen date and the average hashrate (in H/s) come from
+/// the hashrate history, so they cover only the most recent scans. Wattage
+/// isn't kept there, so `avg_wattage` is the last reading.
+/// The config holds no notes or rack locations, so those columns are empty.
+pub fn export_asset_register(config: &AppConfig) -> String {
+    let mut history: HashMap<IpAddr, Sightings> = HashMap::new();
+    for record in &config.hashrate_history {
+        for (ip, hashrate) in &record.hashrates {
+            let sightings = history.entry(*ip).or_insert(Sightings {
+                first: record.timestamp,
+                last: record.timestamp,
+                hashrates: Vec::new(),
+            });
+            sightings.first = sightings.first.min(record.timestamp);
+            sightings.last = sightings.last.max(record.timestamp);
+            sightings.hashrates.push(*hashrate);
+        }
+    }
+
+    let mut groups: Vec<_> = config.last_scan_results.iter().collect();
+    groups.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut csv = ASSET_REGISTER_COLUMNS.join(",");
+    csv.push('\n');
+    for (group, miners) in groups {
+        let mut miners: Vec<_> = miners
+            .iter()
+            .filter(|miner| !config.is_ignored(miner))
+            .collect();
+        miners.sort_by_key(|miner| miner.ip);
+        for miner in miners {
+            let seen = history.get(&miner.ip);
+            let date = |time: &DateTime<Utc>| time.format("%Y-%m-%d").to_string();
+            let avg_hashrate = match seen {
+                Some(sightings) if !sightings.hashrates.is_empty() => {
+                    Some(sightings.hashrates.iter().sum::<f64>() / sightings.hashrates.len() as f64)
+                }
+                _ => miner.hashrate.as_ref().map(hashes_per_second),
+            };
+            let status = HealthReport::from_miner_data(
+                miner,
+                &config.health_thresholds,
+                config.temperature_unit,
+            )
+            .status;
+
+            let fields = [
+                miner.ip.to_string(),
+                group.clone(),
+                miner.device_info.make.to_string(),
+                miner.device_info.model.to_string(),
+                miner.device_info.firmware.to_string(),
+                miner.firmware_version.clone().unwrap_or_default(),
+                miner.serial_number.clone().unwrap_or_default(),
+                miner.mac.map(|mac| mac.to_string()).unwrap_or_default(),
+                String::new(),
+                String::new(),
+                String::new(),
+                config
+                    .first_seen
+                    .get(&miner.ip)
+                    .into_iter()
+                    .chain(seen.map(|sightings| &sightings.first))
+                    .min()
+                    .map(date)
+                    .unwrap_or_default(),
+                seen.map(|sightings| date(&sightings.last))
+                    .unwrap_or_default(),
+                String::new(),
+                status.label().to_string(),
+                avg_hashrate
+                    .map(|hashrate| format!("{hashrate:.0}"))
+                    .unwrap_or_default(),
+                miner
+                    .wattage
+                    .map(|wattage| format!("{:.0}", wattage.as_watts()))
+                    .unwrap_or_default(),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+    }
+    csv
+}
+
+/// Characters that make spreadsheets read a cell as a formula
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+///
+/// Values come from miners on the network, so one that would start a formula,
+/// like `=HYPERLINK(...)`, is prefixed with `'` to open as plain text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(FORMULA_PREFIXES) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Writes `contents` to `path` so readers only ever see the complete old or new file.
+///
+/// Data goes to a sibling temp file that is synced and then renamed over `path`,
+/// so closing the app or crashing mid-write never leaves a truncated file behind.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_atomic_with(path, contents, || Ok(()))
+}
+
+/// `write_atomic` with a hook that runs between writing the temp file and renaming it.
+fn write_atomic_with(
+    path: &Path,
+    contents: &[u8],
+    before_rename: impl FnOnce() -> io::Result<()>,
+) -> io::Result<()> {
+    let temp = temp_path(path);
+
+    let result = (|| {
+        let mut file = File::create(&temp)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        before_rename()?;
+        fs::rename(&temp, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Hidden temp file next to `path`, so the final rename stays on the same filesystem.
+fn temp_path(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{file_name}.tmp"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ScanRecord;
+    use crate::test_support::{terahash, test_miner};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_interrupted_write_keeps_old_file() {
+        let dir = std::env::temp_dir().join(format!("btc_toolkit_export_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("export.csv");
+
+        write_atomic(&path, b"old contents").unwrap();
+
+        let interrupted = write_atomic_with(&path, b"new contents", || {
+            Err(io::Error::other("interrupted"))
+        });
+        assert!(interrupted.is_err());
+        assert_eq!(fs::read(&path).unwrap(), b"old contents");
+        assert!(!temp_path(&path).exists());
+
+        write_atomic(&path, b"new contents").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"new contents");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_asset_register_header() {
+        let csv = export_asset_register(&AppConfig::default());
+        assert_eq!(csv.lines().count(), 1);
+        assert!(csv.starts_with("ip,group,make,model,"));
+        assert!(
+            csv.trim_end()
+                .ends_with(",health_status,avg_hashrate,avg_wattage")
+        );
+
+        assert_eq!(csv_field("Farm A"), "Farm A");
+        assert_eq!(csv_field("Rack 1, Row 2"), "\"Rack 1, Row 2\"");
+        assert_eq!(csv_field("12\" shelf"), "\"12\"\" shelf\"");
+    }
+
+    #[test]
+    fn test_csv_field_neutralizes_formulas() {
+        assert_eq!(csv_field("=1+2"), "'=1+2");
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-2"), "'-2");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("\tcmd"), "'\tcmd");
+        assert_eq!(csv_field("\r=1"), "\"'\r=1\"");
+        assert_eq!(
+            csv_field("=HYPERLINK(\"http://evil\",\"x\")"),
+            "\"'=HYPERLINK(\"\"http://evil\"\",\"\"x\"\")\""
+        );
+        // Only a leading character counts
+        assert_eq!(csv_field("S19-Pro"), "S19-Pro");
+    }
+
+    #[test]
+    fn test_asset_register_rows() {
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+        let ignored_ip: IpAddr = "10.0.0.9".parse().unwrap();
+
+        let mut miner = test_miner(ip);
+        // A miner can report anything, including a spreadsheet formula
+        miner.serial_number = Some("=cmd|' /C calc'!A0".to_string());
+        miner.firmware_version = Some("2024.1".to_string());
+        miner.hashrate = Some(terahash(100.0));
+        let mut ignored = test_miner(ignored_ip);
+        ignored.serial_number = Some("SN-DEMO".to_string());
+        ignored.hashrate = Some(terahash(100.0));
+
+        let mut config = AppConfig::default();
+        config
+            .last_scan_results
+            .insert("Farm A".to_string(), vec![ignored, miner]);
+        config.ignored_serial_numbers.insert("SN-DEMO".to_string());
+        // Sighted before the oldest scan still in the hashrate history
+        config
+            .first_seen
+            .insert(ip, Utc.with_ymd_and_hms(2023, 11, 20, 8, 0, 0).unwrap());
+        config.hashrate_history = vec![
+            ScanRecord {
+                timestamp: Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap(),
+                hashrates: HashMap::from([(ip, 90e12), (ignored_ip, 90e12)]),
+            },
+            ScanRecord {
+                timestamp: Utc.with_ymd_and_hms(2024, 3, 8, 12, 0, 0).unwrap(),
+                hashrates: HashMap::from([(ip, 110e12)]),
+            },
+        ];
+
+        let csv = export_asset_register(&config);
+        let rows: Vec<&str> = csv.lines().skip(1).collect();
+        assert_eq!(rows.len(), 1, "the ignored miner is left out");
+
+        let fields: Vec<&str> = rows[0].split(',').collect();
+        assert_eq!(fields.len(), ASSET_REGISTER_COLUMNS.len());
+        let column = |name: &str| {
+            let index = ASSET_REGISTER_COLUMNS
+                .iter()
+                .position(|column| *column == name)
+                .unwrap();
+            fields[index]
+        };
+        assert_eq!(column("ip"), "10.0.0.5");
+        assert_eq!(column("group"), "Farm A");
+        assert_eq!(column("serial_number"), "'=cmd|' /C calc'!A0");
+        assert_eq!(column("firmware_version"), "2024.1");
+        assert_eq!(column("first_seen_date"), "2023-11-20");
+        assert_eq!(column("last_seen_date"), "2024-03-08");
+        assert_eq!(column("health_status"), "Healthy");
+        assert_eq!(column("avg_hashrate"), "100000000000000");
+        assert_eq!(column("avg_wattage"), "");
+
+        // The config keeps no notes or rack locations
+        for name in ["notes", "rack", "row", "unit"] {
+            assert_eq!(column(name), "", "{name} column");
+        }
+    }
+
+    #[test]
+    fn test_scan_results_rows() {
+        let mut config = AppConfig::default();
+        config.ignored_serial_numbers.insert("SN-DEMO".to_string());
+        let empty = HashMap::from([("Farm A".to_string(), Vec::new())]);
+        assert_eq!(
+            export_scan_results(&empty, &config),
+            "IP,Make,Model,Firmware,FirmwareVersion,GroupName\n"
+        );
+
+        let mut first = test_miner("10.0.0.5".parse().unwrap());
+        first.firmware_version = Some("2024.1".to_string());
+        let second = test_miner("fd00::5".parse().unwrap());
+        let mut ignored = test_miner("10.0.0.2".parse().unwrap());
+        ignored.serial_number = Some("SN-DEMO".to_string());
+        let results = HashMap::from([
+            ("Farm B".to_string(), vec![second.clone()]),
+            ("Farm A, Hall 1".to_string(), vec![first.clone(), ignored]),
+        ]);
+
+        let csv = export_scan_results(&results, &config);
+        let row = |miner: &MinerData, version: &str, group: &str| {
+            format!(
+                "{},{},{},{},{version},{group}",
+                miner.ip,
+                miner.device_info.make,
+                miner.device_info.model,
+                miner.device_info.firmware
+            )
+        };
+        let rows: Vec<&str> = csv.lines().skip(1).collect();
+        assert_eq!(
+            rows,
+            [
+                row(&first, "2024.1", "\"Farm A, Hall 1\""),
+                row(&second, "", "Farm B"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use asic_rs::data::hashrate::{HashRate, HashRateUnit};
+use measurements::Temperature;
+use serde::{Deserialize, Serialize};
+
+const GIGA: f64 = 1e9;
+const TERA: f64 = 1e12;
+const PETA: f64 = 1e15;
+
+/// Converts a hashrate to plain hashes per second, whatever unit the miner reported.
+pub fn hashes_per_second(hr: &HashRate) -> f64 {
+    hr.clone().as_unit(HashRateUnit::Hash).value
+}
+
+/// Formats a hashrate with the most readable SI prefix, e.g. "104.52 TH/s".
+pub fn format_hashrate(hr: &HashRate) -> String {
+    format_hashes_per_second(hashes_per_second(hr))
+}
+
+/// Formats a raw H/s value as GH/s, TH/s or PH/s with two decimals.
+pub fn format_hashes_per_second(hs: f64) -> String {
+    if hs >= PETA {
+        format!("{:.2} PH/s", hs / PETA)
+    } else if hs >= TERA {
+        format!("{:.2} TH/s", hs / TERA)
+    } else {
+        format!("{:.2} GH/s", hs / GIGA)
+    }
+}
+
+/// Formats an approximate size in megabytes, e.g. "~42 MB" or "~0.5 MB".
+pub fn format_megabytes(mb: f64) -> String {
+    if mb < 10.0 {
+        format!("~{mb:.1} MB")
+    } else {
+        format!("~{mb:.0} MB")
+    }
+}
+
+/// Unit used to display temperatures. Miners report Celsius and health
+/// thresholds are always compared in Celsius; this only affects display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TempUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TempUnit {
+    pub const ALL: [TempUnit; 2] = [TempUnit::Celsius, TempUnit::Fahrenheit];
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+        }
+    }
+
+    /// Converts a Celsius reading into this unit.
+    pub fn convert_celsius(self, celsius: f64) -> f64 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+}
+
+/// Formats a temperature in the given unit with one decimal, e.g. "72.5°C".
+pub fn format_temperature(t: &Temperature, unit: TempUnit) -> String {
+    format!(
+        "{:.1}{}",
+        unit.convert_celsius(t.as_celsius()),
+        unit.symbol()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_hashes_per_second() {
+        assert_eq!(format_hashes_per_second(0.0), "0.00 GH/s");
+        assert_eq!(format_hashes_per_second(500e9), "500.00 GH/s");
+        assert_eq!(format_hashes_per_second(1.2e12), "1.20 TH/s");
+        assert_eq!(format_hashes_per_second(104.523e12), "104.52 TH/s");
+        assert_eq!(format_hashes_per_second(2.5e15), "2.50 PH/s");
+    }
+
+    #[test]
+    fn test_format_megabytes() {
+        assert_eq!(format_megabytes(0.52), "~0.5 MB");
+        assert_eq!(format_megabytes(42.3), "~42 MB");
+        assert_eq!(format_megabytes(134.2), "~134 MB");
+    }
+
+    #[test]
+    fn test_temperature_conversion() {
+        assert_eq!(TempUnit::Celsius.convert_celsius(75.0), 75.0);
+        assert_eq!(TempUnit::Fahrenheit.convert_celsius(0.0), 32.0);
+        assert_eq!(TempUnit::Fahrenheit.convert_celsius(100.0), 212.0);
+        assert_eq!(TempUnit::Fahrenheit.convert_celsius(-40.0), -40.0);
+
+        let t = Temperature::from_celsius(72.5);
+        assert_eq!(format_temperature(&t, TempUnit::Celsius), "72.5°C");
+        assert_eq!(format_temperature(&t, TempUnit::Fahrenheit), "162.5°F");
+    }
+}
@@ -0,0 +1,608 @@
+use crate::formatters::{TempUnit, format_temperature, hashes_per_second};
+use crate::theme::colors;
+use asic_rs::data::miner::MinerData;
+use asic_rs::data::pool::PoolData;
+use iced::Color;
+use serde::{Deserialize, Serialize};
+
+/// Overall health classification for a miner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HealthStatus {
+    Healthy,
+    Warning,
+    Critical,
+    /// Answered the scan and identified itself, but only partial data is in
+    Online,
+    /// Not enough data to assess (e.g. partial scan data)
+    Unknown,
+}
+
+impl HealthStatus {
+    /// Computes the status from full miner data using default thresholds.
+    pub fn from_miner_data(miner: &MinerData) -> Self {
+        HealthReport::from_miner_data(miner, &HealthThresholds::default(), TempUnit::default())
+            .status
+    }
+
+    /// Status from the partial data collected while scanning, which has no
+    /// performance fields: `Online` when the miner reported a firmware version
+    /// and a known make, `Unknown` otherwise.
+    pub fn from_partial_miner_data(miner: &MinerData) -> Self {
+        Self::from_identity(
+            miner.firmware_version.is_some(),
+            &miner.device_info.make.to_string(),
+        )
+    }
+
+    fn from_identity(has_firmware_version: bool, make: &str) -> Self {
+        let make = make.trim();
+        if has_firmware_version && !make.is_empty() && !make.eq_ignore_ascii_case("unknown") {
+            Self::Online
+        } else {
+            Self::Unknown
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Self::Healthy => colors::SUCCESS,
+            Self::Warning => colors::WARNING,
+            Self::Critical => colors::DANGER,
+            Self::Online => colors::ACCENT,
+            Self::Unknown => colors::MINING_IDLE,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Healthy => "Healthy",
+            Self::Warning => "Warning",
+            Self::Critical => "Critical",
+            Self::Online => "Online",
+            Self::Unknown => "Unknown",
+        }
+    }
+
+    /// Severity rank used to pick the worst status; `Unknown` ranks lowest,
+    /// then `Online`, which says less than any assessed status.
+    pub const fn rank(self) -> u8 {
+        match self {
+            Self::Unknown => 0,
+            Self::Online => 1,
+            Self::Healthy => 2,
+            Self::Warning => 3,
+            Self::Critical => 4,
+        }
+    }
+
+    /// Returns the most severe status, or `Unknown` if the iterator is empty.
+    pub fn worst(statuses: impl IntoIterator<Item = Self>) -> Self {
+        statuses
+            .into_iter()
+            .max_by_key(|s| s.rank())
+            .unwrap_or(Self::Unknown)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueCategory {
+    NotMining,
+    Hashrate,
+    Hashboards,
+    Chips,
+    Temperature,
+    Fans,
+    PoolAcceptanceRate,
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthIssue {
+    pub category: IssueCategory,
+    pub severity: HealthStatus,
+    pub message: String,
+}
+
+/// Thresholds used to classify miner health. Percentages are of expected values;
+/// temperatures are always Celsius regardless of display preferences.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthThresholds {
+    pub hashrate_warning_pct: f64,
+    pub hashrate_critical_pct: f64,
+    pub chips_warning_pct: f64,
+    pub chips_critical_pct: f64,
+    pub temp_warning_c: f64,
+    pub temp_critical_c: f64,
+    /// Share rejection rates above these percentages flag a pool
+    pub pool_rejection_warning_pct: f64,
+    pub pool_rejection_critical_pct: f64,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            hashrate_warning_pct: 90.0,
+            hashrate_critical_pct: 70.0,
+            chips_warning_pct: 98.0,
+            chips_critical_pct: 90.0,
+            temp_warning_c: 75.0,
+            temp_critical_c: 85.0,
+            pool_rejection_warning_pct: 2.0,
+            pool_rejection_critical_pct: 10.0,
+        }
+    }
+}
+
+/// Health assessment of a single miner
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub issues: Vec<HealthIssue>,
+}
+
+impl HealthReport {
+    /// Assesses chips, hashrate, temperature and fans from full miner data.
+    ///
+    /// Returns `Unknown` when the data carries no performance fields at all,
+    /// which is the case for the partial data collected during scanning.
+    /// Temperatures in issue messages are shown in `unit`.
+    pub fn from_miner_data(
+        miner: &MinerData,
+        thresholds: &HealthThresholds,
+        unit: TempUnit,
+    ) -> Self {
+        let has_performance_data = miner.hashrate.is_some() || !miner.hashboards.is_empty();
+        if !has_performance_data {
+            return Self {
+                status: HealthStatus::Unknown,
+                issues: Vec::new(),
+            };
+        }
+
+        let mut issues = Vec::new();
+
+        if !miner.is_mining {
+            issues.push(HealthIssue {
+                category: IssueCategory::NotMining,
+                severity: HealthStatus::Critical,
+                message: "Not mining".to_string(),
+            });
+        }
+
+        if let (Some(current), Some(expected)) = (&miner.hashrate, &miner.expected_hashrate)
+            && let Some(severity) = classify_shortfall(
+                hashes_per_second(current),
+                hashes_per_second(expected),
+                thresholds.hashrate_warning_pct,
+                thresholds.hashrate_critical_pct,
+            )
+        {
+            issues.push(HealthIssue {
+                category: IssueCategory::Hashrate,
+                severity,
+                message: format!(
+                    "Low hashrate ({:.0}% of expected)",
+                    hashes_per_second(current) / hashes_per_second(expected) * 100.0
+                ),
+            });
+        }
+
+        if let Some(expected) = miner.expected_hashboards
+            && miner.hashboards.len() < expected as usize
+        {
+            issues.push(HealthIssue {
+                category: IssueCategory::Hashboards,
+                severity: HealthStatus::Critical,
+                message: format!(
+                    "{} of {} hashboards detected",
+                    miner.hashboards.len(),
+                    expected
+                ),
+            });
+        }
+
+        if let (Some(working), Some(expected)) = (miner.total_chips, miner.expected_chips)
+            && let Some(severity) = classify_shortfall(
+                working as f64,
+                expected as f64,
+                thresholds.chips_warning_pct,
+                thresholds.chips_critical_pct,
+            )
+        {
+            issues.push(HealthIssue {
+                category: IssueCategory::Chips,
+                severity,
+                message: format!("{working}/{expected} chips working"),
+            });
+        }
+
+        let hottest = miner
+            .hashboards
+            .iter()
+            .filter_map(|b| b.board_temperature)
+            .chain(miner.average_temperature)
+            .max_by(|a, b| a.as_celsius().total_cmp(&b.as_celsius()));
+
+        if let Some(hottest) = hottest
+            && let Some(severity) = classify_temperature(hottest.as_celsius(), thresholds)
+        {
+            issues.push(HealthIssue {
+                category: IssueCategory::Temperature,
+                severity,
+                message: format!("High temperature ({})", format_temperature(&hottest, unit)),
+            });
+        }
+
+        for fan in &miner.fans {
+            if fan.rpm.is_some_and(|rpm| rpm.as_rpm() <= 0.0) {
+                issues.push(HealthIssue {
+                    category: IssueCategory::Fans,
+                    severity: HealthStatus::Critical,
+                    message: format!("Fan {} stopped", fan.position),
+                });
+            }
+        }
+
+        if let Some(expected) = miner.expected_fans
+            && !miner.fans.is_empty()
+            && miner.fans.len() < expected as usize
+        {
+            issues.push(HealthIssue {
+                category: IssueCategory::Fans,
+                severity: HealthStatus::Warning,
+                message: format!("{} of {} fans detected", miner.fans.len(), expected),
+            });
+        }
+
+        for (idx, pool) in miner.pools.iter().enumerate() {
+            if let Some(pct) = pool_rejection_pct(pool)
+                && let Some(severity) = classify_rejection(pct, thresholds)
+            {
+                issues.push(HealthIssue {
+                    category: IssueCategory::PoolAcceptanceRate,
+                    severity,
+                    message: format!("Pool {} rejecting {pct:.1}% of shares", idx + 1),
+                });
+            }
+        }
+
+        let status = if issues.is_empty() {
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::worst(issues.iter().map(|i| i.severity))
+        };
+
+        Self { status, issues }
+    }
+
+    /// One-line summary such as "Healthy", "1 warning: High temperature (78.2°C)"
+    /// or "2 critical: Not mining; Fan 2 stopped". Critical issues are listed
+    /// before warnings.
+    pub fn summary_text(&self) -> String {
+        if self.status == HealthStatus::Unknown {
+            return "No performance data".to_string();
+        }
+        if self.issues.is_empty() {
+            return HealthStatus::Healthy.label().to_string();
+        }
+
+        [
+            (HealthStatus::Critical, "critical", "critical"),
+            (HealthStatus::Warning, "warning", "warnings"),
+        ]
+        .into_iter()
+        .filter_map(|(severity, singular, plural)| {
+            let messages: Vec<&str> = self
+                .issues
+                .iter()
+                .filter(|issue| issue.severity == severity)
+                .map(|issue| issue.message.as_str())
+                .collect();
+            let noun = if messages.len() == 1 {
+                singular
+            } else {
+                plural
+            };
+            (!messages.is_empty())
+                .then(|| format!("{} {noun}: {}", messages.len(), messages.join("; ")))
+        })
+        .collect::<Vec<_>>()
+        .join(". ")
+    }
+}
+
+/// Current hashrate as a fraction of expected, with its health classification.
+/// Returns `None` when either value is missing, as with partial scan data.
+pub fn hashrate_ratio(
+    miner: &MinerData,
+    thresholds: &HealthThresholds,
+) -> Option<(f64, HealthStatus)> {
+    let current = hashes_per_second(miner.hashrate.as_ref()?);
+    let expected = hashes_per_second(miner.expected_hashrate.as_ref()?);
+    if expected <= 0.0 {
+        return None;
+    }
+
+    let status = classify_shortfall(
+        current,
+        expected,
+        thresholds.hashrate_warning_pct,
+        thresholds.hashrate_critical_pct,
+    )
+    .unwrap_or(HealthStatus::Healthy);
+    Some((current / expected, status))
+}
+
+/// Lowercase fragments of miner messages that point at a power supply fault
+const PSU_FAULT_PATTERNS: [&str; 3] = ["psu", "power supply", "voltage"];
+
+/// True if a miner message looks like a power supply fault.
+pub fn is_psu_fault(message: &str) -> bool {
+    let message = message.to_lowercase();
+    PSU_FAULT_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+/// Power draw as a percentage of the PSU/power limit, when both are reported.
+pub fn psu_load_pct(miner: &MinerData) -> Option<f64> {
+    let watts = miner.wattage?.as_watts();
+    let limit = miner.wattage_limit?.as_watts();
+    (limit > 0.0).then(|| watts / limit * 100.0)
+}
+
+/// Submitted shares needed before a pool's rejection rate means anything;
+/// right after boot or a failover one rejected share would read as 50%
+const MIN_SUBMITTED_SHARES: u64 = 20;
+
+/// Percentage of a pool's shares that were rejected, once it has submitted
+/// at least `MIN_SUBMITTED_SHARES`.
+pub fn pool_rejection_pct(pool: &PoolData) -> Option<f64> {
+    let accepted = pool.accepted_shares?;
+    let rejected = pool.rejected_shares?;
+    let total = accepted + rejected;
+    (total >= MIN_SUBMITTED_SHARES).then(|| rejected as f64 / total as f64 * 100.0)
+}
+
+/// Classifies `actual` as a percentage of `expected` against warning/critical floors.
+fn classify_shortfall(
+    actual: f64,
+    expected: f64,
+    warning_pct: f64,
+    critical_pct: f64,
+) -> Option<HealthStatus> {
+    if expected <= 0.0 {
+        return None;
+    }
+
+    let pct = actual / expected * 100.0;
+    if pct < critical_pct {
+        Some(HealthStatus::Critical)
+    } else if pct < warning_pct {
+        Some(HealthStatus::Warning)
+    } else {
+        None
+    }
+}
+
+fn classify_temperature(celsius: f64, thresholds: &HealthThresholds) -> Option<HealthStatus> {
+    if celsius >= thresholds.temp_critical_c {
+        Some(HealthStatus::Critical)
+    } else if celsius >= thresholds.temp_warning_c {
+        Some(HealthStatus::Warning)
+    } else {
+        None
+    }
+}
+
+fn classify_rejection(pct: f64, thresholds: &HealthThresholds) -> Option<HealthStatus> {
+    if pct > thresholds.pool_rejection_critical_pct {
+        Some(HealthStatus::Critical)
+    } else if pct > thresholds.pool_rejection_warning_pct {
+        Some(HealthStatus::Warning)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{terahash, test_miner};
+    use measurements::Temperature;
+
+    #[test]
+    fn test_classify_shortfall() {
+        assert_eq!(classify_shortfall(100.0, 100.0, 90.0, 70.0), None);
+        assert_eq!(
+            classify_shortfall(85.0, 100.0, 90.0, 70.0),
+            Some(HealthStatus::Warning)
+        );
+        assert_eq!(
+            classify_shortfall(50.0, 100.0, 90.0, 70.0),
+            Some(HealthStatus::Critical)
+        );
+        assert_eq!(classify_shortfall(50.0, 0.0, 90.0, 70.0), None);
+    }
+
+    #[test]
+    fn test_classify_temperature() {
+        let thresholds = HealthThresholds::default();
+        assert_eq!(classify_temperature(60.0, &thresholds), None);
+        assert_eq!(
+            classify_temperature(78.2, &thresholds),
+            Some(HealthStatus::Warning)
+        );
+        assert_eq!(
+            classify_temperature(90.0, &thresholds),
+            Some(HealthStatus::Critical)
+        );
+    }
+
+    #[test]
+    fn test_temperature_issue_uses_display_unit() {
+        let mut miner = test_miner("10.0.0.5".parse().unwrap());
+        miner.hashrate = Some(terahash(100.0));
+        miner.average_temperature = Some(Temperature::from_celsius(90.0));
+
+        let message = |unit| {
+            HealthReport::from_miner_data(&miner, &HealthThresholds::default(), unit)
+                .issues
+                .into_iter()
+                .find(|issue| issue.category == IssueCategory::Temperature)
+                .map(|issue| issue.message)
+        };
+        assert_eq!(
+            message(TempUnit::Celsius).as_deref(),
+            Some("High temperature (90.0°C)")
+        );
+        assert_eq!(
+            message(TempUnit::Fahrenheit).as_deref(),
+            Some("High temperature (194.0°F)")
+        );
+    }
+
+    #[test]
+    fn test_partial_status() {
+        assert_eq!(
+            HealthStatus::from_identity(true, "AntMiner"),
+            HealthStatus::Online
+        );
+        assert_eq!(
+            HealthStatus::from_identity(false, "AntMiner"),
+            HealthStatus::Unknown
+        );
+        assert_eq!(
+            HealthStatus::from_identity(true, "Unknown"),
+            HealthStatus::Unknown
+        );
+        // Any assessed status outranks a miner that was only seen online
+        assert_eq!(
+            HealthStatus::worst([HealthStatus::Online, HealthStatus::Healthy]),
+            HealthStatus::Healthy
+        );
+    }
+
+    #[test]
+    fn test_worst_status() {
+        assert_eq!(HealthStatus::worst([]), HealthStatus::Unknown);
+        assert_eq!(
+            HealthStatus::worst([HealthStatus::Unknown, HealthStatus::Healthy]),
+            HealthStatus::Healthy
+        );
+        assert_eq!(
+            HealthStatus::worst([HealthStatus::Warning, HealthStatus::Critical]),
+            HealthStatus::Critical
+        );
+    }
+
+    #[test]
+    fn test_summary_text() {
+        let issue = |category, severity, message: &str| HealthIssue {
+            category,
+            severity,
+            message: message.to_string(),
+        };
+        let report = |issues: Vec<HealthIssue>| HealthReport {
+            status: HealthStatus::worst(issues.iter().map(|i| i.severity)),
+            issues,
+        };
+
+        let healthy = HealthReport {
+            status: HealthStatus::Healthy,
+            issues: Vec::new(),
+        };
+        assert_eq!(healthy.summary_text(), "Healthy");
+
+        let warm = report(vec![issue(
+            IssueCategory::Temperature,
+            HealthStatus::Warning,
+            "High temperature (78.2°C)",
+        )]);
+        assert_eq!(warm.summary_text(), "1 warning: High temperature (78.2°C)");
+
+        let down = report(vec![
+            issue(
+                IssueCategory::NotMining,
+                HealthStatus::Critical,
+                "Not mining",
+            ),
+            issue(IssueCategory::Fans, HealthStatus::Critical, "Fan 2 stopped"),
+        ]);
+        assert_eq!(down.summary_text(), "2 critical: Not mining; Fan 2 stopped");
+
+        let mixed = report(vec![
+            issue(
+                IssueCategory::Fans,
+                HealthStatus::Warning,
+                "3 of 4 fans detected",
+            ),
+            issue(
+                IssueCategory::NotMining,
+                HealthStatus::Critical,
+                "Not mining",
+            ),
+            issue(
+                IssueCategory::Chips,
+                HealthStatus::Warning,
+                "120/126 chips working",
+            ),
+        ]);
+        assert_eq!(
+            mixed.summary_text(),
+            "1 critical: Not mining. 2 warnings: 3 of 4 fans detected; 120/126 chips working"
+        );
+    }
+
+    #[test]
+    fn test_psu_fault_messages() {
+        assert!(is_psu_fault("PSU fan failure"));
+        assert!(is_psu_fault("Power Supply communication lost"));
+        assert!(is_psu_fault("Input VOLTAGE too low"));
+        assert!(is_psu_fault("chain 2 psu_ctrl error"));
+
+        assert!(!is_psu_fault("Fan speed low"));
+        assert!(!is_psu_fault("Chain 1 temperature too high"));
+        assert!(!is_psu_fault(""));
+    }
+
+    #[test]
+    fn test_pool_rejection() {
+        let pool = |accepted, rejected| PoolData {
+            position: Some(0),
+            url: None,
+            accepted_shares: accepted,
+            rejected_shares: rejected,
+            active: Some(true),
+            alive: Some(true),
+            user: None,
+        };
+        let thresholds = HealthThresholds::default();
+
+        assert_eq!(pool_rejection_pct(&pool(None, Some(5))), None);
+        assert_eq!(pool_rejection_pct(&pool(Some(0), Some(0))), None);
+        assert_eq!(pool_rejection_pct(&pool(Some(95), Some(5))), Some(5.0));
+
+        // Too few shares after boot to judge the pool
+        assert_eq!(pool_rejection_pct(&pool(Some(1), Some(1))), None);
+        let mut booting = test_miner("10.0.0.1".parse().unwrap());
+        booting.hashrate = Some(terahash(100.0));
+        booting.pools = vec![pool(Some(1), Some(1))];
+        let report = HealthReport::from_miner_data(&booting, &thresholds, TempUnit::default());
+        assert_eq!(report.status, HealthStatus::Healthy);
+
+        booting.pools = vec![pool(Some(10), Some(10))];
+        let report = HealthReport::from_miner_data(&booting, &thresholds, TempUnit::default());
+        assert_eq!(report.status, HealthStatus::Critical);
+
+        assert_eq!(classify_rejection(2.0, &thresholds), None);
+        assert_eq!(
+            classify_rejection(5.0, &thresholds),
+            Some(HealthStatus::Warning)
+        );
+        assert_eq!(
+            classify_rejection(10.5, &thresholds),
+            Some(HealthStatus::Critical)
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use crate::config::ScanRun;
+use crate::theme;
+use crate::ui_helpers::{format_duration, secondary_button};
+use chrono::{DateTime, Local, NaiveDate};
+use iced::widget::{
+    Space, button, center, column, container, pick_list, row, scrollable, text_input,
+};
+use iced::{Element, Length};
+use std::collections::BTreeSet;
+
+/// Group filter entry that shows runs of every group
+const ALL_GROUPS: &str = "All groups";
+
+#[derive(Debug, Clone)]
+pub enum HistoryMessage {
+    Close,
+    SearchChanged(String),
+    SelectGroup(String),
+    FromChanged(String),
+    ToChanged(String),
+    ClearFilters,
+    /// Shows or hides the per-group breakdown of the run started at this time
+    ToggleRun(DateTime<chrono::Utc>),
+}
+
+/// Which scan runs the history screen lists.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryFilter {
+    /// Matched case-insensitively against group names and errors
+    pub search: String,
+    pub group: Option<String>,
+    /// Inclusive local dates
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, run: &ScanRun) -> bool {
+        let date = DateTime::<Local>::from(run.started_at).date_naive();
+        if self.from.is_some_and(|from| date < from) || self.to.is_some_and(|to| date > to) {
+            return false;
+        }
+        if let Some(group) = &self.group
+            && !run.groups.iter().any(|g| &g.name == group)
+        {
+            return false;
+        }
+
+        let search = self.search.trim().to_lowercase();
+        search.is_empty()
+            || run.groups.iter().any(|group| {
+                group.name.to_lowercase().contains(&search)
+                    || group
+                        .error
+                        .as_ref()
+                        .is_some_and(|error| error.to_lowercase().contains(&search))
+            })
+    }
+}
+
+/// Runs matching `filter`, newest first.
+pub fn filter_runs<'a>(runs: &'a [ScanRun], filter: &HistoryFilter) -> Vec<&'a ScanRun> {
+    runs.iter()
+        .rev()
+        .filter(|run| filter.matches(run))
+        .collect()
+}
+
+/// Log of every completed scan run, filterable by date range, group and text
+pub struct HistoryView {
+    search: String,
+    group: Option<String>,
+    /// Dates as typed, in YYYY-MM-DD; applied once they parse
+    from: String,
+    to: String,
+    expanded_run: Option<DateTime<chrono::Utc>>,
+}
+
+impl HistoryView {
+    pub fn new() -> Self {
+        Self {
+            search: String::new(),
+            group: None,
+            from: String::new(),
+            to: String::new(),
+            expanded_run: None,
+        }
+    }
+
+    pub fn update(&mut self, message: HistoryMessage) {
+        match message {
+            // Navigation is handled by the app
+            HistoryMessage::Close => {}
+            HistoryMessage::SearchChanged(search) => self.search = search,
+            HistoryMessage::SelectGroup(group) => {
+                self.group = (group != ALL_GROUPS).then_some(group);
+            }
+            HistoryMessage::FromChanged(from) => self.from = from,
+            HistoryMessage::ToChanged(to) => self.to = to,
+            HistoryMessage::ClearFilters => {
+                self.search.clear();
+                self.group = None;
+                self.from.clear();
+                self.to.clear();
+            }
+            HistoryMessage::ToggleRun(started_at) => {
+                self.expanded_run = match self.expanded_run {
+                    Some(expanded) if expanded == started_at => None,
+                    _ => Some(started_at),
+                };
+            }
+        }
+    }
+
+    fn filter(&self) -> HistoryFilter {
+        HistoryFilter {
+            search: self.search.clone(),
+            group: self.group.clone(),
+            from: parse_date(&self.from),
+            to: parse_date(&self.to),
+        }
+    }
+
+    pub fn view<'a>(&'a self, runs: &'a [ScanRun]) -> Element<'a, HistoryMessage> {
+        let header = container(
+            row![
+                secondary_button(
+                    "Back",
+                    Some(theme::icons::back().into()),
+                    Some(HistoryMessage::Close),
+                ),
+                Space::new().width(theme::spacing::SM),
+                theme::typography::title("Scan History"),
+                Space::new().width(Length::Fill),
+                theme::typography::small(format!("{} runs recorded", runs.len())),
+            ]
+            .align_y(iced::Alignment::Center),
+        )
+        .style(theme::containers::header_adaptive)
+        .padding(theme::padding::SM)
+        .width(Length::Fill);
+
+        let filtered = filter_runs(runs, &self.filter());
+        let body: Element<'a, HistoryMessage> = if runs.is_empty() {
+            center(theme::typography::body(
+                "No scans recorded yet. Completed scans appear here.",
+            ))
+            .into()
+        } else if filtered.is_empty() {
+            center(theme::typography::body("No scans match these filters")).into()
+        } else {
+            let list = filtered
+                .into_iter()
+                .fold(column![].spacing(theme::spacing::XS), |list, run| {
+                    list.push(self.view_run(run))
+                });
+            scrollable(list).height(Length::Fill).into()
+        };
+
+        container(
+            column![header, self.view_filters(runs), body]
+                .spacing(theme::spacing::SM)
+                .padding(theme::padding::SM),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_filters(&self, runs: &[ScanRun]) -> Element<'_, HistoryMessage> {
+        let groups: BTreeSet<&str> = runs
+            .iter()
+            .flat_map(|run| run.groups.iter().map(|group| group.name.as_str()))
+            .collect();
+        let options: Vec<String> = std::iter::once(ALL_GROUPS)
+            .chain(groups)
+            .map(str::to_string)
+            .collect();
+        let selected = self.group.clone().unwrap_or_else(|| ALL_GROUPS.to_string());
+
+        let date_input =
+            |value: &str, on_input: fn(String) -> HistoryMessage| {
+                let valid = value.is_empty() || parse_date(value).is_some();
+                column![
+                    text_input("YYYY-MM-DD", value)
+                        .on_input(on_input)
+                        .padding(theme::padding::SM)
+                        .width(Length::Fixed(130.0))
+                ]
+                .push((!valid).then(|| {
+                    theme::typography::tiny("Use YYYY-MM-DD").color(theme::colors::DANGER)
+                }))
+            };
+
+        container(
+            row![
+                text_input("Search groups and errors", &self.search)
+                    .on_input(HistoryMessage::SearchChanged)
+                    .padding(theme::padding::SM),
+                pick_list(options, Some(selected), HistoryMessage::SelectGroup),
+                theme::typography::small("From"),
+                date_input(&self.from, HistoryMessage::FromChanged),
+                theme::typography::small("To"),
+                date_input(&self.to, HistoryMessage::ToChanged),
+                secondary_button("Clear", None, Some(HistoryMessage::ClearFilters)),
+            ]
+            .spacing(theme::spacing::SM)
+            .align_y(iced::Alignment::Center),
+        )
+        .style(theme::containers::card_adaptive)
+        .padding(theme::padding::SM)
+        .width(Length::Fill)
+        .into()
+    }
+
+    fn view_run<'a>(&self, run: &'a ScanRun) -> Element<'a, HistoryMessage> {
+        let expanded = self.expanded_run == Some(run.started_at);
+        let started = DateTime::<Local>::from(run.started_at).format("%Y-%m-%d %H:%M");
+        let group_names: Vec<&str> = run.groups.iter().map(|g| g.name.as_str()).collect();
+        let failed = run.failed_groups();
+
+        let summary = row![
+            theme::typography::mono(started.to_string()).width(Length::FillPortion(2)),
+            theme::typography::small(group_names.join(", ")).width(Length::FillPortion(4)),
+            theme::typography::small(format_duration(run.duration_seconds))
+                .width(Length::FillPortion(1)),
+            theme::typography::small(format!("{} miners", run.miners_found()))
+                .width(Length::FillPortion(1)),
+            if failed > 0 {
+                theme::typography::small(format!("{failed} failed")).color(theme::colors::DANGER)
+            } else {
+                theme::typography::small("")
+            }
+            .width(Length::FillPortion(1)),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::Alignment::Center);
+
+        let mut entry = column![
+            button(summary)
+                .style(theme::buttons::table_row)
+                .padding(theme::padding::SM)
+                .on_press(HistoryMessage::ToggleRun(run.started_at))
+                .width(Length::Fill)
+        ];
+
+        if expanded {
+            let details =
+                run.groups
+                    .iter()
+                    .fold(column![].spacing(theme::spacing::XS), |details, group| {
+                        let outcome = match &group.error {
+                            Some(error) => theme::typography::small(format!(
+                                "{} miners · {error}",
+                                group.miners_found
+                            ))
+                            .color(theme::colors::DANGER),
+                            None => {
+                                theme::typography::small(format!("{} miners", group.miners_found))
+                            }
+                        };
+                        details.push(
+                            row![
+                                theme::typography::small(group.name.clone())
+                                    .width(Length::FillPortion(1)),
+                                outcome.width(Length::FillPortion(3)),
+                            ]
+                            .spacing(theme::spacing::SM),
+                        )
+                    });
+            entry = entry.push(
+                container(details)
+                    .style(theme::containers::card_adaptive)
+                    .padding([theme::padding::XS, theme::padding::MD])
+                    .width(Length::Fill),
+            );
+        }
+
+        entry.into()
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ScanRunGroup;
+    use chrono::{TimeZone, Utc};
+
+    fn run(day: u32, groups: &[(&str, Option<&str>)]) -> ScanRun {
+        ScanRun {
+            started_at: Utc.with_ymd_and_hms(2025, 6, day, 12, 0, 0).unwrap(),
+            duration_seconds: 30,
+            groups: groups
+                .iter()
+                .map(|(name, error)| ScanRunGroup {
+                    name: name.to_string(),
+                    miners_found: 3,
+                    error: error.map(str::to_string),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_filter_runs() {
+        let runs = [
+            run(1, &[("Farm A", None)]),
+            run(2, &[("Farm A", None), ("Farm B", Some("Timed out"))]),
+            run(3, &[("Farm B", None)]),
+        ];
+        let days = |filter: &HistoryFilter| -> Vec<u32> {
+            filter_runs(&runs, filter)
+                .iter()
+                .map(|run| chrono::Datelike::day(&run.started_at))
+                .collect()
+        };
+
+        assert_eq!(days(&HistoryFilter::default()), vec![3, 2, 1]);
+        assert_eq!(
+            days(&HistoryFilter {
+                group: Some("Farm B".to_string()),
+                ..HistoryFilter::default()
+            }),
+            vec![3, 2]
+        );
+        assert_eq!(
+            days(&HistoryFilter {
+                search: "timed".to_string(),
+                ..HistoryFilter::default()
+            }),
+            vec![2]
+        );
+        // Date bounds are local dates, whatever zone the test runs in
+        let local_date = DateTime::<Local>::from(runs[1].started_at).date_naive();
+        assert_eq!(
+            days(&HistoryFilter {
+                from: Some(local_date),
+                to: Some(local_date),
+                ..HistoryFilter::default()
+            }),
+            vec![2]
+        );
+
+        assert_eq!(
+            parse_date(" 2025-06-02 "),
+            NaiveDate::from_ymd_opt(2025, 6, 2)
+        );
+        assert_eq!(parse_date("06/02/2025"), None);
+    }
+}
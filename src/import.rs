@@ -0,0 +1,174 @@
+//! Import of scan groups from asic-rs CLI config files.
+//!
+//! asic-rs has no fixed config schema, so the common JSON shapes are accepted:
+//!
+//! - `{"groups": {"Rack A": ["10.0.0.5", ...], "Rack B": {"ips": [...]}}}`
+//! - `{"miners": ["10.0.0.5", ...]}`
+//! - `["10.0.0.5", ...]`
+//!
+//! Each IP list becomes a scan group whose range lists exactly those
+//! addresses, so nothing outside the list is probed and no listed address is
+//! lost to a covering block's network or broadcast address.
+
+use crate::config::ScanGroup;
+use crate::errors::ConfigError;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::net::Ipv4Addr;
+use std::path::Path;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AsicRsConfig {
+    Groups { groups: BTreeMap<String, IpList> },
+    Miners { miners: IpList },
+    List(IpList),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IpList {
+    Ips(Vec<String>),
+    Object { ips: Vec<String> },
+}
+
+impl IpList {
+    fn into_ips(self) -> Vec<String> {
+        match self {
+            Self::Ips(ips) | Self::Object { ips } => ips,
+        }
+    }
+}
+
+/// Reads an asic-rs config and returns the scan groups it describes, for review
+/// before they are added to the app config.
+pub fn import_asicrs_config(path: &Path) -> Result<Vec<ScanGroup>, ConfigError> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| ConfigError::Io(format!("{}: {e}", path.display())))?;
+    let default_name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "Imported".to_string());
+
+    parse_asicrs_config(&content, &default_name)
+}
+
+fn parse_asicrs_config(content: &str, default_name: &str) -> Result<Vec<ScanGroup>, ConfigError> {
+    let config: AsicRsConfig = serde_json::from_str(content).map_err(|_| {
+        ConfigError::Serialization(
+            "expected a JSON list of IPs, {\"miners\": [...]} or {\"groups\": {...}}".to_string(),
+        )
+    })?;
+
+    let lists = match config {
+        AsicRsConfig::Groups { groups } => groups
+            .into_iter()
+            .map(|(name, list)| (name, list.into_ips()))
+            .collect(),
+        AsicRsConfig::Miners { miners: list } | AsicRsConfig::List(list) => {
+            vec![(default_name.to_string(), list.into_ips())]
+        }
+    };
+
+    let mut groups = Vec::new();
+    for (name, ips) in lists {
+        let ips = ips
+            .iter()
+            .map(|ip| {
+                ip.trim().parse::<Ipv4Addr>().map_err(|_| {
+                    ConfigError::InvalidGroup(format!(
+                        "'{ip}' in \"{name}\" is not an IPv4 address"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        groups.extend(groups_for_ips(&name, &ips));
+    }
+
+    if groups.is_empty() {
+        return Err(ConfigError::InvalidGroup(
+            "no miner IPs found in the file".to_string(),
+        ));
+    }
+    Ok(groups)
+}
+
+/// One group scanning exactly `ips`, sorted and without duplicates, or none
+/// for an empty list.
+fn groups_for_ips(name: &str, ips: &[Ipv4Addr]) -> Vec<ScanGroup> {
+    let mut ips = ips.to_vec();
+    ips.sort();
+    ips.dedup();
+    if ips.is_empty() {
+        return Vec::new();
+    }
+
+    let range: Vec<String> = ips.iter().map(Ipv4Addr::to_string).collect();
+    vec![ScanGroup::new(name.to_string(), range.join(","))]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::expand_network_range;
+
+    #[test]
+    fn test_parse_asicrs_config_shapes() {
+        let groups = parse_asicrs_config(
+            r#"{"groups": {"Rack A": ["10.0.1.5", "10.0.1.200"], "Rack B": {"ips": ["10.0.2.9"]}}}"#,
+            "unused",
+        )
+        .unwrap();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "Rack A");
+        assert_eq!(groups[0].network_range, "10.0.1.5,10.0.1.200");
+        assert_eq!(groups[1].network_range, "10.0.2.9");
+
+        let groups = parse_asicrs_config(
+            r#"["192.168.1.20", "192.168.1.10", "192.168.1.20"]"#,
+            "farm",
+        )
+        .unwrap();
+        assert_eq!(groups[0].name, "farm");
+        assert_eq!(groups[0].network_range, "192.168.1.10,192.168.1.20");
+
+        // Addresses far apart stay one group without covering the space between
+        let groups =
+            parse_asicrs_config(r#"{"miners": ["10.0.0.1", "172.16.5.9"]}"#, "farm").unwrap();
+        let ranges: Vec<_> = groups.iter().map(|g| g.network_range.as_str()).collect();
+        assert_eq!(ranges, ["10.0.0.1,172.16.5.9"]);
+
+        assert!(parse_asicrs_config(r#"{"miners": ["not-an-ip"]}"#, "farm").is_err());
+        assert!(parse_asicrs_config(r#"[]"#, "farm").is_err());
+        assert!(parse_asicrs_config("miners = []", "farm").is_err());
+    }
+
+    #[test]
+    fn test_every_imported_ip_is_scanned() {
+        // Addresses on a block's network or broadcast address, and .0/.255
+        let ips = [
+            "10.0.2.8",
+            "10.0.2.11",
+            "10.0.3.0",
+            "10.0.3.255",
+            "10.0.4.3",
+            "192.168.7.12",
+        ];
+        let content = serde_json::to_string(&ips).unwrap();
+        let groups = parse_asicrs_config(&content, "farm").unwrap();
+
+        let scanned: Vec<_> = groups
+            .iter()
+            .flat_map(|group| expand_network_range(&group.network_range).unwrap())
+            .collect();
+        for ip in ips {
+            assert!(
+                scanned.contains(&ip.parse().unwrap()),
+                "{ip} would not be scanned"
+            );
+        }
+        assert_eq!(scanned.len(), ips.len());
+    }
+}
@@ -0,0 +1,194 @@
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::{Mutex, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Log lines kept for the log panel; older ones are dropped
+pub const MAX_LOG_LINES: usize = 500;
+
+/// Level logged unless `RUST_LOG` says otherwise. wgpu, winit and reqwest
+/// log heavily at debug and trace, which would crowd the app's own events
+/// out of the panel.
+const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::INFO;
+
+/// One event captured for the log panel
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    /// The event's message followed by its other fields as `name=value`
+    pub message: String,
+}
+
+impl LogLine {
+    pub fn to_text(&self) -> String {
+        format!(
+            "{} {:<5} {}",
+            self.time.format("%H:%M:%S"),
+            self.level,
+            self.message
+        )
+    }
+}
+
+static LOG_LINES: OnceLock<Mutex<VecDeque<LogLine>>> = OnceLock::new();
+
+fn log_lines() -> &'static Mutex<VecDeque<LogLine>> {
+    LOG_LINES.get_or_init(|| Mutex::new(VecDeque::with_capacity(MAX_LOG_LINES)))
+}
+
+/// Sends log events to stderr as before, and keeps the latest
+/// `MAX_LOG_LINES` of them for the log panel.
+pub fn init() {
+    let installed = tracing_subscriber::registry()
+        .with(log_filter(std::env::var("RUST_LOG").ok().as_deref()))
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .with(LogBufferLayer)
+        .try_init();
+    if let Err(e) = installed {
+        eprintln!("Failed to set up logging: {e}");
+    }
+}
+
+/// Events to log: `DEFAULT_LOG_LEVEL`, or the directives in `rust_log`
+/// such as `debug` or `btc_toolkit=debug,wgpu=warn`.
+fn log_filter(rust_log: Option<&str>) -> Targets {
+    let default = || Targets::new().with_default(DEFAULT_LOG_LEVEL);
+    let Some(directives) = rust_log.map(str::trim).filter(|d| !d.is_empty()) else {
+        return default();
+    };
+    directives.parse().unwrap_or_else(|e| {
+        eprintln!("Ignoring RUST_LOG={directives}: {e}");
+        default()
+    })
+}
+
+/// The buffered log lines, oldest first.
+pub fn recent() -> Vec<LogLine> {
+    log_lines()
+        .lock()
+        .map(|lines| lines.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// The buffered log as plain text, one event per line, for copying.
+pub fn to_text() -> String {
+    recent()
+        .iter()
+        .map(LogLine::to_text)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn push_capped(lines: &mut VecDeque<LogLine>, line: LogLine, max_lines: usize) {
+    lines.push_back(line);
+    while lines.len() > max_lines {
+        lines.pop_front();
+    }
+}
+
+/// `tracing` layer that copies every event into the log panel's buffer
+pub struct LogBufferLayer;
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut message = MessageVisitor::default();
+        event.record(&mut message);
+        let line = LogLine {
+            time: Local::now(),
+            level: *event.metadata().level(),
+            message: message.0,
+        };
+        if let Ok(mut lines) = log_lines().lock() {
+            push_capped(&mut lines, line, MAX_LOG_LINES);
+        }
+    }
+}
+
+/// Formats an event's message first, then its other fields
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.0);
+            let _ = write!(self.0, "{value:?}{fields}");
+        } else {
+            let _ = write!(self.0, " {}={value:?}", field.name());
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0.insert_str(0, value);
+        } else {
+            let _ = write!(self.0, " {}={value}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(message: &str) -> LogLine {
+        LogLine {
+            time: Local::now(),
+            level: Level::INFO,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_log_buffer_is_capped() {
+        let mut lines = VecDeque::new();
+        for n in 0..5 {
+            push_capped(&mut lines, line(&n.to_string()), 3);
+        }
+        let kept: Vec<&str> = lines.iter().map(|line| line.message.as_str()).collect();
+        assert_eq!(kept, ["2", "3", "4"]);
+    }
+
+    #[test]
+    fn test_events_are_captured() {
+        let subscriber = tracing_subscriber::registry().with(LogBufferLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(ip = "10.0.0.5", "Failed to restart miner: timed out");
+        });
+
+        let captured = recent();
+        let event = captured
+            .iter()
+            .find(|line| line.message.starts_with("Failed to restart miner"))
+            .unwrap();
+        assert_eq!(event.level, Level::WARN);
+        assert_eq!(
+            event.message,
+            "Failed to restart miner: timed out ip=10.0.0.5"
+        );
+        assert!(event.to_text().contains(" WARN  Failed to restart miner"));
+    }
+
+    #[test]
+    fn test_log_filter() {
+        let default = log_filter(None);
+        assert!(default.would_enable("wgpu_core::device", &Level::INFO));
+        assert!(!default.would_enable("wgpu_core::device", &Level::DEBUG));
+        assert!(!log_filter(Some("  ")).would_enable("btc_toolkit", &Level::DEBUG));
+
+        let custom = log_filter(Some("btc_toolkit=debug,wgpu=warn"));
+        assert!(custom.would_enable("btc_toolkit::scanner", &Level::DEBUG));
+        assert!(!custom.would_enable("wgpu_core", &Level::INFO));
+
+        // Unparseable directives fall back to the default
+        let invalid = log_filter(Some("btc_toolkit=loud"));
+        assert!(invalid.would_enable("btc_toolkit", &Level::INFO));
+        assert!(!invalid.would_enable("btc_toolkit", &Level::DEBUG));
+    }
+}
@@ -1,46 +1,100 @@
 // don't open console on windows builds
 #![windows_subsystem = "windows"]
 
+mod alerts;
+mod changes;
 mod config;
 mod device_detail_view;
 mod errors;
+mod export;
+mod formatters;
+mod health;
+mod history_view;
+mod import;
+mod log_buffer;
 mod main_view;
+mod miner_table;
 mod network;
 mod network_config;
+mod notifications;
+mod notify;
+mod power_schedule;
+mod restart_confirmation_view;
+mod settings_view;
 mod sorting;
+mod summary;
+#[cfg(test)]
+mod test_support;
 mod theme;
+mod topology_view;
 mod ui_helpers;
+mod widgets;
 
-use crate::config::AppConfig;
-use crate::device_detail_view::{DeviceDetailMessage, DeviceDetailView};
-use crate::main_view::{MainView, MainViewMessage};
+use crate::alerts::AlertTracker;
+use crate::config::{AppConfig, CONFIG_FILE, ConfigDiff, ScanGroup, hashrate_trend};
+use crate::device_detail_view::{DeviceDetailMessage, DeviceDetailView, UpgradePoll};
+use crate::errors::FetchResult;
+use crate::history_view::{HistoryMessage, HistoryView};
+use crate::main_view::{MainView, MainViewMessage, SORT_SCREEN, ScanProgress};
 use crate::network::scanner::{Scanner, ScannerMessage};
 use crate::network_config::{NetworkConfig, NetworkConfigMessage};
-use iced::{Element, Size, Subscription, Task, Theme, window};
+use crate::notifications::Notification;
+use crate::power_schedule::{DueAction, PowerAction};
+use crate::restart_confirmation_view::{RestartConfirmationMessage, RestartConfirmationView};
+use crate::settings_view::{SettingsMessage, SettingsView};
+use crate::topology_view::{TopologyMessage, TopologyView};
+use asic_rs::data::miner::MinerData;
+use iced::widget::{column, space};
+use iced::{Element, Length, Size, Subscription, Task, Theme, window};
 use mimalloc::MiMalloc;
+use std::collections::HashMap;
 use std::net::IpAddr;
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 // http://github.com/microsoft/mimalloc
 // https://github.com/purpleprotocol/mimalloc_rust
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
+/// How often detail windows refetch their miner's data
+const DETAIL_WINDOW_REFRESH: Duration = Duration::from_secs(10);
+
+/// How often scan results refreshed by detail fetches are written to disk
+const DETAIL_RESULTS_SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often group power schedules are checked for window boundaries
+const POWER_SCHEDULE_TICK: Duration = Duration::from_secs(60);
+
+/// Power schedule boundaries missed this long before startup still fire
+const POWER_SCHEDULE_CATCH_UP_MINUTES: i64 = 15;
+
+/// How often a miner is polled while a firmware upgrade is tracked
+const FIRMWARE_UPGRADE_POLL: Duration = Duration::from_secs(10);
+
+/// How often progress buffered at the start of a scan is applied to the UI
+const SCAN_PROGRESS_TICK: Duration = Duration::from_millis(500);
+
+/// How often the scanning indicator redraws to animate its pulse
+const SCAN_PULSE_TICK: Duration = Duration::from_millis(200);
+
+/// How often the open log panel redraws to show new events
+const LOG_PANEL_REFRESH: Duration = Duration::from_secs(1);
+
 /// Main entry point
 ///
 /// Note: We don't use #[tokio::main] because iced with the "tokio" feature flag
 /// manages its own tokio runtime internally. Using #[tokio::main] would create
 /// a nested runtime situation that causes panics during shutdown.
+///
+/// The app runs as a daemon so device details can be opened in extra windows;
+/// the main window is opened in `boot` and closing it exits the app.
 fn main() -> iced::Result {
-    iced::application(BtcToolkit::boot, update, view)
+    log_buffer::init();
+    iced::daemon(BtcToolkit::boot, update, view)
         .subscription(subscription)
-        .window(window::Settings {
-            size: Size::new(1200.0, 800.0),
-            position: window::Position::Centered,
-            min_size: Some(Size::new(1000.0, 650.0)),
-            ..window::Settings::default()
-        })
         .theme(BtcToolkit::theme)
-        .title("BTC Toolkit")
+        .title(BtcToolkit::title)
         .run()
 }
 
@@ -48,47 +102,739 @@ fn main() -> iced::Result {
 enum Page {
     Main,
     NetworkConfig,
+    Settings,
+    TopologyGrid,
+    History,
+    RestartConfirmation,
     DeviceDetail(IpAddr),
 }
 
+/// A device detail view shown in its own window, refreshed independently
+struct DetailWindow {
+    ip: IpAddr,
+    view: DeviceDetailView,
+    fetches: DetailFetches,
+}
+
+/// Miner fetches a detail view is waiting on. Dropping or clearing it aborts
+/// them, and results of fetches it no longer tracks are discarded as stale.
+#[derive(Default)]
+struct DetailFetches {
+    next_id: u64,
+    running: HashMap<u64, iced::task::Handle>,
+}
+
+impl DetailFetches {
+    /// Makes `task` abortable and tags its result with the fetch's id.
+    fn track(&mut self, task: Task<FetchResult<MinerData>>) -> Task<(u64, FetchResult<MinerData>)> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let (task, handle) = task.abortable();
+        self.running.insert(id, handle.abort_on_drop());
+        task.map(move |result| (id, result))
+    }
+
+    /// Stops tracking fetch `id`, returning whether its result is still wanted.
+    fn finish(&mut self, id: u64) -> bool {
+        self.running.remove(&id).is_some()
+    }
+
+    fn cancel_all(&mut self) {
+        self.running.clear();
+    }
+}
+
 struct BtcToolkit {
+    main_window: window::Id,
+    detail_windows: HashMap<window::Id, DetailWindow>,
     current_page: Page,
     main_view: MainView,
     network_config: NetworkConfig,
+    settings_view: SettingsView,
+    topology_view: TopologyView,
+    history_view: HistoryView,
+    restart_confirmation: Option<RestartConfirmationView>,
     device_detail_view: Option<DeviceDetailView>,
+    /// Fetches for the detail page, cancelled when it's left
+    detail_fetches: DetailFetches,
     active_scan: Option<Vec<network::scanner::ScanGroup>>,
+    /// Single groups started with "Scan now", each running as its own
+    /// subscription so the main scan isn't restarted
+    group_scans: Vec<network::scanner::ScanGroup>,
+    /// Progress of every group in the running scan, synced into the main view
+    scan_progress_by_group: HashMap<String, ScanProgress>,
     app_config: AppConfig,
+    notifications: Vec<Notification>,
+    alerts: AlertTracker,
+    /// End of the span the power schedules were last checked over
+    power_schedule_checked: chrono::DateTime<chrono::Utc>,
+    /// Scan results were refreshed by detail fetches since the last save
+    unsaved_detail_results: bool,
 }
 
 impl BtcToolkit {
     fn boot() -> (Self, Task<BtcToolkitMessage>) {
-        let app_config = AppConfig::load();
+        // Large result files take a while to parse, so the window opens first
+        // and the config arrives as `ConfigLoaded`
+        let app_config = AppConfig::default();
         let mut network_config = NetworkConfig::new();
         network_config.set_app_config(app_config.clone());
 
         let mut main_view = MainView::new();
         main_view.set_app_config(app_config.clone());
 
+        let mut settings_view = SettingsView::new();
+        settings_view.set_app_config(&app_config);
+
+        let (main_window, open_main_window) = window::open(window::Settings {
+            size: Size::new(1200.0, 800.0),
+            position: window::Position::Centered,
+            min_size: Some(Size::new(1000.0, 650.0)),
+            ..window::Settings::default()
+        });
+
         (
             Self {
+                main_window,
+                detail_windows: HashMap::new(),
                 current_page: Page::Main,
                 main_view,
                 network_config,
+                settings_view,
+                topology_view: TopologyView::new(),
+                history_view: HistoryView::new(),
+                restart_confirmation: None,
                 device_detail_view: None,
+                detail_fetches: DetailFetches::default(),
                 active_scan: None,
+                group_scans: Vec::new(),
+                scan_progress_by_group: HashMap::new(),
                 app_config,
+                notifications: Vec::new(),
+                alerts: AlertTracker::new(),
+                power_schedule_checked: chrono::Utc::now()
+                    - chrono::Duration::minutes(POWER_SCHEDULE_CATCH_UP_MINUTES),
+                unsaved_detail_results: false,
             },
-            Task::none(),
+            Task::batch([
+                open_main_window.discard(),
+                Task::perform(
+                    // Only fails if loading panicked
+                    async { tokio::task::spawn_blocking(AppConfig::load).await },
+                    |config| BtcToolkitMessage::ConfigLoaded(Box::new(config.unwrap_or_default())),
+                ),
+            ]),
         )
     }
 
-    fn theme(&self) -> Theme {
+    fn theme(&self, _window: window::Id) -> Theme {
         theme::theme()
     }
 
-    fn save_config(&self) {
+    fn title(&self, window: window::Id) -> String {
+        match self.detail_windows.get(&window) {
+            Some(detail) => format!("BTC Toolkit - {}", detail.ip),
+            None => "BTC Toolkit".to_string(),
+        }
+    }
+
+    /// Opens a detail window for `ip` and starts fetching its data.
+    fn open_detail_window(&mut self, ip: IpAddr) -> Task<BtcToolkitMessage> {
+        let (id, open) = window::open(window::Settings {
+            size: Size::new(1000.0, 750.0),
+            min_size: Some(Size::new(800.0, 500.0)),
+            ..window::Settings::default()
+        });
+
+        let mut view = DeviceDetailView::new_loading(ip);
+        view.set_standalone(true);
+        view.set_temperature_unit(self.app_config.temperature_unit);
+        view.set_section_states(self.app_config.detail_section_states.clone());
+        view.set_eol_firmwares(self.app_config.eol_firmwares.clone());
+        view.set_hashrate_trend(hashrate_trend(&self.app_config.hashrate_history, ip));
+        view.set_scan_in_progress(self.main_view.is_scanning());
+        self.detail_windows.insert(
+            id,
+            DetailWindow {
+                ip,
+                view,
+                fetches: DetailFetches::default(),
+            },
+        );
+
+        let fetch = self.track_detail_fetch(
+            Some(id),
+            Task::perform(
+                network::full_fetch::fetch_full_miner_data_async(
+                    ip,
+                    self.app_config.detail_fetch_timeout(),
+                ),
+                |r| r,
+            ),
+        );
+        Task::batch([open.discard(), fetch])
+    }
+
+    /// Runs a fetch for the detail page (`window` is `None`) or a detail
+    /// window, so it's aborted if the view goes away before it finishes.
+    fn track_detail_fetch(
+        &mut self,
+        window: Option<window::Id>,
+        task: Task<FetchResult<MinerData>>,
+    ) -> Task<BtcToolkitMessage> {
+        let fetches = match window {
+            None => &mut self.detail_fetches,
+            Some(id) => match self.detail_windows.get_mut(&id) {
+                Some(detail) => &mut detail.fetches,
+                None => return Task::none(),
+            },
+        };
+        fetches
+            .track(task)
+            .map(move |(fetch, result)| BtcToolkitMessage::DetailFetched(window, fetch, result))
+    }
+
+    /// Updates the scan notice on the detail page and every detail window.
+    fn set_detail_scan_in_progress(&mut self, scanning: bool) {
+        if let Some(ref mut view) = self.device_detail_view {
+            view.set_scan_in_progress(scanning);
+        }
+        for detail in self.detail_windows.values_mut() {
+            detail.view.set_scan_in_progress(scanning);
+        }
+    }
+
+    /// Pushes the configured temperature unit and section states to the
+    /// detail page and windows.
+    fn apply_detail_preferences(&mut self) {
+        let unit = self.app_config.temperature_unit;
+        let sections = &self.app_config.detail_section_states;
+        let eol_firmwares = &self.app_config.eol_firmwares;
+        if let Some(ref mut view) = self.device_detail_view {
+            view.set_temperature_unit(unit);
+            view.set_section_states(sections.clone());
+            view.set_eol_firmwares(eol_firmwares.clone());
+        }
+        for detail in self.detail_windows.values_mut() {
+            detail.view.set_temperature_unit(unit);
+            detail.view.set_section_states(sections.clone());
+            detail.view.set_eol_firmwares(eol_firmwares.clone());
+        }
+    }
+
+    /// Remembers the miner's API version for later batch operation pre-checks.
+    fn remember_api_version(&mut self, result: &FetchResult<MinerData>) {
+        if let Ok(miner) = result
+            && let Some(version) = &miner.api_version
+            && self.app_config.api_version(miner.ip) != Some(version.as_str())
+        {
+            self.edit_config(|config| config.record_api_version(miner.ip, version));
+        }
+    }
+
+    /// Applies a scanner message from the main scan or a "Scan now" scan.
+    fn handle_scanner_message(&mut self, scanner_msg: ScannerMessage) -> Task<BtcToolkitMessage> {
+        match scanner_msg {
+            ScannerMessage::MinerDiscovered { group_name, miner } => {
+                let first = self.announce_first_discovery(&group_name, miner.ip);
+                self.group_progress(&group_name).miners_found += 1;
+                let _ = self
+                    .main_view
+                    .update(MainViewMessage::MinerFound { group_name, miner });
+                return first;
+            }
+            ScannerMessage::MinersDiscovered { group_name, miners } => {
+                let first = match miners.first() {
+                    Some(miner) => self.announce_first_discovery(&group_name, miner.ip),
+                    None => Task::none(),
+                };
+                self.group_progress(&group_name).miners_found += miners.len();
+                let _ = self
+                    .main_view
+                    .update(MainViewMessage::MinersFound { group_name, miners });
+                return first;
+            }
+            ScannerMessage::IpScanned {
+                group_name,
+                total_ips,
+                scanned_count,
+            } => {
+                let progress = self.group_progress(&group_name);
+                progress.total_ips = total_ips;
+                progress.scanned_ips = scanned_count;
+                let _ = self.main_view.update(MainViewMessage::SyncProgress(
+                    self.scan_progress_by_group.clone(),
+                ));
+            }
+            ScannerMessage::ScanSpeed {
+                group_name,
+                ips_per_second,
+            } => {
+                let _ = self
+                    .main_view
+                    .update(MainViewMessage::ScanSpeed(group_name, ips_per_second));
+            }
+            ScannerMessage::GroupScanCompleted { group_name, result } => match result {
+                Ok(()) => {
+                    let _ = self
+                        .main_view
+                        .update(MainViewMessage::GroupCompleted(group_name.clone()));
+                    return self.reverse_lookup_group(&group_name);
+                }
+                Err(error) => {
+                    let _ = self
+                        .main_view
+                        .update(MainViewMessage::GroupError { group_name, error });
+                }
+            },
+            ScannerMessage::AllScansCompleted => {
+                // With "Scan now" scans running alongside the main one, only
+                // the last to finish completes the scan
+                if !self.main_view.is_scanning() || self.main_view.has_groups_in_progress() {
+                    return Task::none();
+                }
+                let _ = self.main_view.update(MainViewMessage::AllScansCompleted);
+                self.sync_scan_data();
+                self.set_detail_scan_in_progress(false);
+                // Saved even without persisted results so the scan history
+                // keeps this run; results are left out of the file then
+                self.save_config();
+                return Task::batch([self.post_completion_webhook(), self.run_post_scan_command()]);
+            }
+        }
+        Task::none()
+    }
+
+    /// Notifies that the scan found its first miner, at `ip`, when that's
+    /// turned on. Call before counting the discovery.
+    fn announce_first_discovery(
+        &mut self,
+        group_name: &str,
+        ip: IpAddr,
+    ) -> Task<BtcToolkitMessage> {
+        // Progress is reset when a scan starts, so no miners means none yet
+        let first = self
+            .scan_progress_by_group
+            .values()
+            .all(|progress| progress.miners_found == 0);
+        if !self.app_config.notify_first_discovery || !first {
+            return Task::none();
+        }
+
+        let message = format!("First miner found at {ip} in {group_name}");
+        self.notify(Notification::success(message.clone()));
+        Task::future(async move {
+            let shown = tokio::task::spawn_blocking(move || {
+                notify::show_desktop_notification("Scan found a miner", &message)
+            })
+            .await;
+            if let Ok(Err(e)) = shown {
+                tracing::warn!("Failed to show desktop notification: {e}");
+            }
+        })
+        .discard()
+    }
+
+    /// Resolves PTR names of the group's miners not looked up yet this
+    /// session, feeding each into the main view as it arrives.
+    fn reverse_lookup_group(&mut self, group_name: &str) -> Task<BtcToolkitMessage> {
+        if !self.app_config.reverse_dns {
+            return Task::none();
+        }
+        let ips = self.main_view.take_unresolved_ips(group_name);
+        if ips.is_empty() {
+            return Task::none();
+        }
+        Task::run(network::reverse_lookup_all(ips), |(ip, name)| {
+            BtcToolkitMessage::MainView(MainViewMessage::ReverseDnsResolved(ip, name))
+        })
+    }
+
+    /// Acts on a firmware upgrade poll of the detail view showing `ip`:
+    /// reports the outcome, or shows progress and polls again. `wrap`
+    /// addresses messages to that view.
+    fn handle_upgrade_poll(
+        &mut self,
+        ip: IpAddr,
+        generation: u64,
+        poll: UpgradePoll,
+        wrap: impl Fn(DeviceDetailMessage) -> BtcToolkitMessage + Clone + Send + 'static,
+    ) -> Task<BtcToolkitMessage> {
+        match poll {
+            UpgradePoll::Complete(version) => {
+                self.notify(Notification::success(format!(
+                    "Upgrade Complete: {ip} is running firmware {}",
+                    version.as_deref().unwrap_or("of unknown version")
+                )));
+                Task::none()
+            }
+            UpgradePoll::Pending(progress) => {
+                let poll_wrap = wrap.clone();
+                let timeout = self.app_config.detail_fetch_timeout();
+                Task::batch([
+                    Task::done(wrap(DeviceDetailMessage::FirmwareUpdateProgress(progress))),
+                    poll_firmware_upgrade(ip, timeout).map(move |result| {
+                        poll_wrap(DeviceDetailMessage::FirmwareUpgradePolled(
+                            generation, result,
+                        ))
+                    }),
+                ])
+            }
+            UpgradePoll::TimedOut => {
+                self.notify(Notification::warning(format!(
+                    "{ip} hasn't come back from its firmware upgrade; check it in the web UI"
+                )));
+                Task::none()
+            }
+            UpgradePoll::NotTracking => Task::none(),
+        }
+    }
+
+    /// Progress entry for `group_name`, started now if the group has none yet.
+    fn group_progress(&mut self, group_name: &str) -> &mut ScanProgress {
+        self.scan_progress_by_group
+            .entry(group_name.to_string())
+            .or_insert_with(|| ScanProgress::new(Instant::now()))
+    }
+
+    /// Scan results by group for reports, leaving out test and demo miners.
+    fn reported_results(&self) -> HashMap<String, Vec<MinerData>> {
+        self.app_config
+            .get_all_scan_results()
+            .iter()
+            .map(|(group, miners)| {
+                let counted = miners
+                    .iter()
+                    .filter(|miner| !self.app_config.is_ignored(miner))
+                    .cloned()
+                    .collect();
+                (group.clone(), counted)
+            })
+            .collect()
+    }
+
+    /// Sends the scan summary to the configured completion webhook, if any.
+    /// Failures are only logged; the webhook never blocks or fails a scan.
+    fn post_completion_webhook(&self) -> Task<BtcToolkitMessage> {
+        let Some(url) = self.app_config.completion_webhook.clone() else {
+            return Task::none();
+        };
+        let user_agent = self.app_config.user_agent().to_string();
+        let proxy = self.app_config.proxy.clone();
+        let summary = notify::ScanSummary::from_results(
+            &self.reported_results(),
+            &self.app_config.health_thresholds,
+        );
+
+        Task::future(async move {
+            if let Err(e) = notify::post_summary(url, user_agent, proxy, summary).await {
+                tracing::warn!("Failed to post scan summary to webhook: {e}");
+            }
+        })
+        .discard()
+    }
+
+    /// Runs the configured post-scan command, if any, with the results JSON.
+    /// Like the webhook, its output and failures are only logged.
+    fn run_post_scan_command(&self) -> Task<BtcToolkitMessage> {
+        let Some(command) = self.app_config.post_scan_command.clone() else {
+            return Task::none();
+        };
+        let results_json = match serde_json::to_string(&self.reported_results()) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::error!("Failed to serialize results for the post-scan command: {e}");
+                return Task::none();
+            }
+        };
+
+        Task::future(async move {
+            match notify::run_post_scan_command(command, results_json).await {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => tracing::info!("Post-scan command output: {output}"),
+                Err(e) => tracing::warn!("Post-scan command failed: {e}"),
+            }
+        })
+        .discard()
+    }
+
+    /// Writes a successful detail fetch back into the stored scan results, in
+    /// place within its group, so the fleet view's health and stats use the
+    /// full data. Skipped mid-scan, since the scan replaces those results.
+    ///
+    /// Detail windows refetch every few seconds, so this only updates memory;
+    /// the file is written on the next save, at the latest after
+    /// `DETAIL_RESULTS_SAVE_INTERVAL` or when the app closes.
+    fn store_detail_result(&mut self, result: &FetchResult<MinerData>) {
+        let Ok(miner) = result else {
+            return;
+        };
+        if self.main_view.is_scanning()
+            || !self.main_view.app_config_mut().update_scan_result(miner)
+        {
+            return;
+        }
+        self.app_config.update_scan_result(miner);
+        self.network_config
+            .app_config_mut()
+            .update_scan_result(miner);
+        self.unsaved_detail_results |= self.app_config.persist_results;
+    }
+
+    /// Notifies about critical issues in freshly fetched data, at most once per
+    /// miner and issue within the configured cooldown.
+    fn raise_health_alerts(&mut self, result: &FetchResult<MinerData>) {
+        let Ok(miner) = result else {
+            return;
+        };
+        let config = &self.app_config;
+        let issues = self.alerts.evaluate(
+            miner,
+            &config.health_thresholds,
+            config.temperature_unit,
+            config.alert_cooldown_seconds,
+        );
+        for issue in issues {
+            self.notify(Notification::error(format!(
+                "{}: {}",
+                miner.ip, issue.message
+            )));
+        }
+    }
+
+    /// Bundled changelog for the firmware version in a fetch result.
+    fn firmware_changelog_for(&self, result: &FetchResult<MinerData>) -> Option<String> {
+        let version = result.as_ref().ok()?.firmware_version.as_deref()?;
+        self.app_config
+            .firmware_changelog(version)
+            .map(str::to_owned)
+    }
+
+    /// Opens the miner's web UI, reporting unreachable miners as a notification.
+    fn open_miner_in_browser(&self, ip: IpAddr) -> Task<BtcToolkitMessage> {
+        let config = self.main_view.get_app_config();
+        Task::perform(
+            ui_helpers::open_miner_url(
+                ip,
+                config.management_port_for(ip),
+                config.browser_command.clone(),
+            ),
+            BtcToolkitMessage::MinerUrlOpened,
+        )
+    }
+
+    fn save_config(&mut self) {
+        self.unsaved_detail_results = false;
         if let Err(e) = self.app_config.save() {
-            eprintln!("Failed to save config: {e}");
+            tracing::error!("Failed to save config: {e}");
+        }
+    }
+
+    /// Replaces the active config and propagates it to every sub-component.
+    fn apply_config(&mut self, config: AppConfig) {
+        self.network_config.set_app_config(config.clone());
+        self.main_view.set_app_config(config.clone());
+        self.main_view.reconcile_groups(&[]);
+        self.settings_view.set_app_config(&config);
+        self.app_config = config;
+        self.apply_detail_preferences();
+    }
+
+    /// Applies an in-place edit to every config copy and persists it.
+    ///
+    /// Unlike `apply_config`, this keeps scan results stored by MainView and
+    /// pending edits in NetworkConfig intact.
+    fn edit_config(&mut self, update: impl Fn(&mut AppConfig)) {
+        update(&mut self.app_config);
+        update(self.main_view.app_config_mut());
+        update(self.network_config.app_config_mut());
+        self.settings_view.set_app_config(&self.app_config);
+        self.save_config();
+    }
+
+    /// Copies scan results and history from MainView into the other config copies.
+    ///
+    /// NetworkConfig may hold unsaved group edits, so only scan-derived fields are
+    /// replaced there; otherwise saving it later would discard the new results.
+    fn sync_scan_data(&mut self) {
+        self.app_config = self.main_view.get_app_config().clone();
+
+        let network_config = self.network_config.app_config_mut();
+        network_config.last_scan_results = self.app_config.last_scan_results.clone();
+        network_config.scan_history_summary = self.app_config.scan_history_summary.clone();
+        network_config.hashrate_history = self.app_config.hashrate_history.clone();
+        network_config.first_seen = self.app_config.first_seen.clone();
+        network_config.miner_count_history = self.app_config.miner_count_history.clone();
+        network_config.last_errors = self.app_config.last_errors.clone();
+    }
+
+    fn notify(&mut self, notification: Notification) {
+        notifications::push(&mut self.notifications, notification);
+    }
+
+    /// Flashes the fault light on `ip` and reports back through `to_message`
+    /// once it's off again.
+    fn start_identify(
+        &mut self,
+        ip: IpAddr,
+        to_message: impl Fn(DeviceDetailMessage) -> BtcToolkitMessage + Send + 'static,
+    ) -> Task<BtcToolkitMessage> {
+        self.notify(Notification::info(format!(
+            "Fault light flashing on {ip} for {}",
+            ui_helpers::format_duration(network::full_fetch::IDENTIFY_DURATION.as_secs())
+        )));
+        Task::perform(
+            network::full_fetch::identify_miner_async(ip),
+            move |result| to_message(DeviceDetailMessage::IdentifyFinished(result)),
+        )
+    }
+
+    fn identify_finished(&mut self, result: FetchResult<bool>) {
+        if let Err(e) = result {
+            self.notify(Notification::error(format!(
+                "Couldn't control the fault light: {e}"
+            )));
+        }
+    }
+
+    /// Fires the power schedule boundaries that came due since the last check
+    /// on each group's last known miners, and records them so they don't
+    /// fire again.
+    fn run_power_schedules(&mut self) -> Task<BtcToolkitMessage> {
+        let now = chrono::Utc::now();
+        let due = power_schedule::due_actions(
+            &self.app_config.scan_groups,
+            self.power_schedule_checked,
+            now,
+            &self.app_config.power_schedule_fired,
+        );
+        self.power_schedule_checked = now;
+        if due.is_empty() {
+            return Task::none();
+        }
+
+        self.edit_config(|config| {
+            for action in &due {
+                config
+                    .power_schedule_fired
+                    .insert(action.key.clone(), action.minute);
+            }
+        });
+
+        let mut tasks = Vec::new();
+        for DueAction { group, action, .. } in due {
+            let ips: Vec<IpAddr> = self
+                .app_config
+                .last_scan_results
+                .get(&group)
+                .into_iter()
+                .flatten()
+                .filter(|miner| !self.app_config.is_ignored(miner))
+                .map(|miner| miner.ip)
+                .collect();
+            if ips.is_empty() {
+                self.notify(Notification::warning(format!(
+                    "Power schedule: no known miners in {group} to {action}; scan the group first"
+                )));
+                continue;
+            }
+            tasks.push(Task::perform(
+                network::full_fetch::batch_command_async(ips, action),
+                move |results| BtcToolkitMessage::PowerScheduleDone(group.clone(), action, results),
+            ));
+        }
+        Task::batch(tasks)
+    }
+
+    /// Re-reads the config file from disk, reporting which groups changed.
+    fn reload_config(&mut self) {
+        self.replace_config_from(Path::new(CONFIG_FILE), "reloaded");
+    }
+
+    /// Restores a backup and writes it back as the active config file. The
+    /// overwritten config is itself backed up, so a restore can be undone.
+    fn restore_backup(&mut self, backup: &Path) {
+        if self.replace_config_from(backup, "restored from backup") {
+            self.save_config();
+            self.settings_view.refresh_backups();
+        }
+    }
+
+    /// Replaces the config with the defaults after backing up the file,
+    /// keeping scan results and history if `keep_results` is set.
+    fn reset_config(&mut self, keep_results: bool) {
+        if self.active_scan.is_some() || !self.group_scans.is_empty() {
+            self.notify(Notification::warning(
+                "Stop the active scan before resetting the configuration",
+            ));
+            return;
+        }
+
+        let backup = if Path::new(CONFIG_FILE).exists() {
+            match self.app_config.back_up_file(CONFIG_FILE) {
+                Ok(backup) => Some(backup),
+                Err(e) => {
+                    self.notify(Notification::error(format!(
+                        "Reset cancelled; the current configuration couldn't be backed up: {e}"
+                    )));
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+
+        // MainView holds the latest scan results
+        let config = self
+            .main_view
+            .get_app_config()
+            .reset_to_defaults(keep_results);
+        self.apply_config(config);
+        self.settings_view.set_unsaved_network_changes(false);
+        self.save_config();
+        self.settings_view.refresh_backups();
+        self.notify(Notification::info(match backup {
+            Some(backup) => format!(
+                "Configuration reset to defaults. The previous file was backed up to {}",
+                backup.display()
+            ),
+            None => "Configuration reset to defaults".to_string(),
+        }));
+    }
+
+    /// Loads a config from `path` and applies it. Returns true on success.
+    fn replace_config_from(&mut self, path: &Path, action: &str) -> bool {
+        if self.active_scan.is_some() || !self.group_scans.is_empty() {
+            self.notify(Notification::warning(
+                "Stop the active scan before replacing the configuration",
+            ));
+            return false;
+        }
+
+        match AppConfig::load_from_file(path) {
+            Ok(config) => {
+                if self.network_config.has_unsaved_changes(&self.app_config) {
+                    self.notify(Notification::warning(
+                        "Unsaved network configuration edits were discarded",
+                    ));
+                }
+
+                let diff = ConfigDiff::between(&self.app_config.scan_groups, &config.scan_groups);
+                self.apply_config(config);
+                self.settings_view.set_unsaved_network_changes(false);
+                self.notify(Notification::info(format!(
+                    "Configuration {action}. {}",
+                    diff.summary()
+                )));
+                true
+            }
+            Err(e) => {
+                self.notify(Notification::error(format!(
+                    "Failed to load configuration from {}: {e}",
+                    path.display()
+                )));
+                false
+            }
         }
     }
 }
@@ -98,33 +844,313 @@ enum BtcToolkitMessage {
     MainView(MainViewMessage),
     NetworkConfig(NetworkConfigMessage),
     DeviceDetail(DeviceDetailMessage),
+    Settings(SettingsMessage),
+    Topology(TopologyMessage),
+    History(HistoryMessage),
+    RestartConfirmation(RestartConfirmationMessage),
+    /// Outcome for every miner of a fleet-wide restart
+    BatchRestartFinished(Vec<(IpAddr, FetchResult<bool>)>),
+    PowerScheduleTick,
+    /// Writes scan results refreshed by detail fetches to disk
+    SaveDetailResults,
+    /// Results of a scheduled power action on the named group's miners
+    PowerScheduleDone(String, PowerAction, Vec<(IpAddr, FetchResult<bool>)>),
+    MinerUrlOpened(Result<(), String>),
     Scanner(ScannerMessage),
+    /// A message from the "Scan now" scan of the named group
+    GroupScan(String, ScannerMessage),
+    /// Opens the device detail for a miner in a separate window
+    OpenDetailInNewWindow(IpAddr),
+    /// A message from the detail view shown in the given window
+    WindowEvent(window::Id, DeviceDetailMessage),
+    /// A tracked detail fetch finished, for the page (`None`) or a window
+    DetailFetched(Option<window::Id>, u64, FetchResult<MinerData>),
+    WindowClosed(window::Id),
+    ReloadConfig,
+    /// The config file finished loading at startup
+    ConfigLoaded(Box<AppConfig>),
+    DismissNotification(usize),
+}
+
+/// Refetches the miner after `FIRMWARE_UPGRADE_POLL` to see whether a
+/// tracked firmware upgrade has finished.
+fn poll_firmware_upgrade(ip: IpAddr, timeout: Duration) -> Task<FetchResult<MinerData>> {
+    Task::perform(
+        async move {
+            tokio::time::sleep(FIRMWARE_UPGRADE_POLL).await;
+            network::full_fetch::fetch_full_miner_data_async(ip, timeout).await
+        },
+        |result| result,
+    )
+}
+
+/// Builds the task for a detail view action that talks to the miner at `ip`.
+///
+/// Control actions refetch the data afterwards so the view reflects the new
+/// state. Returns `None` for messages that are not miner requests.
+fn detail_request(
+    ip: IpAddr,
+    timeout: Duration,
+    message: &DeviceDetailMessage,
+) -> Option<Task<FetchResult<MinerData>>> {
+    use network::full_fetch::{
+        fetch_full_miner_data_async, pause_mining_async, resume_mining_async,
+        toggle_fault_light_async,
+    };
+
+    let task = match message {
+        DeviceDetailMessage::Refresh => {
+            Task::perform(fetch_full_miner_data_async(ip, timeout), |r| r)
+        }
+        DeviceDetailMessage::PauseMining => Task::perform(
+            async move {
+                let _ = pause_mining_async(ip).await;
+                fetch_full_miner_data_async(ip, timeout).await
+            },
+            |r| r,
+        ),
+        DeviceDetailMessage::ResumeMining => Task::perform(
+            async move {
+                let _ = resume_mining_async(ip).await;
+                fetch_full_miner_data_async(ip, timeout).await
+            },
+            |r| r,
+        ),
+        DeviceDetailMessage::ToggleFaultLight => Task::perform(
+            async move {
+                let _ = toggle_fault_light_async(ip).await;
+                fetch_full_miner_data_async(ip, timeout).await
+            },
+            |r| r,
+        ),
+        _ => return None,
+    };
+    Some(task)
 }
 
 fn update(state: &mut BtcToolkit, message: BtcToolkitMessage) -> Task<BtcToolkitMessage> {
     match message {
         BtcToolkitMessage::MainView(message) => match message.clone() {
+            // Scanning the placeholder config would race the real one loading
+            MainViewMessage::StartScan
+            | MainViewMessage::ScanGroupNow(_)
+            | MainViewMessage::ScanLocalSubnets
+                if state.main_view.is_config_loading() =>
+            {
+                Task::none()
+            }
+
+            MainViewMessage::SortColumn(_) => {
+                let task = state.main_view.update(message);
+                if let Some((column, direction)) = state.main_view.sort_state() {
+                    state.edit_config(|config| {
+                        config.set_sort_state(SORT_SCREEN, column, direction)
+                    });
+                }
+                task.map(BtcToolkitMessage::MainView)
+            }
+
             MainViewMessage::OpenNetworkConfig | MainViewMessage::AddGroup => {
                 state.current_page = Page::NetworkConfig;
                 Task::none()
             }
 
+            MainViewMessage::OpenSettings => {
+                let unsaved = state.network_config.has_unsaved_changes(&state.app_config);
+                state.settings_view.set_unsaved_network_changes(unsaved);
+                state.settings_view.refresh_backups();
+                state.current_page = Page::Settings;
+                Task::none()
+            }
+
+            MainViewMessage::ExportCsv => {
+                let path = format!(
+                    "btc_toolkit_scan_{}.csv",
+                    chrono::Local::now().format("%Y%m%d_%H%M%S")
+                );
+                let csv = export::export_scan_results(
+                    state.main_view.current_results(),
+                    &state.app_config,
+                );
+                match export::write_atomic(Path::new(&path), csv.as_bytes()) {
+                    Ok(()) => state.notify(Notification::success(format!(
+                        "Scan results exported to {path}"
+                    ))),
+                    Err(e) => state.notify(Notification::warning(format!(
+                        "Failed to export the scan results: {e}"
+                    ))),
+                }
+                Task::none()
+            }
+
+            MainViewMessage::CopyLog => {
+                state.notify(Notification::info("Log copied to the clipboard"));
+                iced::clipboard::write(log_buffer::to_text())
+            }
+
+            MainViewMessage::OpenHistory => {
+                state.current_page = Page::History;
+                Task::none()
+            }
+
+            MainViewMessage::OpenTopology => {
+                // Keep the previous selection if that group still exists
+                let groups = &state.app_config.scan_groups;
+                let group = state
+                    .topology_view
+                    .selected_group()
+                    .and_then(|name| groups.iter().find(|g| g.name == name))
+                    .or_else(|| groups.first());
+                if let Some(group) = group {
+                    state.topology_view.select_group(group);
+                }
+                state.current_page = Page::TopologyGrid;
+                Task::none()
+            }
+
+            MainViewMessage::OpenIpInBrowser(ip) => state.open_miner_in_browser(ip),
+
+            MainViewMessage::BatchRestartAll => {
+                let miners = state.main_view.current_results().values().flatten();
+                state.restart_confirmation = Some(RestartConfirmationView::new(
+                    miners,
+                    state.app_config.average_restart_seconds,
+                ));
+                state.current_page = Page::RestartConfirmation;
+                Task::none()
+            }
+
+            MainViewMessage::DetectLocalSubnet => {
+                match network::detect_local_subnet() {
+                    Some(range)
+                        if state
+                            .app_config
+                            .scan_groups
+                            .iter()
+                            .any(|g| g.network_range == range) =>
+                    {
+                        state.notify(Notification::info(format!(
+                            "{range} is already configured; check VLAN and firewall settings"
+                        )));
+                    }
+                    Some(range) => {
+                        let group = ScanGroup::new("Local Subnet".to_string(), range.clone());
+                        state.edit_config(|config| {
+                            config.merge_scan_group(group.clone());
+                        });
+                        state.notify(Notification::info(format!(
+                            "Added a scan group for the local subnet {range}"
+                        )));
+                    }
+                    None => state.notify(Notification::warning(
+                        "Could not detect the local subnet; add the range manually in Config",
+                    )),
+                }
+                Task::none()
+            }
+
             MainViewMessage::OpenDeviceDetail(ip) => {
                 // Set loading state and trigger full data fetch
-                state.device_detail_view = Some(DeviceDetailView::new_loading(IpAddr::V4(ip)));
-                state.current_page = Page::DeviceDetail(IpAddr::V4(ip));
+                let mut detail_view = DeviceDetailView::new_loading(ip);
+                detail_view.set_scan_in_progress(state.main_view.is_scanning());
+                detail_view.set_temperature_unit(state.app_config.temperature_unit);
+                detail_view.set_section_states(state.app_config.detail_section_states.clone());
+                detail_view.set_eol_firmwares(state.app_config.eol_firmwares.clone());
+                detail_view
+                    .set_hashrate_trend(hashrate_trend(&state.app_config.hashrate_history, ip));
+                state.device_detail_view = Some(detail_view);
+                state.current_page = Page::DeviceDetail(ip);
+                // Anything still loading was for the previous miner
+                state.detail_fetches.cancel_all();
+
+                // Fetching during a scan is allowed: the detail fetch uses its own
+                // MinerFactory for a single IP and never touches scan state. The scan
+                // subscription is keyed on `active_scan`, not the current page, so it
+                // keeps running and its messages keep updating MainView while the
+                // detail page is shown.
 
                 // Fetch full miner data
                 // Note: With iced's tokio feature enabled, Task::perform runs on the
                 // shared tokio runtime, so we use the async version directly
-                Task::perform(
-                    network::full_fetch::fetch_full_miner_data_async(IpAddr::V4(ip)),
-                    |result| {
-                        BtcToolkitMessage::DeviceDetail(DeviceDetailMessage::DataFetched(result))
-                    },
+                state.track_detail_fetch(
+                    None,
+                    Task::perform(
+                        network::full_fetch::fetch_full_miner_data_async(
+                            ip,
+                            state.app_config.detail_fetch_timeout(),
+                        ),
+                        |r| r,
+                    ),
                 )
             }
 
+            MainViewMessage::ScanLocalSubnets => {
+                let configured: Vec<&str> = state
+                    .app_config
+                    .scan_groups
+                    .iter()
+                    .map(|group| group.network_range.as_str())
+                    .collect();
+                let (new, known): (Vec<String>, Vec<String>) = network::detect_local_subnets()
+                    .into_iter()
+                    .partition(|range| !configured.contains(&range.as_str()));
+
+                if new.is_empty() {
+                    if known.is_empty() {
+                        state.notify(Notification::warning(
+                            "Could not detect any local subnet; add the range manually in Config",
+                        ));
+                        return Task::none();
+                    }
+                    // Everything detected is already configured, so a normal scan covers it
+                    return Task::done(BtcToolkitMessage::MainView(MainViewMessage::StartScan));
+                }
+
+                let groups: Vec<ScanGroup> = new
+                    .into_iter()
+                    .map(|range| ScanGroup::new(format!("Local {range}"), range))
+                    .collect();
+                state.active_scan = Some(
+                    groups
+                        .iter()
+                        .map(|group| {
+                            network::scanner::ScanGroup::new(
+                                group.name.clone(),
+                                group.network_range.clone(),
+                                group.scan_config.clone(),
+                            )
+                        })
+                        .collect(),
+                );
+                state.scan_progress_by_group.clear();
+                state.main_view.start_local_scan(groups);
+                Task::none()
+            }
+
+            MainViewMessage::SaveLocalGroups => {
+                for group in state.main_view.take_local_groups() {
+                    state.edit_config(|config| {
+                        // A clashing name gets a suffix; keep its results under the new name
+                        let name = config.merge_scan_group(group.clone());
+                        if name != group.name
+                            && let Some(results) = config.last_scan_results.remove(&group.name)
+                        {
+                            config.last_scan_results.insert(name, results);
+                        }
+                    });
+                }
+                state.notify(Notification::success(
+                    "Saved the detected subnets as scan groups",
+                ));
+                Task::none()
+            }
+
+            MainViewMessage::DiscardLocalGroups => {
+                let task = state.main_view.update(message);
+                state.sync_scan_data();
+                task.map(BtcToolkitMessage::MainView)
+            }
+
             MainViewMessage::StartScan => {
                 let enabled_groups = state.app_config.get_enabled_groups();
 
@@ -144,13 +1170,69 @@ fn update(state: &mut BtcToolkit, message: BtcToolkitMessage) -> Task<BtcToolkit
                 } else {
                     Some(active_scans)
                 };
+                if !state.main_view.is_scanning() {
+                    state.scan_progress_by_group.clear();
+                }
 
                 let task = state.main_view.update(message);
                 task.map(BtcToolkitMessage::MainView)
             }
 
+            MainViewMessage::ScanGroupNow(name) => {
+                let Some(group) = state
+                    .app_config
+                    .scan_groups
+                    .iter()
+                    .find(|group| group.name == name)
+                    .cloned()
+                else {
+                    return Task::none();
+                };
+                if state.main_view.is_group_scanning(&name) {
+                    state.notify(Notification::warning(format!(
+                        "{name} is already being scanned"
+                    )));
+                    return Task::none();
+                }
+
+                state.group_scans.push(network::scanner::ScanGroup::new(
+                    group.name.clone(),
+                    group.network_range.clone(),
+                    group.scan_config.clone(),
+                ));
+                if !state.main_view.is_scanning() {
+                    state.scan_progress_by_group.clear();
+                }
+                state.scan_progress_by_group.remove(&name);
+                state.main_view.start_group_scan(&group);
+                Task::none()
+            }
+
+            MainViewMessage::StopGroup(name) => {
+                // The scanner answers with the group's cancelled completion
+                state
+                    .active_scan
+                    .iter()
+                    .flatten()
+                    .chain(&state.group_scans)
+                    .filter(|group| group.name == name)
+                    .for_each(|group| group.cancel.cancel());
+                Task::none()
+            }
+
             MainViewMessage::StopScan => {
+                // Dropping the subscription ends the scan; cancelling first
+                // also stops probes that are already in flight
+                state
+                    .active_scan
+                    .iter()
+                    .flatten()
+                    .chain(&state.group_scans)
+                    .for_each(|group| group.cancel.cancel());
                 state.active_scan = None;
+                state.group_scans.clear();
+                state.scan_progress_by_group.clear();
+                state.set_detail_scan_in_progress(false);
                 let task = state.main_view.update(message);
                 task.map(BtcToolkitMessage::MainView)
             }
@@ -165,13 +1247,60 @@ fn update(state: &mut BtcToolkit, message: BtcToolkitMessage) -> Task<BtcToolkit
             state.network_config.update(message.clone());
 
             match message {
+                NetworkConfigMessage::CopyGroup(name) => {
+                    match state.network_config.group_snippet(&name) {
+                        Some(snippet) => {
+                            state.notify(Notification::info(format!(
+                                "Copied group \"{name}\" to the clipboard"
+                            )));
+                            iced::clipboard::write(snippet)
+                        }
+                        None => Task::none(),
+                    }
+                }
+                NetworkConfigMessage::PasteGroup => iced::clipboard::read().map(|contents| {
+                    BtcToolkitMessage::NetworkConfig(NetworkConfigMessage::GroupPasted(contents))
+                }),
+                NetworkConfigMessage::ProfileNetwork => {
+                    let Some(range) = state.network_config.editing_range() else {
+                        return Task::none();
+                    };
+                    Task::perform(
+                        async move {
+                            let report = network::latency::sample_latency(
+                                &range,
+                                network::latency::LATENCY_SAMPLE_SIZE,
+                            )
+                            .await;
+                            (range, report)
+                        },
+                        |(range, report)| {
+                            BtcToolkitMessage::NetworkConfig(NetworkConfigMessage::NetworkProfiled(
+                                range, report,
+                            ))
+                        },
+                    )
+                }
                 NetworkConfigMessage::Close => {
                     state.current_page = Page::Main;
                     Task::none()
                 }
+                NetworkConfigMessage::ConfirmReset(keep_results) => {
+                    state.reset_config(keep_results);
+                    Task::none()
+                }
                 NetworkConfigMessage::Save => {
+                    let renamed = state.network_config.take_renamed_groups();
                     state.app_config = state.network_config.get_app_config().clone();
                     state.main_view.set_app_config(state.app_config.clone());
+                    state.main_view.reconcile_groups(&renamed);
+                    for (old_name, new_name) in &renamed {
+                        if let Some(progress) = state.scan_progress_by_group.remove(old_name) {
+                            state
+                                .scan_progress_by_group
+                                .insert(new_name.clone(), progress);
+                        }
+                    }
                     state.save_config();
                     state.current_page = Page::Main;
                     Task::none()
@@ -180,80 +1309,446 @@ fn update(state: &mut BtcToolkit, message: BtcToolkitMessage) -> Task<BtcToolkit
             }
         }
 
+        BtcToolkitMessage::Settings(message) => match message {
+            SettingsMessage::Close => {
+                state.current_page = Page::Main;
+                Task::none()
+            }
+            SettingsMessage::ReloadConfig => Task::done(BtcToolkitMessage::ReloadConfig),
+            SettingsMessage::SetCompactResults(compact) => {
+                state.edit_config(|config| config.compact_results = compact);
+                Task::none()
+            }
+            SettingsMessage::SetPersistResults(persist) => {
+                state.edit_config(|config| config.persist_results = persist);
+                Task::none()
+            }
+            SettingsMessage::SetBackupOnSave(enabled) => {
+                state.edit_config(|config| config.backup_on_save = enabled);
+                state.settings_view.refresh_backups();
+                Task::none()
+            }
+            SettingsMessage::SetExpandProblemGroups(enabled) => {
+                state.edit_config(|config| config.expand_problem_groups = enabled);
+                Task::none()
+            }
+            SettingsMessage::SetCollapseHealthyGroups(enabled) => {
+                state.edit_config(|config| config.collapse_healthy_groups = enabled);
+                Task::none()
+            }
+            SettingsMessage::SetReverseDns(enabled) => {
+                state.edit_config(|config| config.reverse_dns = enabled);
+                Task::none()
+            }
+            SettingsMessage::SetNotifyFirstDiscovery(enabled) => {
+                state.edit_config(|config| config.notify_first_discovery = enabled);
+                Task::none()
+            }
+            SettingsMessage::SetSchemaUpgradeNotify(enabled) => {
+                state.edit_config(|config| config.schema_upgrade_notify = enabled);
+                Task::none()
+            }
+            SettingsMessage::SetTemperatureUnit(unit) => {
+                state.edit_config(|config| config.temperature_unit = unit);
+                state.apply_detail_preferences();
+                Task::none()
+            }
+            SettingsMessage::SaveBrowserCommand => {
+                let command = state.settings_view.browser_command();
+                state.edit_config(|config| config.browser_command = command.clone());
+                state.notify(Notification::info("Browser command saved"));
+                Task::none()
+            }
+            SettingsMessage::AlertCooldownChanged(minutes) => {
+                state.settings_view.set_alert_cooldown_minutes(minutes);
+                Task::none()
+            }
+            SettingsMessage::SaveAlertCooldown => {
+                if let Some(seconds) = state.settings_view.alert_cooldown_seconds() {
+                    state.edit_config(|config| config.alert_cooldown_seconds = seconds);
+                    state.notify(Notification::info("Alert cooldown saved"));
+                }
+                Task::none()
+            }
+            SettingsMessage::FetchTimeoutChanged(seconds) => {
+                state.settings_view.set_fetch_timeout_seconds(seconds);
+                Task::none()
+            }
+            SettingsMessage::SaveFetchTimeout => {
+                if let Some(seconds) = state.settings_view.fetch_timeout_seconds() {
+                    state.edit_config(|config| config.detail_fetch_timeout_seconds = seconds);
+                    state.notify(Notification::info("Detail fetch timeout saved"));
+                }
+                Task::none()
+            }
+            SettingsMessage::SetManageIgnoredOpen(open) => {
+                state.settings_view.set_manage_ignored_open(open);
+                Task::none()
+            }
+            SettingsMessage::IgnoredSerialChanged(serial) => {
+                state.settings_view.set_ignored_serial_input(serial);
+                Task::none()
+            }
+            SettingsMessage::AddIgnoredSerial => {
+                if let Some(serial) = state.settings_view.take_ignored_serial() {
+                    state.edit_config(|config| {
+                        config.ignored_serial_numbers.insert(serial.clone());
+                    });
+                }
+                Task::none()
+            }
+            SettingsMessage::RemoveIgnoredSerial(serial) => {
+                state.edit_config(|config| {
+                    config.ignored_serial_numbers.remove(&serial);
+                });
+                Task::none()
+            }
+            SettingsMessage::CompletionWebhookChanged(url) => {
+                state.settings_view.set_completion_webhook(url);
+                Task::none()
+            }
+            SettingsMessage::SaveCompletionWebhook => {
+                let url = state.settings_view.completion_webhook();
+                state.edit_config(|config| config.completion_webhook = url.clone());
+                state.notify(Notification::info("Completion webhook saved"));
+                Task::none()
+            }
+            SettingsMessage::PostScanCommandChanged(command) => {
+                state.settings_view.set_post_scan_command(command);
+                Task::none()
+            }
+            SettingsMessage::SavePostScanCommand => {
+                let command = state.settings_view.post_scan_command();
+                let enabled = command.is_some();
+                state.edit_config(|config| config.post_scan_command = command.clone());
+                state.notify(if enabled {
+                    Notification::warning(
+                        "Post-scan command saved; it will run after every completed scan",
+                    )
+                } else {
+                    Notification::info("Post-scan command turned off")
+                });
+                Task::none()
+            }
+            SettingsMessage::UserAgentChanged(agent) => {
+                state.settings_view.set_user_agent(agent);
+                Task::none()
+            }
+            SettingsMessage::SaveUserAgent => {
+                let agent = state.settings_view.user_agent();
+                state.edit_config(|config| config.custom_user_agent = agent.clone());
+                state.notify(Notification::info("User agent saved"));
+                Task::none()
+            }
+            SettingsMessage::ProxyChanged(proxy) => {
+                state.settings_view.set_proxy(proxy);
+                Task::none()
+            }
+            SettingsMessage::SaveProxy => {
+                let proxy = state.settings_view.proxy();
+                match proxy
+                    .as_deref()
+                    .map(network::full_fetch::validate_proxy_url)
+                    .transpose()
+                {
+                    Ok(_) => {
+                        state.edit_config(|config| config.proxy = proxy.clone());
+                        state.notify(Notification::info(match &proxy {
+                            Some(proxy) => {
+                                format!("The completion webhook now goes through {proxy}")
+                            }
+                            None => "Proxy turned off".to_string(),
+                        }));
+                    }
+                    Err(e) => state.notify(Notification::error(e)),
+                }
+                Task::none()
+            }
+            SettingsMessage::BrowserCommandChanged(command) => {
+                state.settings_view.set_browser_command(command);
+                Task::none()
+            }
+            SettingsMessage::EolFirmwaresChanged(versions) => {
+                state.settings_view.set_eol_firmwares(versions);
+                Task::none()
+            }
+            SettingsMessage::SaveEolFirmwares => {
+                let versions = state.settings_view.eol_firmwares();
+                state.edit_config(|config| config.eol_firmwares = versions.clone());
+                state.apply_detail_preferences();
+                state.notify(Notification::info("End-of-life firmware list saved"));
+                Task::none()
+            }
+            SettingsMessage::RestoreBackup(path) => {
+                state.restore_backup(&path);
+                Task::none()
+            }
+            SettingsMessage::ExportAssetRegister => {
+                let path = format!(
+                    "btc_toolkit_asset_register_{}.csv",
+                    chrono::Local::now().format("%Y%m%d_%H%M%S")
+                );
+                let csv = export::export_asset_register(&state.app_config);
+                match export::write_atomic(std::path::Path::new(&path), csv.as_bytes()) {
+                    Ok(()) => state.notify(Notification::success(format!(
+                        "Asset register exported to {path}"
+                    ))),
+                    Err(e) => state.notify(Notification::warning(format!(
+                        "Failed to export the asset register: {e}"
+                    ))),
+                }
+                Task::none()
+            }
+        },
+
+        BtcToolkitMessage::History(message) => {
+            if let HistoryMessage::Close = message {
+                state.current_page = Page::Main;
+            } else {
+                state.history_view.update(message);
+            }
+            Task::none()
+        }
+
+        BtcToolkitMessage::Topology(message) => match message {
+            TopologyMessage::Close => {
+                state.current_page = Page::Main;
+                Task::none()
+            }
+            TopologyMessage::SelectGroup(name) => {
+                if let Some(group) = state.app_config.get_group(&name) {
+                    state.topology_view.select_group(group);
+                }
+                Task::none()
+            }
+            TopologyMessage::OpenDeviceDetail(ip) => Task::done(BtcToolkitMessage::MainView(
+                MainViewMessage::OpenDeviceDetail(ip),
+            )),
+        },
+
+        BtcToolkitMessage::RestartConfirmation(message) => match message {
+            RestartConfirmationMessage::ConfirmationChanged(text) => {
+                if let Some(ref mut view) = state.restart_confirmation {
+                    view.set_confirmation(text);
+                }
+                Task::none()
+            }
+            RestartConfirmationMessage::Cancel => {
+                state.restart_confirmation = None;
+                state.current_page = Page::Main;
+                Task::none()
+            }
+            RestartConfirmationMessage::Confirm => {
+                let Some(view) = state.restart_confirmation.take_if(|v| v.is_confirmed()) else {
+                    return Task::none();
+                };
+                state.current_page = Page::Main;
+
+                let targets = view.targets();
+                let timeout = state.app_config.detail_fetch_timeout();
+                state.notify(Notification::info(format!(
+                    "Restarting {} miners",
+                    targets.len()
+                )));
+
+                Task::perform(
+                    async move {
+                        network::full_fetch::bulk_restart_async(
+                            &targets,
+                            network::full_fetch::BULK_RESTART_CONCURRENCY,
+                            timeout,
+                        )
+                        .await
+                    },
+                    BtcToolkitMessage::BatchRestartFinished,
+                )
+            }
+        },
+
+        BtcToolkitMessage::BatchRestartFinished(results) => {
+            let total = results.len();
+            let mut failed = 0;
+            for (ip, result) in results {
+                if let Err(e) = result {
+                    failed += 1;
+                    tracing::warn!("Failed to restart miner {ip}: {e}");
+                }
+            }
+
+            state.notify(if failed == 0 {
+                Notification::info(format!("Restarted {total} miners"))
+            } else {
+                Notification::warning(format!(
+                    "Restarted {} of {total} miners; {failed} failed",
+                    total - failed
+                ))
+            });
+            Task::none()
+        }
+
+        BtcToolkitMessage::PowerScheduleTick => state.run_power_schedules(),
+
+        BtcToolkitMessage::SaveDetailResults => {
+            if state.unsaved_detail_results {
+                state.save_config();
+            }
+            Task::none()
+        }
+
+        BtcToolkitMessage::PowerScheduleDone(group, action, results) => {
+            let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+            for (ip, result) in &results {
+                if let Err(e) = result {
+                    tracing::warn!("Scheduled \"{action}\" failed on {ip}: {e}");
+                }
+            }
+            state.notify(if failed == 0 {
+                Notification::info(format!(
+                    "Power schedule: {action} on {} miners in {group}",
+                    results.len()
+                ))
+            } else {
+                Notification::warning(format!(
+                    "Power schedule: {action} in {group} failed on {failed} of {} miners",
+                    results.len()
+                ))
+            });
+            Task::none()
+        }
+
+        BtcToolkitMessage::MinerUrlOpened(result) => {
+            if let Err(error) = result {
+                state.notify(Notification::error(error));
+            }
+            Task::none()
+        }
+
+        BtcToolkitMessage::ReloadConfig => {
+            state.reload_config();
+            Task::none()
+        }
+
+        BtcToolkitMessage::ConfigLoaded(mut config) => {
+            if let Some(message) = config.schema_upgrade_message.take() {
+                state.notify(Notification::info(format!(
+                    "Config file upgraded. {message}"
+                )));
+            }
+            state.apply_config(*config);
+            state.main_view.set_config_loading(false);
+            Task::none()
+        }
+
+        BtcToolkitMessage::DismissNotification(idx) => {
+            if idx < state.notifications.len() {
+                state.notifications.remove(idx);
+            }
+            Task::none()
+        }
+
         BtcToolkitMessage::DeviceDetail(message) => {
             match message {
                 DeviceDetailMessage::Back => {
                     state.current_page = Page::Main;
                     state.device_detail_view = None;
+                    state.detail_fetches.cancel_all();
                     Task::none()
                 }
                 DeviceDetailMessage::DataFetched(result) => {
+                    state.remember_api_version(&result);
+                    state.raise_health_alerts(&result);
+                    state.store_detail_result(&result);
+                    let changelog = state.firmware_changelog_for(&result);
+
                     // Update the device detail view with fetched data
                     if let Some(ref mut view) = state.device_detail_view {
                         view.update_with_data(result);
+                        view.set_firmware_changelog(changelog);
                     }
                     Task::none()
                 }
-                DeviceDetailMessage::OpenInBrowser => {
-                    // Extract IP from current page and open in browser
-                    if let Page::DeviceDetail(ip) = state.current_page {
-                        let url = format!("http://{}", ip);
-                        if let Err(e) = opener::open(&url) {
-                            eprintln!("Failed to open URL {}: {}", url, e);
-                        }
+                DeviceDetailMessage::SetChangelogOpen(open) => {
+                    if let Some(ref mut view) = state.device_detail_view {
+                        view.set_changelog_open(open);
                     }
                     Task::none()
                 }
-                DeviceDetailMessage::PauseMining => {
-                    if let Page::DeviceDetail(ip) = state.current_page {
-                        // Perform pause then refetch data to update UI
-                        Task::perform(
-                            async move {
-                                let _ = network::full_fetch::pause_mining_async(ip).await;
-                                network::full_fetch::fetch_full_miner_data_async(ip).await
-                            },
-                            |result| {
-                                BtcToolkitMessage::DeviceDetail(DeviceDetailMessage::DataFetched(
-                                    result,
-                                ))
+                DeviceDetailMessage::SetConnectionDetailsOpen(open) => {
+                    if let Some(ref mut view) = state.device_detail_view {
+                        view.set_connection_details_open(open);
+                    }
+                    Task::none()
+                }
+                DeviceDetailMessage::ToggleSection(section) => {
+                    state.edit_config(|config| config.toggle_detail_section(section));
+                    state.apply_detail_preferences();
+                    Task::none()
+                }
+                DeviceDetailMessage::TrackFirmwareUpgrade => {
+                    let estimate = state.app_config.estimated_upgrade_seconds;
+                    if let Page::DeviceDetail(ip) = state.current_page
+                        && let Some(ref mut view) = state.device_detail_view
+                        && let Some(generation) = view.start_upgrade_tracking(estimate)
+                    {
+                        poll_firmware_upgrade(ip, state.app_config.detail_fetch_timeout()).map(
+                            move |result| {
+                                BtcToolkitMessage::DeviceDetail(
+                                    DeviceDetailMessage::FirmwareUpgradePolled(generation, result),
+                                )
                             },
                         )
                     } else {
                         Task::none()
                     }
                 }
-                DeviceDetailMessage::ResumeMining => {
+                DeviceDetailMessage::CancelFirmwareUpgrade => {
+                    if let Some(ref mut view) = state.device_detail_view {
+                        view.cancel_upgrade_tracking();
+                    }
+                    Task::none()
+                }
+                DeviceDetailMessage::FirmwareUpdateProgress(progress) => {
+                    if let Some(ref mut view) = state.device_detail_view {
+                        view.set_upgrade_progress(progress);
+                    }
+                    Task::none()
+                }
+                DeviceDetailMessage::FirmwareUpgradePolled(generation, result) => {
+                    let (&Page::DeviceDetail(ip), Some(view)) =
+                        (&state.current_page, state.device_detail_view.as_mut())
+                    else {
+                        return Task::none();
+                    };
+                    let poll = view.upgrade_polled(generation, result);
+                    state.handle_upgrade_poll(ip, generation, poll, BtcToolkitMessage::DeviceDetail)
+                }
+                DeviceDetailMessage::Identify => {
+                    let Page::DeviceDetail(ip) = state.current_page else {
+                        return Task::none();
+                    };
+                    if let Some(ref mut view) = state.device_detail_view {
+                        view.set_identifying(true);
+                    }
+                    state.start_identify(ip, BtcToolkitMessage::DeviceDetail)
+                }
+                DeviceDetailMessage::IdentifyFinished(result) => {
+                    if let Some(ref mut view) = state.device_detail_view {
+                        view.set_identifying(false);
+                    }
+                    state.identify_finished(result);
+                    Task::none()
+                }
+                DeviceDetailMessage::OpenInBrowser => {
+                    // Extract IP from current page and open in browser
                     if let Page::DeviceDetail(ip) = state.current_page {
-                        // Perform resume then refetch data to update UI
-                        Task::perform(
-                            async move {
-                                let _ = network::full_fetch::resume_mining_async(ip).await;
-                                network::full_fetch::fetch_full_miner_data_async(ip).await
-                            },
-                            |result| {
-                                BtcToolkitMessage::DeviceDetail(DeviceDetailMessage::DataFetched(
-                                    result,
-                                ))
-                            },
-                        )
+                        state.open_miner_in_browser(ip)
                     } else {
                         Task::none()
                     }
                 }
-                DeviceDetailMessage::ToggleFaultLight => {
+                DeviceDetailMessage::OpenInNewWindow => {
                     if let Page::DeviceDetail(ip) = state.current_page {
-                        // Toggle fault light then refetch data to update UI
-                        Task::perform(
-                            async move {
-                                let _ = network::full_fetch::toggle_fault_light_async(ip).await;
-                                network::full_fetch::fetch_full_miner_data_async(ip).await
-                            },
-                            |result| {
-                                BtcToolkitMessage::DeviceDetail(DeviceDetailMessage::DataFetched(
-                                    result,
-                                ))
-                            },
-                        )
+                        Task::done(BtcToolkitMessage::OpenDetailInNewWindow(ip))
                     } else {
                         Task::none()
                     }
@@ -262,7 +1757,7 @@ fn update(state: &mut BtcToolkit, message: BtcToolkitMessage) -> Task<BtcToolkit
                     if let Page::DeviceDetail(ip) = state.current_page {
                         Task::perform(network::full_fetch::restart_miner_async(ip), |result| {
                             if let Err(e) = result {
-                                eprintln!("Failed to restart miner: {}", e);
+                                tracing::warn!("Failed to restart miner: {e}");
                             }
                             // After restart, the miner will be unavailable for a while
                             // Navigate back to main view
@@ -272,65 +1767,279 @@ fn update(state: &mut BtcToolkit, message: BtcToolkitMessage) -> Task<BtcToolkit
                         Task::none()
                     }
                 }
+                message => match state.current_page {
+                    Page::DeviceDetail(ip) => {
+                        detail_request(ip, state.app_config.detail_fetch_timeout(), &message)
+                            .map(|task| state.track_detail_fetch(None, task))
+                            .unwrap_or_else(Task::none)
+                    }
+                    _ => Task::none(),
+                },
             }
         }
 
-        BtcToolkitMessage::Scanner(scanner_msg) => {
-            match scanner_msg {
-                ScannerMessage::MinerDiscovered { group_name, miner } => {
-                    let _ = state
-                        .main_view
-                        .update(MainViewMessage::MinerFound { group_name, miner });
-                }
-                ScannerMessage::IpScanned {
-                    group_name,
-                    total_ips,
-                    scanned_count,
-                } => {
-                    let _ = state.main_view.update(MainViewMessage::IpScanned {
-                        group_name,
-                        total_ips,
-                        scanned_count,
-                    });
+        BtcToolkitMessage::OpenDetailInNewWindow(ip) => state.open_detail_window(ip),
+
+        BtcToolkitMessage::WindowEvent(id, message) => {
+            let Some(ip) = state.detail_windows.get(&id).map(|detail| detail.ip) else {
+                return Task::none();
+            };
+
+            match message {
+                DeviceDetailMessage::Back => window::close(id),
+                DeviceDetailMessage::DataFetched(result) => {
+                    state.remember_api_version(&result);
+                    state.raise_health_alerts(&result);
+                    state.store_detail_result(&result);
+                    let changelog = state.firmware_changelog_for(&result);
+                    if let Some(detail) = state.detail_windows.get_mut(&id) {
+                        detail.view.update_with_data(result);
+                        detail.view.set_firmware_changelog(changelog);
+                    }
+                    Task::none()
+                }
+                DeviceDetailMessage::SetChangelogOpen(open) => {
+                    if let Some(detail) = state.detail_windows.get_mut(&id) {
+                        detail.view.set_changelog_open(open);
+                    }
+                    Task::none()
+                }
+                DeviceDetailMessage::SetConnectionDetailsOpen(open) => {
+                    if let Some(detail) = state.detail_windows.get_mut(&id) {
+                        detail.view.set_connection_details_open(open);
+                    }
+                    Task::none()
+                }
+                DeviceDetailMessage::ToggleSection(section) => {
+                    state.edit_config(|config| config.toggle_detail_section(section));
+                    state.apply_detail_preferences();
+                    Task::none()
+                }
+                DeviceDetailMessage::TrackFirmwareUpgrade => {
+                    let estimate = state.app_config.estimated_upgrade_seconds;
+                    if let Some(detail) = state.detail_windows.get_mut(&id)
+                        && let Some(generation) = detail.view.start_upgrade_tracking(estimate)
+                    {
+                        poll_firmware_upgrade(ip, state.app_config.detail_fetch_timeout()).map(
+                            move |result| {
+                                BtcToolkitMessage::WindowEvent(
+                                    id,
+                                    DeviceDetailMessage::FirmwareUpgradePolled(generation, result),
+                                )
+                            },
+                        )
+                    } else {
+                        Task::none()
+                    }
+                }
+                DeviceDetailMessage::CancelFirmwareUpgrade => {
+                    if let Some(detail) = state.detail_windows.get_mut(&id) {
+                        detail.view.cancel_upgrade_tracking();
+                    }
+                    Task::none()
                 }
-                ScannerMessage::GroupScanCompleted { group_name, result } => match result {
-                    Ok(()) => {
-                        let _ = state
-                            .main_view
-                            .update(MainViewMessage::GroupCompleted(group_name));
+                DeviceDetailMessage::FirmwareUpdateProgress(progress) => {
+                    if let Some(detail) = state.detail_windows.get_mut(&id) {
+                        detail.view.set_upgrade_progress(progress);
                     }
-                    Err(error) => {
-                        let _ = state
-                            .main_view
-                            .update(MainViewMessage::GroupError { group_name, error });
+                    Task::none()
+                }
+                DeviceDetailMessage::FirmwareUpgradePolled(generation, result) => {
+                    let Some(detail) = state.detail_windows.get_mut(&id) else {
+                        return Task::none();
+                    };
+                    let poll = detail.view.upgrade_polled(generation, result);
+                    state.handle_upgrade_poll(ip, generation, poll, move |message| {
+                        BtcToolkitMessage::WindowEvent(id, message)
+                    })
+                }
+                DeviceDetailMessage::Identify => {
+                    if let Some(detail) = state.detail_windows.get_mut(&id) {
+                        detail.view.set_identifying(true);
                     }
+                    state.start_identify(ip, move |message| {
+                        BtcToolkitMessage::WindowEvent(id, message)
+                    })
+                }
+                DeviceDetailMessage::IdentifyFinished(result) => {
+                    if let Some(detail) = state.detail_windows.get_mut(&id) {
+                        detail.view.set_identifying(false);
+                    }
+                    state.identify_finished(result);
+                    Task::none()
+                }
+                DeviceDetailMessage::OpenInBrowser => state.open_miner_in_browser(ip),
+                DeviceDetailMessage::OpenInNewWindow => Task::none(),
+                DeviceDetailMessage::Restart => {
+                    Task::perform(
+                        network::full_fetch::restart_miner_async(ip),
+                        move |result| {
+                            if let Err(e) = result {
+                                tracing::warn!("Failed to restart miner: {e}");
+                            }
+                            // The miner will be unavailable for a while, so close its window
+                            BtcToolkitMessage::WindowEvent(id, DeviceDetailMessage::Back)
+                        },
+                    )
+                }
+                message => detail_request(ip, state.app_config.detail_fetch_timeout(), &message)
+                    .map(|task| state.track_detail_fetch(Some(id), task))
+                    .unwrap_or_else(Task::none),
+            }
+        }
+
+        BtcToolkitMessage::DetailFetched(window, fetch, result) => {
+            let fetches = match window {
+                None => Some(&mut state.detail_fetches),
+                Some(id) => state
+                    .detail_windows
+                    .get_mut(&id)
+                    .map(|detail| &mut detail.fetches),
+            };
+            // Cancelled by leaving the view; the result is for a miner no longer shown
+            if !fetches.is_some_and(|fetches| fetches.finish(fetch)) {
+                return Task::none();
+            }
+            let message = DeviceDetailMessage::DataFetched(result);
+            update(
+                state,
+                match window {
+                    None => BtcToolkitMessage::DeviceDetail(message),
+                    Some(id) => BtcToolkitMessage::WindowEvent(id, message),
                 },
-                ScannerMessage::AllScansCompleted => {
-                    let _ = state.main_view.update(MainViewMessage::AllScansCompleted);
-                    state.app_config = state.main_view.get_app_config().clone();
+            )
+        }
+
+        BtcToolkitMessage::WindowClosed(id) => {
+            if id == state.main_window {
+                if state.unsaved_detail_results {
                     state.save_config();
                 }
+                iced::exit()
+            } else {
+                state.detail_windows.remove(&id);
+                Task::none()
             }
-            Task::none()
+        }
+
+        BtcToolkitMessage::Scanner(scanner_msg) => state.handle_scanner_message(scanner_msg),
+
+        BtcToolkitMessage::GroupScan(group_name, scanner_msg) => {
+            // A "Scan now" subscription covers one group and ends with its own
+            // AllScansCompleted
+            if matches!(scanner_msg, ScannerMessage::AllScansCompleted) {
+                state.group_scans.retain(|group| group.name != group_name);
+            }
+            state.handle_scanner_message(scanner_msg)
         }
     }
 }
 
 fn subscription(state: &BtcToolkit) -> Subscription<BtcToolkitMessage> {
-    if let Some(ref active_scans) = state.active_scan {
+    let scan = if let Some(ref active_scans) = state.active_scan {
         Scanner::scan_multiple_groups(active_scans.clone()).map(BtcToolkitMessage::Scanner)
     } else {
         Subscription::none()
-    }
+    };
+
+    // Keyed by group name as well, so a "Scan now" never shares an identity
+    // with a main scan over the same single group
+    let group_scans = state.group_scans.iter().map(|group| {
+        Scanner::scan_multiple_groups(vec![group.clone()])
+            .with(group.name.clone())
+            .map(|(group_name, message)| BtcToolkitMessage::GroupScan(group_name, message))
+    });
+
+    // Early scan progress is buffered in MainView and applied at this rate
+    let progress_tick = if state.main_view.is_rendering_paused() {
+        iced::time::every(SCAN_PROGRESS_TICK)
+            .map(|_| BtcToolkitMessage::MainView(MainViewMessage::FlushScanProgress))
+    } else {
+        Subscription::none()
+    };
+
+    let pulse_tick = if state.main_view.is_scanning() {
+        iced::time::every(SCAN_PULSE_TICK)
+            .map(|_| BtcToolkitMessage::MainView(MainViewMessage::PulseScanIndicator))
+    } else {
+        Subscription::none()
+    };
+
+    let log_refresh = if state.main_view.is_log_open() {
+        iced::time::every(LOG_PANEL_REFRESH)
+            .map(|_| BtcToolkitMessage::MainView(MainViewMessage::RefreshLog))
+    } else {
+        Subscription::none()
+    };
+
+    // Each detail window gets its own timer so windows refresh independently
+    let detail_refreshes = state.detail_windows.keys().map(|&id| {
+        iced::time::every(DETAIL_WINDOW_REFRESH)
+            .with(id)
+            .map(|(id, _)| BtcToolkitMessage::WindowEvent(id, DeviceDetailMessage::Refresh))
+    });
+
+    // Ticks even without schedules so the checked span keeps up with the
+    // clock; a window added later must not replay the hours before it
+    let power_schedule =
+        iced::time::every(POWER_SCHEDULE_TICK).map(|_| BtcToolkitMessage::PowerScheduleTick);
+
+    let save_detail_results = if state.unsaved_detail_results {
+        iced::time::every(DETAIL_RESULTS_SAVE_INTERVAL)
+            .map(|_| BtcToolkitMessage::SaveDetailResults)
+    } else {
+        Subscription::none()
+    };
+
+    Subscription::batch(
+        [
+            scan,
+            progress_tick,
+            pulse_tick,
+            log_refresh,
+            power_schedule,
+            save_detail_results,
+            window::close_events().map(BtcToolkitMessage::WindowClosed),
+        ]
+        .into_iter()
+        .chain(group_scans)
+        .chain(detail_refreshes),
+    )
 }
 
-fn view(state: &BtcToolkit) -> Element<'_, BtcToolkitMessage> {
-    match &state.current_page {
+fn view(state: &BtcToolkit, window: window::Id) -> Element<'_, BtcToolkitMessage> {
+    if window != state.main_window {
+        return match state.detail_windows.get(&window) {
+            Some(detail) => detail
+                .view
+                .view()
+                .map(move |message| BtcToolkitMessage::WindowEvent(window, message)),
+            None => space().into(),
+        };
+    }
+
+    let page = match &state.current_page {
         Page::Main => state.main_view.view().map(BtcToolkitMessage::MainView),
         Page::NetworkConfig => state
             .network_config
             .view()
             .map(BtcToolkitMessage::NetworkConfig),
+        Page::Settings => state.settings_view.view().map(BtcToolkitMessage::Settings),
+        Page::RestartConfirmation => match &state.restart_confirmation {
+            Some(view) => view.view().map(BtcToolkitMessage::RestartConfirmation),
+            None => state.main_view.view().map(BtcToolkitMessage::MainView),
+        },
+        Page::TopologyGrid => state
+            .topology_view
+            .view(
+                &state.app_config.scan_groups,
+                state.main_view.current_results(),
+            )
+            .map(BtcToolkitMessage::Topology),
+        Page::History => state
+            .history_view
+            .view(&state.app_config.scan_runs)
+            .map(BtcToolkitMessage::History),
         Page::DeviceDetail(_ip) => {
             if let Some(ref device_view) = state.device_detail_view {
                 device_view.view().map(BtcToolkitMessage::DeviceDetail)
@@ -339,5 +2048,45 @@ fn view(state: &BtcToolkit) -> Element<'_, BtcToolkitMessage> {
                 state.main_view.view().map(BtcToolkitMessage::MainView)
             }
         }
+    };
+
+    if state.notifications.is_empty() {
+        return page;
+    }
+
+    column![
+        notifications::view(&state.notifications, BtcToolkitMessage::DismissNotification),
+        page
+    ]
+    .spacing(theme::spacing::XS)
+    .padding([theme::padding::XS, 0.0])
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detail_fetches_cancel_all() {
+        let mut fetches = DetailFetches::default();
+        let _first = fetches.track(Task::none());
+        let _second = fetches.track(Task::none());
+        assert_eq!(fetches.running.len(), 2);
+
+        fetches.cancel_all();
+        assert!(fetches.running.is_empty());
+
+        // Results of the cancelled fetches arrive late and are discarded
+        assert!(!fetches.finish(0));
+        assert!(!fetches.finish(1));
+
+        // New fetches get fresh ids, so old results can't be mistaken for them
+        let _third = fetches.track(Task::none());
+        assert!(!fetches.finish(1));
+        assert!(fetches.finish(2));
+        assert!(fetches.running.is_empty());
     }
 }
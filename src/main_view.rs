@@ -1,42 +1,114 @@
-use crate::config::AppConfig;
+use crate::changes::{MinerSnapshot, describe_changes};
+use crate::config::{
+    AppConfig, DeviceExpectation, GroupScanSummary, ScanGroup, ScanRecord, ScanRun, ScanRunGroup,
+};
+use crate::errors::ScannerError;
+use crate::health::HealthStatus;
+use crate::log_buffer;
+use crate::miner_table;
 use crate::network::estimate_ip_count;
-use crate::sorting::{SortColumn, SortDirection, sort_miners_by_column};
+use crate::sorting::{
+    GroupSortKey, SortColumn, SortDirection, sort_groups_by_field, sort_miners_by_column,
+};
+use crate::summary::{algorithm_breakdown, format_breakdown, make_breakdown};
 use crate::theme;
 use crate::ui_helpers::{
     calculate_progress, danger_button, format_duration, primary_button, secondary_button,
+    truncated_text,
 };
+use crate::widgets::Sparkline;
+use asic_rs::data::device::MinerMake;
 use asic_rs::data::miner::MinerData;
-use iced::widget::{Space, button, column, container, progress_bar, row, scrollable};
+use iced::widget::{
+    Space, button, column, container, pick_list, progress_bar, row, scrollable, tooltip,
+};
 use iced::{Element, Length, Task};
 use std::collections::{HashMap, HashSet};
-use std::net::Ipv4Addr;
-use std::time::Instant;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+/// Longest group name and range shown in a group header before eliding
+const GROUP_NAME_CHARS: usize = 40;
+const GROUP_RANGE_CHARS: usize = 48;
+
+/// Height of the stacked fleet-by-make bar
+const MAKE_BAR_HEIGHT: f32 = 10.0;
+
+/// Height of the scrollable log panel below the results
+const LOG_PANEL_HEIGHT: f32 = 200.0;
+
+/// Segment colors for the fleet-by-make bar, reused in order when there are
+/// more makes than colors
+const MAKE_COLORS: [iced::Color; 6] = [
+    theme::colors::DATA_BLUE,
+    theme::colors::DATA_CYAN,
+    theme::colors::DATA_GREEN,
+    theme::colors::DATA_YELLOW,
+    theme::colors::DATA_ORANGE,
+    theme::colors::DATA_RED,
+];
 
 #[derive(Debug, Clone)]
 pub enum MainViewMessage {
     OpenNetworkConfig,
+    OpenSettings,
+    OpenTopology,
+    OpenHistory,
+    /// Writes the displayed results to a timestamped CSV file
+    ExportCsv,
+    /// Shows or hides the log panel
+    ToggleLogPanel,
+    /// Copies the buffered log to the clipboard
+    CopyLog,
+    /// Redraw tick that picks up new log lines while the panel is open
+    RefreshLog,
+    /// Restart every miner from the current results, after confirmation
+    BatchRestartAll,
     StartScan,
     StopScan,
+    /// Scan one configured group now, alongside any scan already running
+    ScanGroupNow(String),
+    /// Stop one group's scan, leaving the other groups scanning
+    StopGroup(String),
     AddGroup,
-    OpenIpInBrowser(Ipv4Addr),
-    OpenDeviceDetail(Ipv4Addr),
+    OpenIpInBrowser(IpAddr),
+    OpenDeviceDetail(IpAddr),
     MinerFound {
         group_name: String,
         miner: MinerData,
     },
-    IpScanned {
+    /// A batch of discoveries from one group, handled like repeated `MinerFound`
+    MinersFound {
         group_name: String,
-        total_ips: usize,
-        scanned_count: usize,
+        miners: Vec<MinerData>,
     },
+    /// Latest progress of every group in the scan, as tracked by the app
+    SyncProgress(HashMap<String, ScanProgress>),
+    ScanSpeed(String, f64),
+    /// Render tick that applies progress buffered while rendering is paused
+    FlushScanProgress,
+    /// Redraw tick that animates the scanning indicator
+    PulseScanIndicator,
     GroupCompleted(String),
+    /// PTR name of a miner's IP, `None` if it has none
+    ReverseDnsResolved(IpAddr, Option<String>),
     GroupError {
         group_name: String,
-        error: String,
+        error: ScannerError,
     },
     AllScansCompleted,
     SortColumn(SortColumn),
+    SortGroups(GroupSortKey),
     ToggleGroupCollapse(String),
+    ToggleIgnoredCollapse,
+    ToggleEolCollapse,
+    ToggleMakeSummary,
+    DetectLocalSubnet,
+    /// Detect every local subnet and scan it without configuring groups first
+    ScanLocalSubnets,
+    /// Add the groups from the last local-subnet scan to the config
+    SaveLocalGroups,
+    DiscardLocalGroups,
 }
 
 #[derive(Debug, Clone)]
@@ -46,8 +118,46 @@ pub struct GroupScanStatus {
     pub miner_count: usize,
     pub total_ips: usize,
     pub scanned_ips: usize,
+    /// Latest throughput reported by the scanner while the group is running
+    pub ips_per_second: Option<f64>,
+    /// When this group's scan started, which is later than the scan's for
+    /// groups started with "Scan now"
+    pub started_at: Option<Instant>,
+    /// When the group finished or failed
+    pub completed_at: Option<Instant>,
+}
+
+impl GroupScanStatus {
+    /// Start-to-completion time, once the group has finished.
+    pub fn duration(&self) -> Option<Duration> {
+        Some(self.completed_at?.duration_since(self.started_at?))
+    }
+}
+
+/// Live progress of one group's scan. The app keeps these and syncs them
+/// into the main view, which buffers or applies them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanProgress {
+    pub total_ips: usize,
+    pub scanned_ips: usize,
+    pub miners_found: usize,
+    pub start_time: Instant,
 }
 
+impl ScanProgress {
+    pub fn new(start_time: Instant) -> Self {
+        Self {
+            total_ips: 0,
+            scanned_ips: 0,
+            miners_found: 0,
+            start_time,
+        }
+    }
+}
+
+/// Key of the main view's miner table sort in `AppConfig::sort_states`
+pub const SORT_SCREEN: &str = "main";
+
 pub struct MainView {
     app_config: AppConfig,
     is_scanning: bool,
@@ -60,12 +170,47 @@ pub struct MainView {
     error_messages: Vec<String>,
     sort_column: Option<SortColumn>,
     sort_direction: SortDirection,
+    group_sort: GroupSortKey,
     collapsed_groups: HashSet<String>,
+    /// The "Ignored Miners" section at the bottom is folded away
+    ignored_collapsed: bool,
+    /// The "EOL Firmware" compliance list is folded away
+    eol_collapsed: bool,
+    /// The fleet composition bar at the top of the results is folded away
+    make_summary_collapsed: bool,
+    /// Miners as they were before the current/last scan started, for change badges
+    previous_snapshots: HashMap<IpAddr, MinerSnapshot>,
+    /// The last completed scan found nothing in any group
+    no_miners_found: bool,
+    /// The last scan ran to the end rather than being stopped
+    scan_completed: bool,
+    /// Unsaved groups created by "Scan Local"; shown alongside configured groups
+    local_groups: Vec<ScanGroup>,
+    /// Early in a scan, before 10% of IPs are scanned or a miner is found,
+    /// progress is buffered and applied on a render tick instead of per message
+    pause_rendering: bool,
+    /// Latest progress per group held back while paused
+    pending_progress: HashMap<String, ScanProgress>,
+    /// Groups in the current scan that haven't completed or failed yet
+    scanning_groups: HashSet<String>,
+    /// PTR names looked up this session; `None` while pending or unresolved
+    ptr_names: HashMap<IpAddr, Option<String>>,
+    /// The config is still loading at startup; scanning and editing wait for it
+    config_loading: bool,
+    log_open: bool,
 }
 
 impl MainView {
+    /// Starts with the default config while the real one loads.
     pub fn new() -> Self {
-        let app_config = AppConfig::load();
+        Self {
+            config_loading: true,
+            ..Self::with_config(AppConfig::default())
+        }
+    }
+
+    fn with_config(app_config: AppConfig) -> Self {
+        let (sort_column, sort_direction) = app_config.sort_state_for(SORT_SCREEN);
         Self {
             app_config,
             is_scanning: false,
@@ -74,30 +219,70 @@ impl MainView {
             total_groups: 0,
             completed_groups: 0,
             start_time: None,
+            scan_completed: false,
             total_ips_to_scan: 0,
             error_messages: Vec::new(),
-            sort_column: Some(SortColumn::IpAddress),
-            sort_direction: SortDirection::Ascending,
+            sort_column: Some(sort_column),
+            sort_direction,
+            group_sort: GroupSortKey::default(),
             collapsed_groups: HashSet::new(),
+            ignored_collapsed: true,
+            eol_collapsed: true,
+            make_summary_collapsed: false,
+            previous_snapshots: HashMap::new(),
+            no_miners_found: false,
+            local_groups: Vec::new(),
+            pause_rendering: false,
+            pending_progress: HashMap::new(),
+            scanning_groups: HashSet::new(),
+            ptr_names: HashMap::new(),
+            config_loading: false,
+            log_open: false,
         }
     }
 
     pub fn set_app_config(&mut self, config: AppConfig) {
+        let (sort_column, sort_direction) = config.sort_state_for(SORT_SCREEN);
+        self.sort_column = Some(sort_column);
+        self.sort_direction = sort_direction;
         self.app_config = config;
     }
 
+    /// The miner table's current sort, for persisting it.
+    pub fn sort_state(&self) -> Option<(SortColumn, SortDirection)> {
+        self.sort_column.map(|column| (column, self.sort_direction))
+    }
+
     pub fn get_app_config(&self) -> &AppConfig {
         &self.app_config
     }
 
+    pub fn app_config_mut(&mut self) -> &mut AppConfig {
+        &mut self.app_config
+    }
+
+    pub fn is_scanning(&self) -> bool {
+        self.is_scanning
+    }
+
+    pub fn is_log_open(&self) -> bool {
+        self.log_open
+    }
+
+    pub fn is_config_loading(&self) -> bool {
+        self.config_loading
+    }
+
+    pub fn set_config_loading(&mut self, loading: bool) {
+        self.config_loading = loading;
+    }
+
     pub fn start_scanning(&mut self, groups: Vec<String>) {
-        self.is_scanning = true;
-        self.start_time = Some(Instant::now());
+        self.reset_scan_state();
         self.total_groups = groups.len();
-        self.completed_groups = 0;
+        self.scanning_groups = groups.into_iter().collect();
         self.discovered_miners_by_group.clear();
-        self.group_status.clear();
-        self.error_messages.clear();
+        self.pause_rendering = true;
         self.app_config.clear_scan_results();
 
         let enabled_groups = self.app_config.get_enabled_groups();
@@ -107,79 +292,351 @@ impl MainView {
             .sum();
     }
 
+    /// Adds a single group to the scan, starting one if none is running.
+    ///
+    /// Other groups keep their live or stored results and progress; only this
+    /// group's results are replaced as it is rescanned. A group that already
+    /// finished in the running scan counts as outstanding again. The caller
+    /// must check `is_group_scanning` first.
+    pub fn start_group_scan(&mut self, group: &ScanGroup) {
+        let finished_this_scan = self.is_scanning
+            && self
+                .group_status
+                .get(&group.name)
+                .is_some_and(|status| status.completed);
+
+        if !self.is_scanning {
+            self.reset_scan_state();
+            self.discovered_miners_by_group = self.app_config.get_all_scan_results().clone();
+        }
+
+        if finished_this_scan {
+            self.completed_groups = self.completed_groups.saturating_sub(1);
+        } else {
+            self.total_groups += 1;
+            self.total_ips_to_scan += estimate_ip_count(&group.network_range);
+        }
+        self.group_status.remove(&group.name);
+        self.pending_progress.remove(&group.name);
+        self.discovered_miners_by_group.remove(&group.name);
+        self.scanning_groups.insert(group.name.clone());
+    }
+
+    /// Brings per-group scan state in line with the config after groups were
+    /// edited: state of `renamed` groups moves to the new name, and state of
+    /// groups that no longer exist is dropped so nothing orphaned is shown.
+    pub fn reconcile_groups(&mut self, renamed: &[(String, String)]) {
+        for (old_name, new_name) in renamed {
+            if let Some(status) = self.group_status.remove(old_name) {
+                self.group_status.insert(new_name.clone(), status);
+            }
+            if let Some(miners) = self.discovered_miners_by_group.remove(old_name) {
+                self.discovered_miners_by_group
+                    .insert(new_name.clone(), miners);
+            }
+            if let Some(progress) = self.pending_progress.remove(old_name) {
+                self.pending_progress.insert(new_name.clone(), progress);
+            }
+            if self.scanning_groups.remove(old_name) {
+                self.scanning_groups.insert(new_name.clone());
+            }
+            if self.collapsed_groups.remove(old_name) {
+                self.collapsed_groups.insert(new_name.clone());
+            }
+            let old_prefix = format!("{old_name}: ");
+            for message in &mut self.error_messages {
+                if let Some(error) = message.strip_prefix(&old_prefix) {
+                    *message = format!("{new_name}: {error}");
+                }
+            }
+        }
+
+        let known: HashSet<String> = self
+            .app_config
+            .scan_groups
+            .iter()
+            .chain(&self.local_groups)
+            .map(|group| group.name.clone())
+            .collect();
+        self.group_status.retain(|name, _| known.contains(name));
+        self.discovered_miners_by_group
+            .retain(|name, _| known.contains(name));
+        self.pending_progress.retain(|name, _| known.contains(name));
+        self.scanning_groups.retain(|name| known.contains(name));
+        self.collapsed_groups.retain(|name| known.contains(name));
+        self.error_messages.retain(|message| {
+            known
+                .iter()
+                .any(|name| message.starts_with(&format!("{name}: ")))
+        });
+    }
+
+    /// True if `group_name` is part of the running scan and hasn't finished.
+    pub fn is_group_scanning(&self, group_name: &str) -> bool {
+        self.is_scanning && self.scanning_groups.contains(group_name)
+    }
+
+    /// True while any group of the running scan is still outstanding.
+    pub fn has_groups_in_progress(&self) -> bool {
+        self.is_scanning && !self.scanning_groups.is_empty()
+    }
+
+    /// Clears progress from the previous scan and starts the clock.
+    fn reset_scan_state(&mut self) {
+        self.is_scanning = true;
+        self.start_time = Some(Instant::now());
+        self.total_groups = 0;
+        self.completed_groups = 0;
+        self.total_ips_to_scan = 0;
+        self.group_status.clear();
+        self.error_messages.clear();
+        self.no_miners_found = false;
+        self.scan_completed = false;
+        self.pause_rendering = false;
+        self.pending_progress.clear();
+        self.scanning_groups.clear();
+
+        // Keep the outgoing results so rediscovered miners can be compared against them
+        self.previous_snapshots = self
+            .app_config
+            .get_all_scan_results()
+            .values()
+            .flatten()
+            .map(|miner| (miner.ip, MinerSnapshot::from_miner_data(miner)))
+            .collect();
+    }
+
+    /// Starts scanning temporary groups that aren't part of the config.
+    pub fn start_local_scan(&mut self, groups: Vec<ScanGroup>) {
+        self.start_scanning(groups.iter().map(|g| g.name.clone()).collect());
+        self.total_ips_to_scan = groups
+            .iter()
+            .map(|group| estimate_ip_count(&group.network_range))
+            .sum();
+        self.local_groups = groups;
+    }
+
+    /// True while early scan progress is buffered for the render tick.
+    pub fn is_rendering_paused(&self) -> bool {
+        self.is_scanning && self.pause_rendering
+    }
+
+    fn resume_rendering(&mut self) {
+        self.pause_rendering = false;
+        self.flush_progress();
+    }
+
+    fn flush_progress(&mut self) {
+        for (group_name, progress) in std::mem::take(&mut self.pending_progress) {
+            self.apply_progress(group_name, &progress);
+        }
+    }
+
+    /// IPs scanned so far across all groups, including buffered progress.
+    fn scanned_ip_total(&self) -> usize {
+        let applied: usize = self
+            .group_status
+            .iter()
+            .filter(|(name, _)| !self.pending_progress.contains_key(*name))
+            .map(|(_, status)| status.scanned_ips)
+            .sum();
+        let pending: usize = self
+            .pending_progress
+            .values()
+            .map(|progress| progress.scanned_ips)
+            .sum();
+        applied + pending
+    }
+
+    fn add_discoveries(&mut self, group_name: String, miners: Vec<MinerData>) {
+        self.resume_rendering();
+        let found = miners.len();
+        self.discovered_miners_by_group
+            .entry(group_name.clone())
+            .or_default()
+            .extend(miners);
+
+        if let Some(status) = self.group_status.get_mut(&group_name) {
+            status.miner_count += found;
+        } else {
+            self.group_status.insert(
+                group_name,
+                GroupScanStatus {
+                    completed: false,
+                    error: None,
+                    miner_count: found,
+                    total_ips: 0, // Will be set when progress is first synced
+                    scanned_ips: 0,
+                    ips_per_second: None,
+                    started_at: None,
+                    completed_at: None,
+                },
+            );
+        }
+    }
+
+    fn apply_progress(&mut self, group_name: String, progress: &ScanProgress) {
+        let status = self
+            .group_status
+            .entry(group_name)
+            .or_insert_with(|| GroupScanStatus {
+                completed: false,
+                error: None,
+                miner_count: 0,
+                total_ips: 0,
+                scanned_ips: 0,
+                ips_per_second: None,
+                started_at: None,
+                completed_at: None,
+            });
+        status.total_ips = progress.total_ips;
+        status.scanned_ips = progress.scanned_ips;
+        status.miner_count = status.miner_count.max(progress.miners_found);
+        status.started_at = Some(progress.start_time);
+    }
+
+    /// IPs of the group's miners without a reverse lookup this session,
+    /// marked as pending so they aren't requested twice.
+    pub fn take_unresolved_ips(&mut self, group_name: &str) -> Vec<IpAddr> {
+        let Some(miners) = self.discovered_miners_by_group.get(group_name) else {
+            return Vec::new();
+        };
+        let ips: Vec<IpAddr> = miners
+            .iter()
+            .map(|miner| miner.ip)
+            .filter(|ip| !self.ptr_names.contains_key(ip))
+            .collect();
+        for ip in &ips {
+            self.ptr_names.insert(*ip, None);
+        }
+        ips
+    }
+
+    /// Hands over the temporary local groups, e.g. to save them to the config.
+    pub fn take_local_groups(&mut self) -> Vec<ScanGroup> {
+        std::mem::take(&mut self.local_groups)
+    }
+
+    /// Drops the temporary local groups along with their results and history.
+    fn discard_local_groups(&mut self) {
+        for group in self.local_groups.drain(..) {
+            self.app_config.last_scan_results.remove(&group.name);
+            self.app_config.scan_history_summary.remove(&group.name);
+            self.app_config.miner_count_history.remove(&group.name);
+            self.app_config.last_errors.remove(&group.name);
+            self.discovered_miners_by_group.remove(&group.name);
+        }
+    }
+
+    fn record_scan_summary(
+        &mut self,
+        group_name: &str,
+        miners_found: usize,
+        error: Option<&ScannerError>,
+    ) {
+        let duration = self
+            .group_status
+            .get(group_name)
+            .and_then(GroupScanStatus::duration)
+            .or_else(|| self.start_time.map(|t| t.elapsed()));
+        let summary = GroupScanSummary {
+            timestamp: chrono::Utc::now(),
+            miners_found,
+            duration_seconds: duration.map_or(0, |d| d.as_secs()),
+            error: error.map(ToString::to_string),
+        };
+        let cancelled = matches!(error, Some(ScannerError::Cancelled));
+        self.app_config
+            .record_scan_summary(group_name, summary, cancelled);
+    }
+
     pub fn update(&mut self, message: MainViewMessage) -> Task<MainViewMessage> {
         match message {
-            MainViewMessage::OpenNetworkConfig => Task::none(),
+            MainViewMessage::OpenNetworkConfig
+            | MainViewMessage::OpenSettings
+            | MainViewMessage::OpenTopology
+            | MainViewMessage::OpenHistory
+            | MainViewMessage::CopyLog
+            | MainViewMessage::ExportCsv
+            | MainViewMessage::RefreshLog
+            | MainViewMessage::BatchRestartAll
+            | MainViewMessage::DetectLocalSubnet => Task::none(),
             MainViewMessage::StartScan => {
-                if !self.is_scanning {
+                if !self.is_scanning && !self.config_loading {
                     let enabled_groups = self.app_config.get_enabled_groups();
                     let group_names: Vec<String> =
                         enabled_groups.iter().map(|g| g.name.clone()).collect();
+                    self.discard_local_groups();
                     self.start_scanning(group_names);
                 }
                 Task::none()
             }
             MainViewMessage::StopScan => {
                 self.is_scanning = false;
+                self.scan_completed = false;
+                self.scanning_groups.clear();
                 Task::none()
             }
+            // Started at the BtcToolkit level, which owns the scan subscriptions
+            MainViewMessage::ScanGroupNow(_) | MainViewMessage::StopGroup(_) => Task::none(),
             MainViewMessage::AddGroup => Task::none(),
-            MainViewMessage::OpenIpInBrowser(ip) => {
-                let url = format!("http://{}", ip);
-                if let Err(e) = opener::open(&url) {
-                    eprintln!("Failed to open URL {}: {}", url, e);
-                }
-                Task::none()
-            }
+            // Handled at the BtcToolkit level so failures can raise a notification
+            MainViewMessage::OpenIpInBrowser(_ip) => Task::none(),
             MainViewMessage::OpenDeviceDetail(_ip) => {
-                // This is handled at the BtcToolkit level, not here
+                // This is handled at the BtcToolkit level, not here. Scan state is
+                // deliberately left untouched so a running scan keeps its progress.
                 Task::none()
             }
             MainViewMessage::MinerFound { group_name, miner } => {
-                self.discovered_miners_by_group
-                    .entry(group_name.clone())
-                    .or_default()
-                    .push(miner);
-
-                if let Some(status) = self.group_status.get_mut(&group_name) {
-                    status.miner_count += 1;
-                } else {
-                    self.group_status.insert(
-                        group_name,
-                        GroupScanStatus {
-                            completed: false,
-                            error: None,
-                            miner_count: 1,
-                            total_ips: 0, // Will be set when first IpScanned message arrives
-                            scanned_ips: 0,
-                        },
-                    );
+                self.add_discoveries(group_name, vec![miner]);
+                Task::none()
+            }
+            MainViewMessage::MinersFound { group_name, miners } => {
+                self.add_discoveries(group_name, miners);
+                Task::none()
+            }
+            MainViewMessage::ScanSpeed(group_name, ips_per_second) => {
+                if let Some(status) = self.group_status.get_mut(&group_name)
+                    && !status.completed
+                {
+                    status.ips_per_second = Some(ips_per_second);
                 }
                 Task::none()
             }
-            MainViewMessage::IpScanned {
-                group_name,
-                total_ips,
-                scanned_count,
-            } => {
-                if let Some(status) = self.group_status.get_mut(&group_name) {
-                    status.total_ips = total_ips;
-                    status.scanned_ips = scanned_count;
+            MainViewMessage::SyncProgress(progress) => {
+                // Groups that finished or were removed keep their final status
+                let running: Vec<(String, ScanProgress)> = progress
+                    .into_iter()
+                    .filter(|(name, _)| self.scanning_groups.contains(name))
+                    .collect();
+                if self.pause_rendering {
+                    self.pending_progress.extend(running);
+                    if self.scanned_ip_total() * 10 >= self.total_ips_to_scan {
+                        self.resume_rendering();
+                    }
                 } else {
-                    self.group_status.insert(
-                        group_name,
-                        GroupScanStatus {
-                            completed: false,
-                            error: None,
-                            miner_count: 0,
-                            total_ips,
-                            scanned_ips: scanned_count,
-                        },
-                    );
+                    for (group_name, progress) in running {
+                        self.apply_progress(group_name, &progress);
+                    }
                 }
                 Task::none()
             }
+            MainViewMessage::FlushScanProgress => {
+                self.flush_progress();
+                Task::none()
+            }
+            MainViewMessage::ToggleLogPanel => {
+                self.log_open = !self.log_open;
+                Task::none()
+            }
+            // Nothing changes; the redraw picks up the next pulse step
+            MainViewMessage::PulseScanIndicator => Task::none(),
+            MainViewMessage::ReverseDnsResolved(ip, name) => {
+                self.ptr_names.insert(ip, name);
+                Task::none()
+            }
             MainViewMessage::GroupCompleted(group_name) => {
+                self.flush_progress();
                 let miner_count = self
                     .discovered_miners_by_group
                     .get(&group_name)
@@ -187,9 +644,9 @@ impl MainView {
                     .unwrap_or(0);
 
                 let existing_status = self.group_status.get(&group_name);
-                let (total_ips, scanned_ips) = existing_status
-                    .map(|s| (s.total_ips, s.scanned_ips))
-                    .unwrap_or((0, 0));
+                let (total_ips, scanned_ips, started_at) = existing_status
+                    .map(|s| (s.total_ips, s.scanned_ips, s.started_at))
+                    .unwrap_or((0, 0, None));
 
                 self.group_status.insert(
                     group_name.clone(),
@@ -199,9 +656,18 @@ impl MainView {
                         miner_count,
                         total_ips,
                         scanned_ips,
+                        ips_per_second: None,
+                        // A group that failed before reporting progress ran
+                        // from the start of the scan
+                        started_at: started_at.or(self.start_time),
+                        completed_at: Some(Instant::now()),
                     },
                 );
                 self.completed_groups += 1;
+                self.scanning_groups.remove(&group_name);
+                self.record_scan_summary(&group_name, miner_count, None);
+                self.app_config
+                    .record_miner_count(&group_name, miner_count as u32);
 
                 self.app_config.store_scan_results(
                     &group_name,
@@ -211,23 +677,27 @@ impl MainView {
                         .unwrap_or_default(),
                 );
 
-                if let Err(e) = self.app_config.save() {
-                    eprintln!("Failed to save config: {}", e);
+                // Nothing else changed, so skip the write when results stay in memory
+                if self.app_config.persist_results
+                    && let Err(e) = self.app_config.save()
+                {
+                    tracing::error!("Failed to save config: {e}");
                 }
 
                 Task::none()
             }
             MainViewMessage::GroupError { group_name, error } => {
+                self.flush_progress();
                 let existing_status = self.group_status.get(&group_name);
-                let (total_ips, scanned_ips) = existing_status
-                    .map(|s| (s.total_ips, s.scanned_ips))
-                    .unwrap_or((0, 0));
+                let (total_ips, scanned_ips, started_at) = existing_status
+                    .map(|s| (s.total_ips, s.scanned_ips, s.started_at))
+                    .unwrap_or((0, 0, None));
 
                 self.group_status.insert(
                     group_name.clone(),
                     GroupScanStatus {
                         completed: true,
-                        error: Some(error.clone()),
+                        error: Some(error.to_string()),
                         miner_count: self
                             .discovered_miners_by_group
                             .get(&group_name)
@@ -235,15 +705,44 @@ impl MainView {
                             .unwrap_or(0),
                         total_ips,
                         scanned_ips,
+                        ips_per_second: None,
+                        // A group that failed before reporting progress ran
+                        // from the start of the scan
+                        started_at: started_at.or(self.start_time),
+                        completed_at: Some(Instant::now()),
                     },
                 );
                 self.error_messages
                     .push(format!("{}: {}", group_name, error));
                 self.completed_groups += 1;
+                self.scanning_groups.remove(&group_name);
+
+                let miner_count = self
+                    .discovered_miners_by_group
+                    .get(&group_name)
+                    .map_or(0, |miners| miners.len());
+                self.record_scan_summary(&group_name, miner_count, Some(&error));
                 Task::none()
             }
             MainViewMessage::AllScansCompleted => {
+                self.resume_rendering();
                 self.is_scanning = false;
+                // Groups carried over untouched by a single-group scan have no
+                // status and weren't sighted again
+                let now = chrono::Utc::now();
+                let sighted = || {
+                    self.discovered_miners_by_group
+                        .iter()
+                        .filter(|(group, _)| self.group_status.contains_key(*group))
+                        .flat_map(|(_, miners)| miners)
+                };
+                self.app_config.record_first_seen(now, sighted());
+                self.app_config
+                    .record_hashrates(ScanRecord::from_miners(now, sighted()));
+                self.no_miners_found = self.discovered_miners_by_group.values().all(Vec::is_empty);
+                self.scan_completed = true;
+                self.record_scan_run();
+                self.auto_collapse_groups();
                 Task::none()
             }
             MainViewMessage::SortColumn(column) => {
@@ -255,6 +754,19 @@ impl MainView {
                 }
                 Task::none()
             }
+            MainViewMessage::DiscardLocalGroups => {
+                self.discard_local_groups();
+                if let Err(e) = self.app_config.save() {
+                    tracing::error!("Failed to save config: {e}");
+                }
+                Task::none()
+            }
+            // Detection and saving touch every config copy, so they're handled at the BtcToolkit level
+            MainViewMessage::ScanLocalSubnets | MainViewMessage::SaveLocalGroups => Task::none(),
+            MainViewMessage::SortGroups(key) => {
+                self.group_sort = key;
+                Task::none()
+            }
             MainViewMessage::ToggleGroupCollapse(group_name) => {
                 if self.collapsed_groups.contains(&group_name) {
                     self.collapsed_groups.remove(&group_name);
@@ -263,6 +775,18 @@ impl MainView {
                 }
                 Task::none()
             }
+            MainViewMessage::ToggleEolCollapse => {
+                self.eol_collapsed = !self.eol_collapsed;
+                Task::none()
+            }
+            MainViewMessage::ToggleIgnoredCollapse => {
+                self.ignored_collapsed = !self.ignored_collapsed;
+                Task::none()
+            }
+            MainViewMessage::ToggleMakeSummary => {
+                self.make_summary_collapsed = !self.make_summary_collapsed;
+                Task::none()
+            }
         }
     }
 
@@ -276,12 +800,15 @@ impl MainView {
             row![stats, Space::new().width(Length::Fill), toolbar]
                 .align_y(iced::alignment::Vertical::Center),
         )
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::SM)
         .width(Length::Fill);
 
+        let log_panel = self.log_open.then(|| self.view_log_panel());
+
         container(
             column![header, main_content]
+                .push(log_panel)
                 .spacing(theme::spacing::SM)
                 .padding(theme::padding::SM),
         )
@@ -290,8 +817,57 @@ impl MainView {
         .into()
     }
 
+    /// Recent log events, newest at the bottom, for troubleshooting without a
+    /// terminal.
+    fn view_log_panel(&self) -> Element<'_, MainViewMessage> {
+        let lines = log_buffer::recent();
+        let header = row![
+            theme::typography::heading("Log"),
+            Space::new().width(theme::spacing::SM),
+            theme::typography::small(format!(
+                "last {} of up to {} events",
+                lines.len(),
+                log_buffer::MAX_LOG_LINES
+            )),
+            Space::new().width(Length::Fill),
+            secondary_button(
+                "Copy",
+                None,
+                (!lines.is_empty()).then_some(MainViewMessage::CopyLog),
+            ),
+        ]
+        .align_y(iced::alignment::Vertical::Center);
+
+        let body: Element<'_, MainViewMessage> = if lines.is_empty() {
+            theme::typography::small("Nothing logged yet").into()
+        } else {
+            let list = lines.iter().fold(column![].spacing(2.0), |list, line| {
+                let text = theme::typography::tiny(line.to_text()).font(iced::Font::MONOSPACE);
+                list.push(match line.level {
+                    tracing::Level::ERROR => text.color(theme::colors::DANGER),
+                    tracing::Level::WARN => text.color(theme::colors::WARNING),
+                    _ => text,
+                })
+            });
+            scrollable(list)
+                .anchor_bottom()
+                .height(Length::Fixed(LOG_PANEL_HEIGHT))
+                .width(Length::Fill)
+                .into()
+        };
+
+        container(column![header, body].spacing(theme::spacing::SM))
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::SM)
+            .width(Length::Fill)
+            .into()
+    }
+
     fn view_toolbar(&self) -> Element<'_, MainViewMessage> {
-        let scan_button = if self.is_scanning {
+        let ready = !self.config_loading;
+        let scan_button = if self.config_loading {
+            secondary_button("Loading configuration…", None, None)
+        } else if self.is_scanning {
             danger_button(
                 "Stop",
                 Some(theme::icons::stop().into()),
@@ -310,26 +886,145 @@ impl MainView {
             }
         };
 
+        let local_scan_button = secondary_button(
+            "Scan Local",
+            Some(theme::icons::network().into()),
+            (ready && !self.is_scanning).then_some(MainViewMessage::ScanLocalSubnets),
+        );
+
+        // Edits made before the config loads would be overwritten by it
         let config_button = secondary_button(
             "Config",
+            Some(theme::icons::network().into()),
+            ready.then_some(MainViewMessage::OpenNetworkConfig),
+        );
+
+        let topology_button =
+            secondary_button("Topology", None, Some(MainViewMessage::OpenTopology));
+
+        let history_button = secondary_button("History", None, Some(MainViewMessage::OpenHistory));
+
+        let log_button = secondary_button(
+            if self.log_open { "Hide Log" } else { "Log" },
+            None,
+            Some(MainViewMessage::ToggleLogPanel),
+        );
+
+        let settings_button = secondary_button(
+            "Settings",
             Some(theme::icons::settings().into()),
-            Some(MainViewMessage::OpenNetworkConfig),
+            ready.then_some(MainViewMessage::OpenSettings),
         );
 
-        row![scan_button, config_button]
-            .spacing(theme::spacing::SM)
-            .into()
+        let has_miners = self.current_results().values().any(|m| !m.is_empty());
+        // Exports whatever is displayed: live discoveries or the stored results
+        let export_button = secondary_button(
+            "Export CSV",
+            None,
+            has_miners.then_some(MainViewMessage::ExportCsv),
+        );
+        let restart_button = danger_button(
+            "Restart All",
+            Some(theme::icons::refresh().into()),
+            (has_miners && !self.is_scanning).then_some(MainViewMessage::BatchRestartAll),
+        );
+
+        let group_sort = pick_list(
+            GroupSortKey::ALL,
+            Some(self.group_sort),
+            MainViewMessage::SortGroups,
+        )
+        .text_size(12)
+        .padding([theme::padding::XS, theme::padding::SM]);
+
+        row![
+            scan_button,
+            local_scan_button,
+            config_button,
+            topology_button,
+            history_button,
+            export_button,
+            log_button,
+            restart_button,
+            settings_button,
+            theme::typography::small("Sort groups"),
+            group_sort
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center)
+        .into()
     }
 
-    fn view_stats(&self) -> Element<'_, MainViewMessage> {
-        let enabled_groups = self.app_config.get_enabled_groups();
-        let all_results = if self.is_scanning {
+    /// Results to display right now: live discoveries while scanning, otherwise
+    /// the results stored from the last completed scan.
+    /// Logs the groups this scan covered for the history screen.
+    fn record_scan_run(&mut self) {
+        if self.group_status.is_empty() {
+            return;
+        }
+        let elapsed = self.start_time.map(|t| t.elapsed()).unwrap_or_default();
+        let mut groups: Vec<ScanRunGroup> = self
+            .group_status
+            .iter()
+            .map(|(name, status)| ScanRunGroup {
+                name: name.clone(),
+                miners_found: status.miner_count,
+                error: status.error.clone(),
+            })
+            .collect();
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+        self.app_config.record_scan_run(ScanRun {
+            started_at: chrono::Utc::now()
+                - chrono::Duration::from_std(elapsed).unwrap_or_default(),
+            duration_seconds: elapsed.as_secs(),
+            groups,
+        });
+    }
+
+    /// Expands groups with a Critical miner and collapses fully healthy ones,
+    /// as far as the user opted into either.
+    fn auto_collapse_groups(&mut self) {
+        let group_health: Vec<(String, HealthStatus)> = self
+            .discovered_miners_by_group
+            .iter()
+            .filter_map(|(group, miners)| {
+                let mut statuses = miners
+                    .iter()
+                    .filter(|miner| !self.app_config.is_ignored(miner))
+                    .map(HealthStatus::from_miner_data)
+                    .peekable();
+                statuses.peek()?;
+                Some((group.clone(), HealthStatus::worst(statuses)))
+            })
+            .collect();
+        apply_auto_collapse(
+            &mut self.collapsed_groups,
+            group_health,
+            self.app_config.expand_problem_groups,
+            self.app_config.collapse_healthy_groups,
+        );
+    }
+
+    pub fn current_results(&self) -> &HashMap<String, Vec<MinerData>> {
+        if self.is_scanning {
             &self.discovered_miners_by_group
         } else {
             self.app_config.get_all_scan_results()
-        };
+        }
+    }
 
-        let total_miners: usize = all_results.values().map(|miners| miners.len()).sum();
+    fn view_stats(&self) -> Element<'_, MainViewMessage> {
+        let enabled_groups = self.app_config.get_enabled_groups();
+        let all_results = self.current_results();
+
+        let counted_miners = || {
+            all_results
+                .values()
+                .flatten()
+                .filter(|miner| !self.app_config.is_ignored(miner))
+        };
+        let total_miners = counted_miners().count();
         let total_ips: usize = enabled_groups
             .iter()
             .map(|group| estimate_ip_count(&group.network_range))
@@ -357,6 +1052,10 @@ impl MainView {
                 format_duration(self.start_time.map(|t| t.elapsed().as_secs()).unwrap_or(0));
 
             row![
+                container(theme::typography::tiny("Scanning"))
+                    .style(theme::containers::scanning_in_progress)
+                    .padding([2.0, theme::padding::SM]),
+                Space::new().width(theme::spacing::SM),
                 theme::typography::small(format!("{} miners found", total_miners)),
                 Space::new().width(theme::spacing::MD),
                 theme::typography::small(format!(
@@ -364,13 +1063,26 @@ impl MainView {
                     scanned_ips_all_groups, total_ips_all_groups
                 )),
                 Space::new().width(theme::spacing::SM),
-                container(progress_bar(0.0..=1.0, progress_value)).width(Length::Fixed(120.0)),
+                container(
+                    progress_bar(0.0..=1.0, progress_value).style(theme::progress_bars::scanning)
+                )
+                .width(Length::Fixed(120.0)),
                 Space::new().width(theme::spacing::SM),
                 theme::typography::tiny(elapsed),
             ]
             .align_y(iced::alignment::Vertical::Center)
         } else {
-            row![
+            let completed = self.scan_completed.then(|| {
+                row![
+                    container(theme::typography::tiny("Scan complete"))
+                        .style(theme::containers::scan_complete)
+                        .padding([2.0, theme::padding::SM]),
+                    Space::new().width(theme::spacing::SM),
+                ]
+                .align_y(iced::alignment::Vertical::Center)
+            });
+            let mut idle_row = row![
+                completed,
                 theme::typography::small(format!(
                     "{} groups ({} enabled)",
                     self.app_config.scan_groups.len(),
@@ -381,21 +1093,36 @@ impl MainView {
                 Space::new().width(theme::spacing::MD),
                 theme::typography::small(format!("{} miners", total_miners)),
             ]
-            .align_y(iced::alignment::Vertical::Center)
+            .align_y(iced::alignment::Vertical::Center);
+
+            // Only worth the space for mixed fleets
+            let algorithms = algorithm_breakdown(counted_miners());
+            if algorithms.len() > 1 {
+                idle_row = idle_row
+                    .push(Space::new().width(theme::spacing::MD))
+                    .push(theme::typography::tiny(format_breakdown(&algorithms)));
+            }
+
+            idle_row
         };
 
         stats_row.into()
     }
 
     fn view_main_content(&self) -> Element<'_, MainViewMessage> {
-        // Get results from current scan or last scan
-        let results = if self.is_scanning {
-            &self.discovered_miners_by_group
-        } else {
-            self.app_config.get_all_scan_results()
-        };
+        let results = self.current_results();
+
+        if self.config_loading {
+            return container(theme::typography::small("Loading configuration…"))
+                .padding(theme::padding::MD)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x(Length::Fill)
+                .center_y(Length::Fill)
+                .into();
+        }
 
-        if self.app_config.scan_groups.is_empty() {
+        if self.app_config.scan_groups.is_empty() && self.local_groups.is_empty() {
             return container(
                 column![
                     theme::typography::small("No groups configured"),
@@ -414,10 +1141,59 @@ impl MainView {
 
         let mut content = column![].spacing(theme::spacing::SM);
 
-        for group in &self.app_config.scan_groups {
+        if self.no_miners_found && !self.is_scanning {
+            content = content.push(self.view_no_miners_help());
+        }
+
+        if !self.local_groups.is_empty() && !self.is_scanning {
+            content = content.push(self.view_save_local_groups());
+        }
+
+        let durations = self.scan_durations();
+        if self.scan_completed && !durations.is_empty() {
+            content = content.push(self.view_scan_durations(durations));
+        }
+
+        let makes = make_breakdown(
+            results
+                .values()
+                .flatten()
+                .filter(|miner| !self.app_config.is_ignored(miner)),
+        );
+        if !makes.is_empty() {
+            content = content.push(self.view_make_summary(makes));
+        }
+
+        // Temporary local groups are listed after the configured ones
+        let groups = sort_groups_by_field(&self.app_config.scan_groups, results, self.group_sort)
+            .into_iter()
+            .chain(sort_groups_by_field(
+                &self.local_groups,
+                results,
+                self.group_sort,
+            ));
+
+        let mut ignored_miners = Vec::new();
+        let mut eol_miners = Vec::new();
+
+        for group in groups {
             let estimated_ips = estimate_ip_count(&group.network_range);
             let status = self.group_status.get(&group.name);
-            let miners = results.get(&group.name);
+            let miners = results.get(&group.name).map(|miners| {
+                let (ignored, counted): (Vec<MinerData>, Vec<MinerData>) = miners
+                    .iter()
+                    .cloned()
+                    .partition(|miner| self.app_config.is_ignored(miner));
+                ignored_miners.extend(ignored);
+                eol_miners.extend(
+                    counted
+                        .iter()
+                        .filter(|miner| self.app_config.has_eol_firmware(miner))
+                        .cloned(),
+                );
+                counted
+            });
+            let miners = miners.as_ref();
             let miner_count = miners.map(|m| m.len()).unwrap_or(0);
             let is_collapsed = self.collapsed_groups.contains(&group.name);
 
@@ -430,7 +1206,11 @@ impl MainView {
                         format!("{} miners", status.miner_count)
                     }
                 } else if status.total_ips > 0 {
-                    format!("scanning {}/{}", status.scanned_ips, status.total_ips)
+                    let progress = format!("scanning {}/{}", status.scanned_ips, status.total_ips);
+                    match status.ips_per_second {
+                        Some(speed) => format!("{progress} · {speed:.0} IP/s"),
+                        None => progress,
+                    }
                 } else {
                     "scanning...".to_string()
                 }
@@ -445,22 +1225,142 @@ impl MainView {
             // Collapse indicator
             let collapse_icon = if is_collapsed { "▶" } else { "▼" };
 
+            // Worst health among the group's miners
+            let group_health: Element<'_, MainViewMessage> = match miners {
+                Some(miners) if !miners.is_empty() => theme::widgets::status_dot(
+                    HealthStatus::worst(miners.iter().map(HealthStatus::from_miner_data)),
+                ),
+                _ => Space::new().width(theme::widgets::STATUS_DOT_SIZE).into(),
+            };
+
+            // Miners found by recent scans against the most the group has had;
+            // a slide under that line means miners are dropping off
+            let trend = self
+                .app_config
+                .miner_count_history
+                .get(&group.name)
+                .filter(|counts| counts.len() > 1)
+                .map(|counts| {
+                    let expected = counts.iter().copied().max().unwrap_or(0);
+                    let latest = counts.back().copied().unwrap_or(0);
+                    let color = if latest < expected {
+                        theme::colors::WARNING
+                    } else {
+                        theme::colors::SUCCESS
+                    };
+                    let sparkline: Element<'_, MainViewMessage> =
+                        Sparkline::new(counts.iter().map(|&count| count as f32), color)
+                            .reference(expected as f32)
+                            .into();
+                    row![sparkline, Space::new().width(theme::spacing::SM)]
+                        .align_y(iced::alignment::Vertical::Center)
+                });
+
+            // "12 OK · 2 ⚠ · 1 ✕", leaving out empty counts; nothing until
+            // some miner has enough data to assess
+            let health_summary = miners.and_then(|miners| {
+                let (healthy, warning, critical, _) = health_counts(miners);
+                let parts: Vec<Element<'_, MainViewMessage>> = [
+                    (healthy, "OK", HealthStatus::Healthy),
+                    (warning, "⚠", HealthStatus::Warning),
+                    (critical, "✕", HealthStatus::Critical),
+                ]
+                .into_iter()
+                .filter(|(count, _, _)| *count > 0)
+                .map(|(count, symbol, status)| {
+                    theme::typography::small(format!("{count} {symbol}"))
+                        .color(status.color())
+                        .into()
+                })
+                .collect();
+                if parts.is_empty() {
+                    return None;
+                }
+                let mut summary = row![].spacing(theme::spacing::XS);
+                for (index, part) in parts.into_iter().enumerate() {
+                    if index > 0 {
+                        summary = summary.push(theme::typography::small("·"));
+                    }
+                    summary = summary.push(part);
+                }
+                Some(
+                    row![summary, Space::new().width(theme::spacing::MD)]
+                        .align_y(iced::alignment::Vertical::Center),
+                )
+            });
+
+            // A failure from a previous session, until this session scans the group
+            let last_error = self
+                .app_config
+                .last_errors
+                .get(&group.name)
+                .filter(|_| status.is_none())
+                .map(|error| {
+                    row![
+                        tooltip(
+                            container(theme::typography::tiny("Last scan failed"))
+                                .style(theme::containers::warning_adaptive)
+                                .padding([0.0, theme::padding::XS]),
+                            container(theme::typography::small(error.as_str()))
+                                .style(theme::containers::tooltip_adaptive)
+                                .padding(theme::padding::SM),
+                            tooltip::Position::Bottom,
+                        ),
+                        Space::new().width(theme::spacing::SM),
+                    ]
+                    .align_y(iced::alignment::Vertical::Center)
+                });
+
+            // Configured groups can be (re)scanned on their own, even mid-scan
+            let is_configured = self
+                .app_config
+                .scan_groups
+                .iter()
+                .any(|configured| configured.name == group.name);
+            // ...and a running group can be stopped without ending the scan
+            let scan_now = if self.is_group_scanning(&group.name) {
+                Some(
+                    button(theme::typography::small("Stop"))
+                        .style(button::danger)
+                        .padding([2.0, theme::padding::SM])
+                        .on_press(MainViewMessage::StopGroup(group.name.clone())),
+                )
+            } else {
+                is_configured.then(|| {
+                    button(theme::typography::small("Scan now"))
+                        .style(button::secondary)
+                        .padding([2.0, theme::padding::SM])
+                        .on_press(MainViewMessage::ScanGroupNow(group.name.clone()))
+                })
+            };
+
             // Group header (clickable)
             let group_header = button(
                 container(
                     row![
                         theme::typography::body(collapse_icon),
                         Space::new().width(theme::spacing::SM),
-                        theme::typography::body(&group.name),
+                        group_health,
+                        Space::new().width(theme::spacing::SM),
+                        truncated_text(&group.name, GROUP_NAME_CHARS, theme::typography::body),
                         Space::new().width(theme::spacing::MD),
-                        theme::typography::small(&group.network_range),
+                        truncated_text(
+                            &group.network_range,
+                            GROUP_RANGE_CHARS,
+                            theme::typography::small
+                        ),
                         theme::typography::small(format!(" (~{})", estimated_ips)),
                         Space::new().width(Length::Fill),
-                        theme::typography::body(status_text)
+                        last_error,
+                        health_summary,
+                        trend,
+                        theme::typography::body(status_text),
+                        Space::new().width(theme::spacing::SM),
+                        scan_now,
                     ]
                     .align_y(iced::alignment::Vertical::Center),
                 )
-                .style(theme::containers::header)
+                .style(theme::containers::header_adaptive)
                 .padding([theme::padding::SM, theme::padding::MD])
                 .width(Length::Fill),
             )
@@ -482,122 +1382,17 @@ impl MainView {
                         let mut sorted_miners = miners.clone();
                         self.sort_miners(&mut sorted_miners);
 
-                        // Table header with sortable columns
-                        let sort_arrow = |col: SortColumn| -> String {
-                            if self.sort_column == Some(col) {
-                                match self.sort_direction {
-                                    SortDirection::Ascending => " ▲".to_string(),
-                                    SortDirection::Descending => " ▼".to_string(),
-                                }
-                            } else {
-                                String::new()
-                            }
-                        };
-
-                        let table_header = container(
-                            row![
-                                container(
-                                    button(theme::typography::small(format!(
-                                        "IP{}",
-                                        sort_arrow(SortColumn::IpAddress)
-                                    )))
-                                    .style(button::text)
-                                    .padding(0)
-                                    .on_press(MainViewMessage::SortColumn(SortColumn::IpAddress))
-                                )
-                                .width(Length::FillPortion(2)),
-                                container(
-                                    button(theme::typography::small(format!(
-                                        "Model{}",
-                                        sort_arrow(SortColumn::Model)
-                                    )))
-                                    .style(button::text)
-                                    .padding(0)
-                                    .on_press(MainViewMessage::SortColumn(SortColumn::Model))
-                                )
-                                .width(Length::FillPortion(2)),
-                                container(
-                                    button(theme::typography::small(format!(
-                                        "Make{}",
-                                        sort_arrow(SortColumn::Make)
-                                    )))
-                                    .style(button::text)
-                                    .padding(0)
-                                    .on_press(MainViewMessage::SortColumn(SortColumn::Make))
-                                )
-                                .width(Length::FillPortion(1)),
-                                container(
-                                    button(theme::typography::small(format!(
-                                        "Firmware{}",
-                                        sort_arrow(SortColumn::Firmware)
-                                    )))
-                                    .style(button::text)
-                                    .padding(0)
-                                    .on_press(MainViewMessage::SortColumn(SortColumn::Firmware))
-                                )
-                                .width(Length::FillPortion(1)),
-                                container(
-                                    button(theme::typography::small(format!(
-                                        "Version{}",
-                                        sort_arrow(SortColumn::FirmwareVersion)
-                                    )))
-                                    .style(button::text)
-                                    .padding(0)
-                                    .on_press(
-                                        MainViewMessage::SortColumn(SortColumn::FirmwareVersion)
-                                    )
-                                )
-                                .width(Length::FillPortion(1)),
-                            ]
-                            .spacing(theme::spacing::XS),
-                        )
-                        .padding(theme::padding::XS);
-
-                        let mut miners_list = column![].spacing(2.0);
-
-                        for miner in sorted_miners {
-                            let miner_ip = match miner.ip {
-                                std::net::IpAddr::V4(ipv4) => ipv4,
-                                std::net::IpAddr::V6(_) => continue,
-                            };
-
-                            let miner_row = button(
-                                row![
-                                    container(theme::typography::mono(miner_ip.to_string()))
-                                        .width(Length::FillPortion(2)),
-                                    container(theme::typography::mono(
-                                        format!("{}", miner.device_info.model).replace("Plus", "+")
-                                    ))
-                                    .width(Length::FillPortion(2)),
-                                    container(theme::typography::mono(format!(
-                                        "{}",
-                                        miner.device_info.make
-                                    )))
-                                    .width(Length::FillPortion(1)),
-                                    container(theme::typography::mono(format!(
-                                        "{}",
-                                        miner.device_info.firmware
-                                    )))
-                                    .width(Length::FillPortion(1)),
-                                    container(theme::typography::mono(
-                                        miner.firmware_version.as_deref().unwrap_or("-")
-                                    ))
-                                    .width(Length::FillPortion(1)),
-                                ]
-                                .spacing(theme::spacing::XS)
-                                .align_y(iced::alignment::Vertical::Center),
-                            )
-                            .style(theme::buttons::table_row)
-                            .padding(theme::padding::XS)
-                            .on_press(MainViewMessage::OpenDeviceDetail(miner_ip))
-                            .width(Length::Fill);
-
-                            miners_list = miners_list.push(miner_row);
-                        }
-
-                        container(column![table_header, miners_list].spacing(theme::spacing::XS))
-                            .padding([0.0, theme::padding::MD])
-                            .into()
+                        container(miner_table::view(
+                            &sorted_miners,
+                            self.sort_column,
+                            self.sort_direction,
+                            MainViewMessage::SortColumn,
+                            MainViewMessage::OpenDeviceDetail,
+                            |miner| self.view_ip_badges_in(Some(&group.expected_device), miner),
+                            self.app_config.temperature_unit,
+                        ))
+                        .padding([0.0, theme::padding::MD])
+                        .into()
                     }
                 } else {
                     container(theme::typography::tiny("Not scanned"))
@@ -611,17 +1406,648 @@ impl MainView {
             content = content.push(group_section);
         }
 
+        if !eol_miners.is_empty() {
+            content = content.push(self.view_eol_miners(eol_miners));
+        }
+
+        if !ignored_miners.is_empty() {
+            content = content.push(self.view_ignored_miners(ignored_miners));
+        }
+
         container(scrollable(content))
-            .style(theme::containers::card)
+            .style(theme::containers::card_adaptive)
             .padding(theme::padding::SM)
             .width(Length::Fill)
             .height(Length::Fill)
             .into()
     }
 
+    /// Collapsible fleet composition: one stacked bar segment per make, sized
+    /// by its miner count, with a legend underneath.
+    fn view_make_summary(&self, makes: Vec<(MinerMake, usize)>) -> Element<'_, MainViewMessage> {
+        let collapse_icon = if self.make_summary_collapsed {
+            "▶"
+        } else {
+            "▼"
+        };
+        let total: usize = makes.iter().map(|(_, count)| count).sum();
+
+        let header = button(
+            container(
+                row![
+                    theme::typography::body(collapse_icon),
+                    Space::new().width(theme::spacing::SM),
+                    theme::typography::body("Fleet by Make"),
+                    Space::new().width(Length::Fill),
+                    theme::typography::small(format!("{} makes · {total} miners", makes.len())),
+                ]
+                .align_y(iced::alignment::Vertical::Center),
+            )
+            .style(theme::containers::header_adaptive)
+            .padding([theme::padding::SM, theme::padding::MD])
+            .width(Length::Fill),
+        )
+        .style(button::text)
+        .padding(0)
+        .on_press(MainViewMessage::ToggleMakeSummary)
+        .width(Length::Fill);
+
+        if self.make_summary_collapsed {
+            return header.into();
+        }
+
+        let colored = || makes.iter().zip(MAKE_COLORS.iter().cycle());
+        let bar = colored().fold(
+            row![].height(MAKE_BAR_HEIGHT),
+            |bar, ((_, count), &color)| {
+                let portion = u16::try_from(*count).unwrap_or(u16::MAX);
+                bar.push(
+                    container(Space::new())
+                        .style(move |_theme: &iced::Theme| container::Style {
+                            background: Some(iced::Background::Color(color)),
+                            ..Default::default()
+                        })
+                        .width(Length::FillPortion(portion))
+                        .height(Length::Fill),
+                )
+            },
+        );
+        let legend = colored().fold(
+            row![].spacing(theme::spacing::MD),
+            |legend, ((make, count), &color)| {
+                legend.push(
+                    row![
+                        theme::typography::small("■").color(color),
+                        theme::typography::small(format!(
+                            "{make}: {count} ({:.0}%)",
+                            *count as f64 / total as f64 * 100.0
+                        )),
+                    ]
+                    .spacing(theme::spacing::XS),
+                )
+            },
+        );
+
+        column![
+            header,
+            container(column![bar, legend].spacing(theme::spacing::SM))
+                .padding([theme::padding::XS, theme::padding::MD])
+        ]
+        .spacing(theme::spacing::XS)
+        .into()
+    }
+
+    /// Collapsible compliance list of miners running end-of-life firmware.
+    /// They stay in their groups too.
+    fn view_eol_miners(&self, mut miners: Vec<MinerData>) -> Element<'_, MainViewMessage> {
+        let collapse_icon = if self.eol_collapsed { "▶" } else { "▼" };
+
+        let header = button(
+            container(
+                row![
+                    theme::typography::body(collapse_icon),
+                    Space::new().width(theme::spacing::SM),
+                    theme::typography::body("EOL Firmware"),
+                    Space::new().width(Length::Fill),
+                    theme::typography::body(format!("{} miners", miners.len()))
+                        .color(theme::colors::DANGER),
+                ]
+                .align_y(iced::alignment::Vertical::Center),
+            )
+            .style(theme::containers::header_adaptive)
+            .padding([theme::padding::SM, theme::padding::MD])
+            .width(Length::Fill),
+        )
+        .style(button::text)
+        .padding(0)
+        .on_press(MainViewMessage::ToggleEolCollapse)
+        .width(Length::Fill);
+
+        if self.eol_collapsed {
+            return header.into();
+        }
+
+        self.sort_miners(&mut miners);
+        let table = container(miner_table::view(
+            &miners,
+            self.sort_column,
+            self.sort_direction,
+            MainViewMessage::SortColumn,
+            MainViewMessage::OpenDeviceDetail,
+            |miner| self.view_ip_badges(miner),
+            self.app_config.temperature_unit,
+        ))
+        .padding([0.0, theme::padding::MD]);
+
+        column![header, table].spacing(theme::spacing::XS).into()
+    }
+
+    /// Collapsible list of miners whose serial numbers are on the ignore list.
+    fn view_ignored_miners(&self, mut miners: Vec<MinerData>) -> Element<'_, MainViewMessage> {
+        let collapse_icon = if self.ignored_collapsed { "▶" } else { "▼" };
+
+        let header = button(
+            container(
+                row![
+                    theme::typography::body(collapse_icon),
+                    Space::new().width(theme::spacing::SM),
+                    theme::typography::body("Ignored Miners"),
+                    Space::new().width(Length::Fill),
+                    theme::typography::body(format!("{} miners", miners.len())),
+                ]
+                .align_y(iced::alignment::Vertical::Center),
+            )
+            .style(theme::containers::header_adaptive)
+            .padding([theme::padding::SM, theme::padding::MD])
+            .width(Length::Fill),
+        )
+        .style(button::text)
+        .padding(0)
+        .on_press(MainViewMessage::ToggleIgnoredCollapse)
+        .width(Length::Fill);
+
+        if self.ignored_collapsed {
+            return header.into();
+        }
+
+        self.sort_miners(&mut miners);
+        let table = container(miner_table::view(
+            &miners,
+            self.sort_column,
+            self.sort_direction,
+            MainViewMessage::SortColumn,
+            MainViewMessage::OpenDeviceDetail,
+            |miner| self.view_ip_badges(miner),
+            self.app_config.temperature_unit,
+        ))
+        .padding([0.0, theme::padding::MD]);
+
+        column![header, table].spacing(theme::spacing::XS).into()
+    }
+
+    /// Offer to keep the subnets found by "Scan Local" once their scan is done.
+    fn view_save_local_groups(&self) -> Element<'_, MainViewMessage> {
+        let ranges: Vec<&str> = self
+            .local_groups
+            .iter()
+            .map(|group| group.network_range.as_str())
+            .collect();
+
+        container(
+            row![
+                column![
+                    theme::typography::heading("Keep the detected subnets?"),
+                    theme::typography::small(format!(
+                        "Save {} as scan groups to rescan them with the Scan button",
+                        ranges.join(", ")
+                    )),
+                ]
+                .spacing(theme::spacing::XS)
+                .width(Length::Fill),
+                secondary_button("Discard", None, Some(MainViewMessage::DiscardLocalGroups)),
+                primary_button("Save Groups", None, Some(MainViewMessage::SaveLocalGroups)),
+            ]
+            .spacing(theme::spacing::MD)
+            .align_y(iced::Alignment::Center),
+        )
+        .style(theme::containers::card_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// Groups of the last scan with how long each took, slowest first.
+    fn scan_durations(&self) -> Vec<(&str, Duration)> {
+        let mut durations: Vec<(&str, Duration)> = self
+            .group_status
+            .iter()
+            .filter_map(|(name, status)| Some((name.as_str(), status.duration()?)))
+            .collect();
+        durations.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        durations
+    }
+
+    /// Post-scan breakdown of scan time per group, to spot slow ranges.
+    fn view_scan_durations<'a>(
+        &self,
+        durations: Vec<(&'a str, Duration)>,
+    ) -> Element<'a, MainViewMessage> {
+        let rows = durations.into_iter().fold(
+            column![theme::typography::heading("Scan time by group")].spacing(theme::spacing::XS),
+            |rows, (name, duration)| {
+                rows.push(
+                    row![
+                        theme::typography::small(name).width(Length::FillPortion(3)),
+                        theme::typography::mono(format_duration(duration.as_secs()))
+                            .width(Length::FillPortion(1)),
+                    ]
+                    .spacing(theme::spacing::SM),
+                )
+            },
+        );
+
+        container(rows)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
+    /// Guidance shown after a scan that found nothing, usually a wrong range.
+    fn view_no_miners_help(&self) -> Element<'_, MainViewMessage> {
+        container(
+            row![
+                column![
+                    theme::typography::heading("No miners found"),
+                    theme::typography::small(
+                        "Check that the network ranges match the subnet your miners are on, \
+                         that this computer can reach that VLAN, and that no firewall blocks \
+                         the miner APIs (ports 80, 4028 and 50051).",
+                    ),
+                ]
+                .spacing(theme::spacing::XS)
+                .width(Length::Fill),
+                secondary_button(
+                    "Detect Local Subnet",
+                    Some(theme::icons::network().into()),
+                    Some(MainViewMessage::DetectLocalSubnet),
+                ),
+            ]
+            .spacing(theme::spacing::MD)
+            .align_y(iced::Alignment::Center),
+        )
+        .style(theme::containers::warning_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// Badges beside a miner's IP outside a group: its change badge, EOL
+    /// badge and PTR name.
+    fn view_ip_badges(&self, miner: &MinerData) -> Element<'static, MainViewMessage> {
+        self.view_ip_badges_in(None, miner)
+    }
+
+    /// Badges beside a miner's IP, plus an "unexpected" badge when it differs
+    /// from the device its group expects.
+    fn view_ip_badges_in(
+        &self,
+        expected: Option<&DeviceExpectation>,
+        miner: &MinerData,
+    ) -> Element<'static, MainViewMessage> {
+        let mismatches = expected.map_or_else(Vec::new, |expected| expected.mismatches(miner));
+        let unexpected = (!mismatches.is_empty()).then(|| {
+            let parts: Vec<&str> = mismatches.iter().map(|m| m.label()).collect();
+            tooltip(
+                container(theme::typography::tiny("Unexpected device"))
+                    .style(theme::containers::warning_adaptive)
+                    .padding([0.0, theme::padding::XS]),
+                container(theme::typography::small(format!(
+                    "Different {} than this group expects",
+                    parts.join(", ")
+                )))
+                .style(theme::containers::tooltip_adaptive)
+                .padding(theme::padding::SM),
+                tooltip::Position::Top,
+            )
+        });
+
+        row![self.view_change_badge(miner)]
+            .push(self.app_config.has_eol_firmware(miner).then(|| {
+                container(theme::typography::tiny("EOL"))
+                    .style(theme::containers::error_adaptive)
+                    .padding([0.0, theme::padding::XS])
+            }))
+            .push(unexpected)
+            .push(self.view_ptr_name(miner))
+            .spacing(theme::spacing::XS)
+            .align_y(iced::alignment::Vertical::Center)
+            .into()
+    }
+
+    /// Reverse DNS name, with the miner's own hostname in the tooltip when
+    /// the two differ.
+    fn view_ptr_name(&self, miner: &MinerData) -> Option<Element<'static, MainViewMessage>> {
+        let name = self.ptr_names.get(&miner.ip)?.clone()?;
+        let detail = match miner.hostname.as_deref() {
+            Some(hostname) if !hostname.eq_ignore_ascii_case(&name) => {
+                format!("Reverse DNS name\nMiner hostname: {hostname}")
+            }
+            _ => "Reverse DNS name".to_string(),
+        };
+        Some(
+            tooltip(
+                theme::typography::tiny(name).color(theme::colors::TEXT_TERTIARY),
+                container(theme::typography::small(detail))
+                    .style(theme::containers::tooltip_adaptive)
+                    .padding(theme::padding::SM),
+                tooltip::Position::Top,
+            )
+            .into(),
+        )
+    }
+
+    /// "changed" badge with a tooltip listing differences from the previous scan.
+    fn view_change_badge(&self, miner: &MinerData) -> Element<'static, MainViewMessage> {
+        let changes = self
+            .previous_snapshots
+            .get(&miner.ip)
+            .map(|previous| describe_changes(previous, &MinerSnapshot::from_miner_data(miner)))
+            .unwrap_or_default();
+
+        if changes.is_empty() {
+            return Space::new().width(0).into();
+        }
+
+        tooltip(
+            container(theme::typography::tiny("changed"))
+                .style(theme::containers::warning_adaptive)
+                .padding([0.0, theme::padding::XS]),
+            container(theme::typography::small(changes.join("\n")))
+                .style(theme::containers::tooltip_adaptive)
+                .padding(theme::padding::SM),
+            tooltip::Position::Top,
+        )
+        .into()
+    }
+
     fn sort_miners(&self, miners: &mut [MinerData]) {
         if let Some(column) = self.sort_column {
             sort_miners_by_column(miners, column, self.sort_direction);
         }
     }
 }
+
+/// Healthy, Warning, Critical and not-yet-assessed miners among `miners`.
+fn health_counts(miners: &[MinerData]) -> (usize, usize, usize, usize) {
+    count_health(miners.iter().map(HealthStatus::from_miner_data))
+}
+
+/// `health_counts` over already computed statuses; `Online` miners only have
+/// partial data, so they count as unassessed.
+fn count_health(statuses: impl IntoIterator<Item = HealthStatus>) -> (usize, usize, usize, usize) {
+    statuses.into_iter().fold(
+        (0, 0, 0, 0),
+        |(healthy, warning, critical, unknown), status| match status {
+            HealthStatus::Healthy => (healthy + 1, warning, critical, unknown),
+            HealthStatus::Warning => (healthy, warning + 1, critical, unknown),
+            HealthStatus::Critical => (healthy, warning, critical + 1, unknown),
+            HealthStatus::Online | HealthStatus::Unknown => {
+                (healthy, warning, critical, unknown + 1)
+            }
+        },
+    )
+}
+
+/// Updates `collapsed` from each group's worst miner health: Critical groups
+/// open when `expand_problems` is set, all-Healthy groups close when
+/// `collapse_healthy` is set, and everything else keeps its state.
+fn apply_auto_collapse(
+    collapsed: &mut HashSet<String>,
+    group_health: impl IntoIterator<Item = (String, HealthStatus)>,
+    expand_problems: bool,
+    collapse_healthy: bool,
+) {
+    for (group, health) in group_health {
+        match health {
+            HealthStatus::Critical if expand_problems => {
+                collapsed.remove(&group);
+            }
+            HealthStatus::Healthy if collapse_healthy => {
+                collapsed.insert(group);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_count_health() {
+        use HealthStatus::*;
+        assert_eq!(
+            count_health([
+                Healthy, Critical, Healthy, Warning, Online, Unknown, Healthy
+            ]),
+            (3, 1, 1, 2)
+        );
+        assert_eq!(count_health([]), (0, 0, 0, 0));
+        assert_eq!(health_counts(&[]), (0, 0, 0, 0));
+    }
+
+    fn progress(group_name: &str, total_ips: usize, scanned_ips: usize) -> MainViewMessage {
+        let progress = ScanProgress {
+            total_ips,
+            scanned_ips,
+            ..ScanProgress::new(Instant::now())
+        };
+        MainViewMessage::SyncProgress(HashMap::from([(group_name.to_string(), progress)]))
+    }
+
+    #[test]
+    fn test_early_progress_is_buffered_until_tick() {
+        let mut view = MainView::with_config(AppConfig::default());
+        let _ = view.update(MainViewMessage::StartScan);
+
+        let _ = view.update(progress("Default", 254, 5));
+        let _ = view.update(progress("Default", 254, 10));
+        assert!(view.is_rendering_paused());
+        assert!(!view.group_status.contains_key("Default"));
+
+        // The render tick applies only the latest buffered progress
+        let _ = view.update(MainViewMessage::FlushScanProgress);
+        assert_eq!(view.group_status["Default"].scanned_ips, 10);
+
+        // Passing 10% of the IPs resumes per-message updates
+        let _ = view.update(progress("Default", 254, 26));
+        assert!(!view.is_rendering_paused());
+        assert_eq!(view.group_status["Default"].scanned_ips, 26);
+        let _ = view.update(progress("Default", 254, 27));
+        assert_eq!(view.group_status["Default"].scanned_ips, 27);
+    }
+
+    #[test]
+    fn test_group_scan_keeps_other_groups() {
+        let farm_b = ScanGroup::new("Farm B".to_string(), "10.0.1.0/24".to_string());
+        // Completed groups save persisted results, which would overwrite the real config file
+        let mut config = AppConfig {
+            persist_results: false,
+            ..AppConfig::default()
+        };
+        config.scan_groups.push(farm_b.clone());
+        config.store_scan_results("Default", Vec::new());
+
+        // Scanning one group from idle keeps the other group's stored results
+        let mut view = MainView::with_config(config.clone());
+        view.start_group_scan(&farm_b);
+        assert!(view.is_scanning());
+        assert!(view.is_group_scanning("Farm B"));
+        assert!(!view.is_group_scanning("Default"));
+        assert!(view.current_results().contains_key("Default"));
+        assert_eq!(view.total_groups, 1);
+        let _ = view.update(MainViewMessage::GroupCompleted("Farm B".to_string()));
+        assert!(!view.has_groups_in_progress());
+
+        // Rescanning a group that already finished mid-scan leaves the others running
+        let mut view = MainView::with_config(config);
+        let _ = view.update(MainViewMessage::StartScan);
+        let _ = view.update(progress("Farm B", 254, 100));
+        let _ = view.update(MainViewMessage::GroupCompleted("Default".to_string()));
+        assert_eq!((view.completed_groups, view.total_groups), (1, 2));
+
+        let default_group = view.app_config.scan_groups[0].clone();
+        view.start_group_scan(&default_group);
+        assert_eq!((view.completed_groups, view.total_groups), (0, 2));
+        assert!(view.is_group_scanning("Default"));
+        assert!(view.is_group_scanning("Farm B"));
+        assert!(!view.group_status.contains_key("Default"));
+        assert_eq!(view.group_status["Farm B"].scanned_ips, 100);
+    }
+
+    #[test]
+    fn test_group_scan_durations() {
+        let farm_b = ScanGroup::new("Farm B".to_string(), "10.0.1.0/24".to_string());
+        // Completed groups save persisted results, which would overwrite the real config file
+        let mut config = AppConfig {
+            persist_results: false,
+            ..AppConfig::default()
+        };
+        config.scan_groups.push(farm_b);
+        let mut view = MainView::with_config(config);
+        let _ = view.update(MainViewMessage::StartScan);
+
+        // Farm B started well before Default, so it's the slower of the two
+        let started = Instant::now();
+        let mut early = ScanProgress::new(started - Duration::from_secs(90));
+        early.total_ips = 254;
+        let _ = view.update(MainViewMessage::SyncProgress(HashMap::from([
+            ("Farm B".to_string(), early),
+            ("Default".to_string(), ScanProgress::new(started)),
+        ])));
+        let _ = view.update(MainViewMessage::FlushScanProgress);
+        assert!(view.scan_durations().is_empty());
+
+        let _ = view.update(MainViewMessage::GroupCompleted("Default".to_string()));
+        let _ = view.update(MainViewMessage::GroupError {
+            group_name: "Farm B".to_string(),
+            error: ScannerError::TooManyFailures(10),
+        });
+        let _ = view.update(MainViewMessage::AllScansCompleted);
+
+        let durations = view.scan_durations();
+        let names: Vec<&str> = durations.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["Farm B", "Default"]);
+        assert!(durations[0].1 >= Duration::from_secs(90));
+        assert!(view.scan_completed);
+    }
+
+    #[test]
+    fn test_reconcile_groups_after_config_edit() {
+        let mut view = MainView::with_config(AppConfig::default());
+        for name in ["Default", "Farm B", "Farm C"] {
+            let _ = view.update(MainViewMessage::GroupError {
+                group_name: name.to_string(),
+                error: ScannerError::ChannelClosed,
+            });
+            view.discovered_miners_by_group
+                .insert(name.to_string(), Vec::new());
+        }
+
+        // "Default" renamed to "Farm A", "Farm B" deleted, "Farm C" kept, "Farm D" added
+        let mut config = AppConfig::default();
+        config.scan_groups[0].name = "Farm A".to_string();
+        for name in ["Farm C", "Farm D"] {
+            config
+                .scan_groups
+                .push(ScanGroup::new(name.to_string(), "10.0.3.0/24".to_string()));
+        }
+        view.set_app_config(config);
+        view.reconcile_groups(&[("Default".to_string(), "Farm A".to_string())]);
+
+        let mut statuses: Vec<&String> = view.group_status.keys().collect();
+        statuses.sort();
+        assert_eq!(statuses, ["Farm A", "Farm C"]);
+        let mut results: Vec<&String> = view.discovered_miners_by_group.keys().collect();
+        results.sort();
+        assert_eq!(results, ["Farm A", "Farm C"]);
+        assert_eq!(
+            view.error_messages,
+            [
+                "Farm A: Communication channel closed",
+                "Farm C: Communication channel closed"
+            ]
+        );
+        assert!(!view.group_status.contains_key("Farm D"));
+    }
+
+    #[test]
+    fn test_stopped_scan_keeps_last_error() {
+        let mut view = MainView::with_config(AppConfig {
+            persist_results: false,
+            ..AppConfig::default()
+        });
+        for error in [ScannerError::TooManyFailures(10), ScannerError::Cancelled] {
+            let _ = view.update(MainViewMessage::GroupError {
+                group_name: "Default".to_string(),
+                error,
+            });
+        }
+
+        assert_eq!(
+            view.app_config.last_errors["Default"],
+            ScannerError::TooManyFailures(10).to_string()
+        );
+        assert_eq!(view.app_config.scan_history("Default").len(), 2);
+    }
+
+    #[test]
+    fn test_open_device_detail_preserves_scan_progress() {
+        let mut view = MainView::with_config(AppConfig::default());
+        let _ = view.update(MainViewMessage::StartScan);
+        let _ = view.update(progress("Default", 256, 42));
+
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 42));
+        let _ = view.update(MainViewMessage::OpenDeviceDetail(ip));
+
+        assert!(view.is_scanning());
+        let status = &view.group_status["Default"];
+        assert_eq!(status.scanned_ips, 42);
+        assert_eq!(status.total_ips, 256);
+        assert!(!status.completed);
+
+        // Further scan progress still applies while the detail page is open
+        let _ = view.update(progress("Default", 256, 43));
+        assert_eq!(view.group_status["Default"].scanned_ips, 43);
+    }
+
+    #[test]
+    fn test_auto_collapse() {
+        let health = || {
+            vec![
+                ("Broken".to_string(), HealthStatus::Critical),
+                ("Fine".to_string(), HealthStatus::Healthy),
+                ("Meh".to_string(), HealthStatus::Warning),
+            ]
+        };
+        let all_collapsed =
+            || -> HashSet<String> { ["Broken", "Meh"].into_iter().map(String::from).collect() };
+
+        let mut collapsed = all_collapsed();
+        apply_auto_collapse(&mut collapsed, health(), false, false);
+        assert_eq!(collapsed, all_collapsed());
+
+        apply_auto_collapse(&mut collapsed, health(), true, false);
+        assert!(!collapsed.contains("Broken"));
+        assert!(collapsed.contains("Meh"));
+        assert!(!collapsed.contains("Fine"));
+
+        apply_auto_collapse(&mut collapsed, health(), true, true);
+        assert!(collapsed.contains("Fine"));
+        assert!(collapsed.contains("Meh"));
+        assert!(!collapsed.contains("Broken"));
+    }
+}
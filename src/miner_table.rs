@@ -0,0 +1,291 @@
+use crate::formatters::{TempUnit, format_hashrate};
+use crate::health::{HealthReport, HealthStatus, HealthThresholds, hashrate_ratio};
+use crate::sorting::{SortColumn, SortDirection};
+use crate::theme;
+use crate::ui_helpers::truncated_text;
+use asic_rs::data::miner::MinerData;
+use iced::widget::{Space, button, column, container, row, tooltip};
+use iced::{Element, Length};
+use std::net::IpAddr;
+
+/// Width of the leading health indicator column
+const HEALTH_COLUMN_WIDTH: f32 = 16.0;
+
+/// Characters shown in a cell before it is cut with an ellipsis
+const WIDE_CELL_CHARS: usize = 20;
+const NARROW_CELL_CHARS: usize = 12;
+
+/// Sortable table of miners, shared by every screen that lists scan results.
+///
+/// `miners` are shown in the given order; sorting is the caller's job so the
+/// arrows reflect `sort_column`/`sort_direction`. `ip_badge` renders optional
+/// extras next to the IP, such as the "changed" badge.
+pub fn view<'a, Message: Clone + 'static>(
+    miners: &[MinerData],
+    sort_column: Option<SortColumn>,
+    sort_direction: SortDirection,
+    on_sort: impl Fn(SortColumn) -> Message,
+    on_select: impl Fn(IpAddr) -> Message,
+    ip_badge: impl Fn(&MinerData) -> Element<'a, Message>,
+    temperature_unit: TempUnit,
+) -> Element<'a, Message> {
+    // Table header with sortable columns
+    let sort_arrow = |col: SortColumn| -> String {
+        if sort_column == Some(col) {
+            match sort_direction {
+                SortDirection::Ascending => " ▲".to_string(),
+                SortDirection::Descending => " ▼".to_string(),
+            }
+        } else {
+            String::new()
+        }
+    };
+
+    let table_header = container(
+        row![
+            Space::new().width(HEALTH_COLUMN_WIDTH),
+            container(
+                button(theme::typography::small(format!(
+                    "IP{}",
+                    sort_arrow(SortColumn::IpAddress)
+                )))
+                .style(button::text)
+                .padding(0)
+                .on_press(on_sort(SortColumn::IpAddress))
+            )
+            .width(Length::FillPortion(2)),
+            container(
+                button(theme::typography::small(format!(
+                    "Model{}",
+                    sort_arrow(SortColumn::Model)
+                )))
+                .style(button::text)
+                .padding(0)
+                .on_press(on_sort(SortColumn::Model))
+            )
+            .width(Length::FillPortion(2)),
+            container(
+                button(theme::typography::small(format!(
+                    "Make{}",
+                    sort_arrow(SortColumn::Make)
+                )))
+                .style(button::text)
+                .padding(0)
+                .on_press(on_sort(SortColumn::Make))
+            )
+            .width(Length::FillPortion(1)),
+            container(
+                button(theme::typography::small(format!(
+                    "Firmware{}",
+                    sort_arrow(SortColumn::Firmware)
+                )))
+                .style(button::text)
+                .padding(0)
+                .on_press(on_sort(SortColumn::Firmware))
+            )
+            .width(Length::FillPortion(1)),
+            container(
+                button(theme::typography::small(format!(
+                    "Version{}",
+                    sort_arrow(SortColumn::FirmwareVersion)
+                )))
+                .style(button::text)
+                .padding(0)
+                .on_press(on_sort(SortColumn::FirmwareVersion))
+            )
+            .width(Length::FillPortion(1)),
+            container(
+                button(theme::typography::small(format!(
+                    "Algo{}",
+                    sort_arrow(SortColumn::Algorithm)
+                )))
+                .style(button::text)
+                .padding(0)
+                .on_press(on_sort(SortColumn::Algorithm))
+            )
+            .width(Length::FillPortion(1)),
+            container(theme::typography::small("Hashrate")).width(Length::FillPortion(1)),
+        ]
+        .spacing(theme::spacing::XS),
+    )
+    .padding(theme::padding::XS);
+
+    let mut miners_list = column![].spacing(2.0);
+
+    for miner in miners {
+        let miner_ip = miner.ip;
+
+        let mut cells = row![
+            container(view_health_dot(miner, temperature_unit)).width(HEALTH_COLUMN_WIDTH),
+            container(
+                row![
+                    theme::typography::mono(miner_ip.to_string()),
+                    ip_badge(miner)
+                ]
+                .spacing(theme::spacing::XS)
+                .align_y(iced::alignment::Vertical::Center)
+            )
+            .width(Length::FillPortion(2)),
+        ];
+
+        if is_unidentified(miner) {
+            // Blank make/model/firmware cells look broken; say what we know instead
+            cells = cells.push(
+                container(
+                    row![
+                        theme::typography::small(format!("Unidentified device at {miner_ip}"))
+                            .color(theme::colors::TEXT_TERTIARY),
+                        theme::typography::tiny("open web UI to identify")
+                            .color(theme::colors::TEXT_DISABLED),
+                    ]
+                    .spacing(theme::spacing::SM)
+                    .align_y(iced::alignment::Vertical::Center),
+                )
+                .width(Length::FillPortion(7)),
+            );
+        } else {
+            cells = cells
+                .push(
+                    container(truncated_text(
+                        &format!("{}", miner.device_info.model).replace("Plus", "+"),
+                        WIDE_CELL_CHARS,
+                        theme::typography::mono,
+                    ))
+                    .width(Length::FillPortion(2)),
+                )
+                .push(
+                    container(truncated_text(
+                        &miner.device_info.make.to_string(),
+                        NARROW_CELL_CHARS,
+                        theme::typography::mono,
+                    ))
+                    .width(Length::FillPortion(1)),
+                )
+                .push(
+                    container(truncated_text(
+                        &miner.device_info.firmware.to_string(),
+                        NARROW_CELL_CHARS,
+                        theme::typography::mono,
+                    ))
+                    .width(Length::FillPortion(1)),
+                )
+                .push(
+                    container(truncated_text(
+                        miner.firmware_version.as_deref().unwrap_or("-"),
+                        NARROW_CELL_CHARS,
+                        theme::typography::mono,
+                    ))
+                    .width(Length::FillPortion(1)),
+                )
+                .push(
+                    container(truncated_text(
+                        &miner.device_info.algo.to_string(),
+                        NARROW_CELL_CHARS,
+                        theme::typography::mono,
+                    ))
+                    .width(Length::FillPortion(1)),
+                )
+                .push(
+                    container(
+                        column![
+                            theme::typography::mono(
+                                miner
+                                    .hashrate
+                                    .as_ref()
+                                    .map(format_hashrate)
+                                    .unwrap_or_else(|| "-".to_string())
+                            ),
+                            view_hashrate_gauge(miner),
+                        ]
+                        .spacing(2),
+                    )
+                    .width(Length::FillPortion(1)),
+                );
+        }
+
+        let miner_row = button(
+            cells
+                .spacing(theme::spacing::XS)
+                .align_y(iced::alignment::Vertical::Center),
+        )
+        .style(theme::buttons::table_row)
+        .padding(theme::padding::XS)
+        .on_press(on_select(miner_ip))
+        .width(Length::Fill);
+
+        miners_list = miners_list.push(miner_row);
+    }
+
+    column![table_header, miners_list]
+        .spacing(theme::spacing::XS)
+        .into()
+}
+
+/// True when the miner answered but make, model and firmware all came back
+/// empty or unknown, so there is nothing meaningful to show in those cells.
+pub fn is_unidentified(miner: &MinerData) -> bool {
+    [
+        miner.device_info.make.to_string(),
+        miner.device_info.model.to_string(),
+        miner.device_info.firmware.to_string(),
+    ]
+    .iter()
+    .all(|value| is_blank_identity(value))
+}
+
+fn is_blank_identity(value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty() || value.eq_ignore_ascii_case("unknown")
+}
+
+/// Health status dot with a tooltip summarizing the miner's issues. Scan
+/// results without performance data show whether the miner answered instead.
+fn view_health_dot<Message: 'static>(
+    miner: &MinerData,
+    temperature_unit: TempUnit,
+) -> Element<'static, Message> {
+    let report =
+        HealthReport::from_miner_data(miner, &HealthThresholds::default(), temperature_unit);
+    let (status, summary) = match report.status {
+        HealthStatus::Unknown => match HealthStatus::from_partial_miner_data(miner) {
+            HealthStatus::Online => (
+                HealthStatus::Online,
+                "Online; open the miner for a full health check".to_string(),
+            ),
+            status => (status, report.summary_text()),
+        },
+        status => (status, report.summary_text()),
+    };
+    tooltip(
+        theme::widgets::status_dot(status),
+        container(theme::typography::small(summary))
+            .style(theme::containers::tooltip_adaptive)
+            .padding(theme::padding::SM),
+        tooltip::Position::Right,
+    )
+    .into()
+}
+
+/// Thin bar of hashrate as a fraction of expected, colored by health thresholds.
+/// Blank when the miner doesn't report an expected hashrate.
+fn view_hashrate_gauge<Message: 'static>(miner: &MinerData) -> Element<'static, Message> {
+    match hashrate_ratio(miner, &HealthThresholds::default()) {
+        Some((ratio, status)) => theme::widgets::gauge_bar(ratio as f32, status.color()),
+        None => Space::new().height(theme::widgets::GAUGE_BAR_HEIGHT).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blank_identity() {
+        assert!(is_blank_identity(""));
+        assert!(is_blank_identity("  "));
+        assert!(is_blank_identity("Unknown"));
+        assert!(is_blank_identity("unknown "));
+        assert!(!is_blank_identity("AntMiner"));
+        assert!(!is_blank_identity("S19 Unknown Variant"));
+    }
+}
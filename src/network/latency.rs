@@ -0,0 +1,186 @@
+use super::{estimate_ip_count, key_to_ip, parse_segments, segment_span};
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+
+/// IPs sampled by "Profile Network"
+pub const LATENCY_SAMPLE_SIZE: usize = 10;
+
+/// Port probed on each sampled IP; every supported miner serves its web UI here
+const PROBE_PORT: u16 = 80;
+
+/// How long a sampled IP gets to answer before it counts as failed
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Bounds of the suggested probe timeout
+const MIN_SUGGESTED_TIMEOUT_SECS: u64 = 1;
+const MAX_SUGGESTED_TIMEOUT_SECS: u64 = 5;
+
+/// Response times measured on a sample of a range before scanning it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyReport {
+    pub sampled: usize,
+    /// Median response time of the IPs that answered, `None` if none did
+    pub p50_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+    /// Sampled IPs that didn't answer within `PROBE_TIMEOUT`
+    pub failed_count: usize,
+    range_size: usize,
+    /// Average time one probe of the range is expected to take
+    per_ip_ms: f64,
+}
+
+impl LatencyReport {
+    fn from_samples(range_size: usize, mut latencies_ms: Vec<u64>, failed_count: usize) -> Self {
+        latencies_ms.sort_unstable();
+        let sampled = latencies_ms.len() + failed_count;
+        let p50_ms = percentile(&latencies_ms, 50);
+        let p95_ms = percentile(&latencies_ms, 95);
+
+        // Unresponsive IPs cost a full timeout, responsive ones their median
+        let failed_share = if sampled == 0 {
+            0.0
+        } else {
+            failed_count as f64 / sampled as f64
+        };
+        let per_ip_ms = failed_share * PROBE_TIMEOUT.as_millis() as f64
+            + (1.0 - failed_share) * p50_ms.unwrap_or(0) as f64;
+
+        Self {
+            sampled,
+            p50_ms,
+            p95_ms,
+            failed_count,
+            range_size,
+            per_ip_ms,
+        }
+    }
+
+    /// Rough time to scan the whole range with `concurrency` probes in
+    /// flight, as set by the group's `ScanConfig::concurrency_limit`.
+    pub fn estimated_scan_minutes(&self, concurrency: usize) -> f64 {
+        self.range_size as f64 * self.per_ip_ms / concurrency.max(1) as f64 / 60_000.0
+    }
+
+    /// A `probe_timeout_secs` with headroom over the slowest typical answer,
+    /// rounded up to whole seconds, or `None` when no sampled IP answered.
+    pub fn suggested_probe_timeout_secs(&self) -> Option<u64> {
+        let p95 = self.p95_ms?;
+        Some(
+            (p95 * 3)
+                .div_ceil(1000)
+                .clamp(MIN_SUGGESTED_TIMEOUT_SECS, MAX_SUGGESTED_TIMEOUT_SECS),
+        )
+    }
+}
+
+/// Measures how quickly IPs in `range` answer by timing a TCP connect to up
+/// to `sample_size` addresses spread evenly across it. A refused connection
+/// counts as an answer, since the host is up.
+///
+/// An invalid range yields an empty report.
+pub async fn sample_latency(range: &str, sample_size: usize) -> LatencyReport {
+    let ips = sample_ips(range, sample_size);
+    let results = iced::futures::future::join_all(ips.into_iter().map(probe)).await;
+
+    let latencies_ms: Vec<u64> = results.iter().flatten().copied().collect();
+    let failed_count = results.len() - latencies_ms.len();
+    LatencyReport::from_samples(estimate_ip_count(range), latencies_ms, failed_count)
+}
+
+/// Connect time to `ip` in milliseconds, or `None` if it didn't answer.
+async fn probe(ip: IpAddr) -> Option<u64> {
+    let started = Instant::now();
+    let connect = TcpStream::connect(SocketAddr::new(ip, PROBE_PORT));
+    match tokio::time::timeout(PROBE_TIMEOUT, connect).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => {}
+        _ => return None,
+    }
+    Some(started.elapsed().as_millis() as u64)
+}
+
+/// Up to `count` distinct addresses spread evenly across every segment of
+/// `range`, or all of them if it has fewer.
+fn sample_ips(range: &str, count: usize) -> Vec<IpAddr> {
+    let Ok(spans) = parse_segments(range).and_then(|segments| {
+        segments
+            .iter()
+            .map(segment_span)
+            .collect::<Result<Vec<_>, _>>()
+    }) else {
+        return Vec::new();
+    };
+    let total: u128 = spans.iter().map(|(start, end)| end.1 - start.1 + 1).sum();
+    let count = count as u128;
+
+    let mut ips = Vec::new();
+    for i in 0..count.min(total) {
+        // Middle of the i-th of `count` equal slices
+        let mut offset = if total <= count {
+            i
+        } else {
+            total * (2 * i + 1) / (2 * count)
+        };
+        for (start, end) in &spans {
+            let size = end.1 - start.1 + 1;
+            if offset < size {
+                let ip = key_to_ip((start.0, start.1 + offset));
+                if !ips.contains(&ip) {
+                    ips.push(ip);
+                }
+                break;
+            }
+            offset -= size;
+        }
+    }
+    ips
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_ips() {
+        let ips = sample_ips("10.0.0.0/24", 4);
+        let last_octets: Vec<String> = ips
+            .iter()
+            .map(|ip| ip.to_string().rsplit('.').next().unwrap().to_string())
+            .collect();
+        assert_eq!(last_octets, ["32", "96", "159", "223"]);
+
+        // Small ranges are sampled in full, across segments
+        assert_eq!(sample_ips("10.0.0.1-3, 10.0.1.9", 10).len(), 4);
+        assert!(sample_ips("not a range", 10).is_empty());
+    }
+
+    #[test]
+    fn test_latency_report() {
+        let report = LatencyReport::from_samples(254, (1..=20).collect(), 5);
+        assert_eq!(report.sampled, 25);
+        assert_eq!(report.p50_ms, Some(10));
+        assert_eq!(report.p95_ms, Some(19));
+        assert_eq!(report.suggested_probe_timeout_secs(), Some(1));
+        assert!(report.estimated_scan_minutes(256) > 0.0);
+        // Fewer probes in flight take proportionally longer
+        let ratio = report.estimated_scan_minutes(20) / report.estimated_scan_minutes(200);
+        assert!((ratio - 10.0).abs() < 1e-9);
+
+        let slow = LatencyReport::from_samples(254, vec![900, 1200], 0);
+        assert_eq!(slow.suggested_probe_timeout_secs(), Some(4));
+
+        let silent = LatencyReport::from_samples(254, Vec::new(), 10);
+        assert_eq!(silent.p95_ms, None);
+        assert_eq!(silent.suggested_probe_timeout_secs(), None);
+    }
+}
@@ -1,43 +1,399 @@
 pub mod full_fetch;
+pub mod latency;
 pub mod scanner;
 
-use crate::errors::ScannerError;
+use crate::errors::{CidrError, NetworkRangeError, ScannerError};
 use asic_rs::miners::factory::MinerFactory;
+use iced::futures::{Stream, StreamExt, stream};
+use lru::LruCache;
 use scanner::ScanConfig;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, UdpSocket};
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Ranges whose IP counts are remembered; the main view asks for every
+/// group's count on each render
+const IP_COUNT_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(100).unwrap();
+
+/// IP counts keyed by the range exactly as typed
+static IP_COUNT_CACHE: OnceLock<Mutex<LruCache<String, usize>>> = OnceLock::new();
+
+fn ip_count_cache() -> &'static Mutex<LruCache<String, usize>> {
+    IP_COUNT_CACHE.get_or_init(|| Mutex::new(LruCache::new(IP_COUNT_CACHE_SIZE)))
+}
+
+/// Largest number of addresses a range may cover, a /8
+const MAX_RANGE_ADDRESSES: u128 = 1 << 24;
+
+/// Reverse DNS lookups in flight at once, so enrichment doesn't flood the resolver
+const REVERSE_DNS_CONCURRENCY: usize = 4;
+
+/// Longest a reverse lookup may take before the IP counts as having no name
+const REVERSE_DNS_TIMEOUT: Duration = Duration::from_secs(3);
 
 /// Validates and creates a MinerFactory from a network range string.
 ///
-/// Supports two formats:
+/// Supports three formats, which can be mixed as comma-separated segments:
 /// - CIDR notation: "192.168.1.0/24"
 /// - IP range: "192.168.1.1-100"
+/// - Single IP: "10.0.0.5"
+///
+/// Overlapping segments are deduplicated so each host is probed once. Lists
+/// of single IPs are handed to asic-rs as-is rather than as /32 subnets.
 ///
 /// # Errors
 ///
-/// Returns `ScannerError::NetworkRangeInvalid` if the format is invalid
+/// Returns `ScannerError::NetworkRangeInvalid` if any segment is invalid
 pub fn create_miner_factory(network_range: &str) -> Result<MinerFactory, ScannerError> {
-    // Validate non-empty input
-    if network_range.trim().is_empty() {
-        return Err(ScannerError::NetworkRangeInvalid(
-            "Network range cannot be empty".to_string(),
-        ));
+    let invalid = |e: NetworkRangeError| ScannerError::NetworkRangeInvalid(e.to_string());
+    validate_network_range(network_range).map_err(invalid)?;
+    let segments = parse_segments(network_range).map_err(invalid)?;
+
+    if let Some(ips) = single_ips(&segments) {
+        return Ok(MinerFactory::new().with_ips(ips));
     }
 
-    if network_range.contains('/') {
-        // CIDR notation
-        MinerFactory::new().with_subnet(network_range).map_err(|e| {
-            ScannerError::NetworkRangeInvalid(format!("Invalid CIDR '{network_range}': {e}"))
-        })
-    } else if network_range.contains('-') {
-        // IP range notation
-        MinerFactory::new().with_range(network_range).map_err(|e| {
-            ScannerError::NetworkRangeInvalid(format!("Invalid range '{network_range}': {e}"))
+    // A lone segment goes straight to asic-rs without expanding it first
+    if let [segment] = segments.as_slice() {
+        return segment_factory(segment);
+    }
+
+    // asic-rs builders append to the factory's host list, so feeding it the
+    // disjoint runs of the union yields every host exactly once
+    let hosts = expand_segments(&segments)?;
+    collapse_to_ranges(&hosts)
+        .iter()
+        .try_fold(MinerFactory::new(), add_segment)
+}
+
+/// How a network range string was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeFormat {
+    Cidr,
+    Range,
+    Single,
+    /// Several comma-separated single IPs
+    IpList,
+    /// Several comma-separated segments
+    List,
+}
+
+impl fmt::Display for RangeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeFormat::Cidr => write!(f, "CIDR"),
+            RangeFormat::Range => write!(f, "IP range"),
+            RangeFormat::Single => write!(f, "Single IP"),
+            RangeFormat::IpList => write!(f, "IP list"),
+            RangeFormat::List => write!(f, "Multiple ranges"),
+        }
+    }
+}
+
+/// What a valid network range covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkRangeInfo {
+    pub format: RangeFormat,
+    /// Distinct hosts across all segments
+    pub ip_count: usize,
+    pub first_ip: IpAddr,
+    pub last_ip: IpAddr,
+}
+
+/// Checks a network range without building a factory, describing exactly
+/// what is wrong with it or which hosts it covers.
+///
+/// Accepts the same formats as `create_miner_factory`. CIDR blocks exclude
+/// their IPv4 network and broadcast addresses, like the scanner does.
+pub fn validate_network_range(range: &str) -> Result<NetworkRangeInfo, NetworkRangeError> {
+    let segments = parse_segments(range)?;
+    let merged = merged_spans(&segments)?;
+
+    let ip_count: u128 = merged.iter().map(|(start, end)| end.1 - start.1 + 1).sum();
+    if ip_count > MAX_RANGE_ADDRESSES {
+        return Err(NetworkRangeError::TooLarge {
+            input: range.trim().to_string(),
+            ip_count,
+        });
+    }
+
+    let format = match segments.as_slice() {
+        [RangeSegment::Cidr(_)] => RangeFormat::Cidr,
+        [RangeSegment::Range(_)] => RangeFormat::Range,
+        [RangeSegment::Single(_)] => RangeFormat::Single,
+        _ if single_ips(&segments).is_some() => RangeFormat::IpList,
+        _ => RangeFormat::List,
+    };
+
+    Ok(NetworkRangeInfo {
+        format,
+        ip_count: ip_count as usize,
+        first_ip: key_to_ip(merged[0].0),
+        last_ip: key_to_ip(merged[merged.len() - 1].1),
+    })
+}
+
+/// First and last address of each run of IPv4 hosts in a valid range, in
+/// order. Lets callers see a large range's shape without expanding it.
+pub fn ipv4_spans(range: &str) -> Result<Vec<(Ipv4Addr, Ipv4Addr)>, NetworkRangeError> {
+    validate_network_range(range)?;
+    let segments = parse_segments(range)?;
+    Ok(merged_spans(&segments)?
+        .into_iter()
+        .filter_map(|(start, end)| match (key_to_ip(start), key_to_ip(end)) {
+            (IpAddr::V4(start), IpAddr::V4(end)) => Some((start, end)),
+            _ => None,
         })
+        .collect())
+}
+
+/// Every segment's span, sorted, with overlapping and adjacent spans merged
+/// so shared hosts count once.
+fn merged_spans(segments: &[RangeSegment]) -> Result<Vec<(HostKey, HostKey)>, NetworkRangeError> {
+    let mut spans = segments
+        .iter()
+        .map(segment_span)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    spans.sort_unstable();
+    let mut merged: Vec<(HostKey, HostKey)> = Vec::new();
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some((_, last_end))
+                if start.0 == last_end.0 && start.1 <= last_end.1.saturating_add(1) =>
+            {
+                *last_end = (*last_end).max(end);
+            }
+            _ => merged.push((start, end)),
+        }
+    }
+    Ok(merged)
+}
+
+/// An address as (is IPv6, numeric value), ordering IPv4 before IPv6.
+type HostKey = (bool, u128);
+
+fn ip_to_key(ip: IpAddr) -> HostKey {
+    match ip {
+        IpAddr::V4(v4) => (false, u128::from(u32::from(v4))),
+        IpAddr::V6(v6) => (true, u128::from(v6)),
+    }
+}
+
+fn key_to_ip((is_v6, value): HostKey) -> IpAddr {
+    if is_v6 {
+        IpAddr::V6(Ipv6Addr::from(value))
     } else {
-        Err(ScannerError::NetworkRangeInvalid(format!(
-            "Invalid format '{}'. Use CIDR (192.168.1.0/24) or range (192.168.1.1-100)",
-            network_range
-        )))
+        IpAddr::V4(Ipv4Addr::from(value as u32))
+    }
+}
+
+/// First and last host of one segment, rejecting segments over a /8.
+fn segment_span(segment: &RangeSegment) -> Result<(HostKey, HostKey), NetworkRangeError> {
+    let (input, start, end) = match segment {
+        RangeSegment::Single(ip) => return Ok((ip_to_key(*ip), ip_to_key(*ip))),
+        RangeSegment::Cidr(cidr) => {
+            let (start, end) = cidr_span(cidr)?;
+            (cidr, start, end)
+        }
+        RangeSegment::Range(range) => {
+            let (start, end) = octet_range_span(range)?;
+            (range, start, end)
+        }
+    };
+
+    let ip_count = end.1 - start.1 + 1;
+    if ip_count > MAX_RANGE_ADDRESSES {
+        return Err(NetworkRangeError::TooLarge {
+            input: input.clone(),
+            ip_count,
+        });
     }
+    Ok((start, end))
+}
+
+fn cidr_span(cidr: &str) -> Result<(HostKey, HostKey), NetworkRangeError> {
+    let invalid = |reason| NetworkRangeError::InvalidCidr {
+        input: cidr.to_string(),
+        reason,
+    };
+    let (address, prefix) = cidr.split_once('/').unwrap_or((cidr, ""));
+    let address: IpAddr = address
+        .trim()
+        .parse()
+        .map_err(|_| invalid(CidrError::InvalidAddress(address.trim().to_string())))?;
+    let prefix: u8 = prefix
+        .trim()
+        .parse()
+        .map_err(|_| invalid(CidrError::InvalidPrefix(prefix.trim().to_string())))?;
+    let max = if address.is_ipv4() { 32 } else { 128 };
+    if prefix > max {
+        return Err(invalid(CidrError::PrefixTooLong { prefix, max }));
+    }
+
+    // Host bits in the address are ignored, as "192.168.1.5/24" means the whole /24
+    let (is_v6, value) = ip_to_key(address);
+    let host_bits = u32::from(max - prefix);
+    let size = 1u128.checked_shl(host_bits).unwrap_or(u128::MAX);
+    let network = value & !(size.wrapping_sub(1));
+    let broadcast = network | size.wrapping_sub(1);
+    let (first, last) = if !is_v6 && host_bits >= 2 {
+        (network + 1, broadcast - 1)
+    } else {
+        (network, broadcast)
+    };
+    Ok(((is_v6, first), (is_v6, last)))
+}
+
+/// Span of a last-octet range such as "192.168.1.1-100".
+fn octet_range_span(range: &str) -> Result<(HostKey, HostKey), NetworkRangeError> {
+    let (start, end) = range.split_once('-').unwrap_or((range, ""));
+    let (start, end) = (start.trim(), end.trim());
+    let start: Ipv4Addr = start
+        .parse()
+        .map_err(|_| NetworkRangeError::InvalidIp(start.to_string()))?;
+    let [a, b, c, first] = start.octets();
+    let last: u8 = end
+        .parse()
+        .map_err(|_| NetworkRangeError::InvalidIp(format!("{a}.{b}.{c}.{end}")))?;
+    if first > last {
+        return Err(NetworkRangeError::StartAfterEnd {
+            input: range.to_string(),
+        });
+    }
+
+    let start = ip_to_key(IpAddr::V4(start));
+    Ok((start, (false, start.1 + u128::from(last - first))))
+}
+
+/// One comma-separated piece of a network range string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RangeSegment {
+    Cidr(String),
+    Range(String),
+    Single(IpAddr),
+}
+
+/// Splits a network range on commas and classifies each segment.
+fn parse_segments(network_range: &str) -> Result<Vec<RangeSegment>, NetworkRangeError> {
+    let segments: Vec<&str> = network_range
+        .split(',')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    if segments.is_empty() {
+        return Err(NetworkRangeError::Empty);
+    }
+
+    segments
+        .into_iter()
+        .map(|segment| {
+            if segment.contains('/') {
+                Ok(RangeSegment::Cidr(segment.to_string()))
+            } else if segment.contains('-') {
+                Ok(RangeSegment::Range(segment.to_string()))
+            } else {
+                segment
+                    .parse()
+                    .map(RangeSegment::Single)
+                    .map_err(|_| NetworkRangeError::InvalidIp(segment.to_string()))
+            }
+        })
+        .collect()
+}
+
+/// The distinct addresses, in input order, when every segment is a single IP.
+fn single_ips(segments: &[RangeSegment]) -> Option<Vec<IpAddr>> {
+    let mut ips: Vec<IpAddr> = Vec::with_capacity(segments.len());
+    for segment in segments {
+        let RangeSegment::Single(ip) = segment else {
+            return None;
+        };
+        if !ips.contains(ip) {
+            ips.push(*ip);
+        }
+    }
+    Some(ips)
+}
+
+fn segment_factory(segment: &RangeSegment) -> Result<MinerFactory, ScannerError> {
+    add_segment(MinerFactory::new(), segment)
+}
+
+fn add_segment(
+    factory: MinerFactory,
+    segment: &RangeSegment,
+) -> Result<MinerFactory, ScannerError> {
+    match segment {
+        RangeSegment::Cidr(cidr) => factory
+            .with_subnet(cidr)
+            .map_err(|e| ScannerError::NetworkRangeInvalid(format!("Invalid CIDR '{cidr}': {e}"))),
+        RangeSegment::Range(range) => factory.with_range(range).map_err(|e| {
+            ScannerError::NetworkRangeInvalid(format!("Invalid range '{range}': {e}"))
+        }),
+        RangeSegment::Single(ip) => {
+            let prefix = if ip.is_ipv4() { 32 } else { 128 };
+            factory
+                .with_subnet(&format!("{ip}/{prefix}"))
+                .map_err(|e| ScannerError::NetworkRangeInvalid(format!("Invalid IP '{ip}': {e}")))
+        }
+    }
+}
+
+/// Union of every segment's hosts, sorted and without duplicates.
+fn expand_segments(segments: &[RangeSegment]) -> Result<BTreeSet<IpAddr>, ScannerError> {
+    let mut hosts = BTreeSet::new();
+    for segment in segments {
+        match segment {
+            RangeSegment::Single(ip) => {
+                hosts.insert(*ip);
+            }
+            _ => hosts.extend(segment_factory(segment)?.hosts()),
+        }
+    }
+    Ok(hosts)
+}
+
+/// Every host `network_range` covers, for tests outside this module.
+#[cfg(test)]
+pub(crate) fn expand_network_range(network_range: &str) -> Result<BTreeSet<IpAddr>, ScannerError> {
+    let segments = parse_segments(network_range)
+        .map_err(|e| ScannerError::NetworkRangeInvalid(e.to_string()))?;
+    expand_segments(&segments)
+}
+
+/// Collapses sorted hosts into the fewest segments: last-octet ranges such as
+/// "10.0.0.1-50" for runs of consecutive IPv4 addresses within a /24, and
+/// single IPs for everything else.
+fn collapse_to_ranges(hosts: &BTreeSet<IpAddr>) -> Vec<RangeSegment> {
+    let mut runs: Vec<(IpAddr, u8)> = Vec::new();
+    for &ip in hosts {
+        if let IpAddr::V4(v4) = ip
+            && let Some((IpAddr::V4(start), end)) = runs.last_mut()
+            && start.octets()[..3] == v4.octets()[..3]
+            && u16::from(*end) + 1 == u16::from(v4.octets()[3])
+        {
+            *end = v4.octets()[3];
+            continue;
+        }
+        let last_octet = match ip {
+            IpAddr::V4(v4) => v4.octets()[3],
+            IpAddr::V6(_) => 0,
+        };
+        runs.push((ip, last_octet));
+    }
+
+    runs.into_iter()
+        .map(|(start, end)| match start {
+            IpAddr::V4(v4) if v4.octets()[3] != end => {
+                RangeSegment::Range(format!("{start}-{end}"))
+            }
+            _ => RangeSegment::Single(start),
+        })
+        .collect()
 }
 
 /// Creates a MinerFactory with search filters applied.
@@ -59,12 +415,274 @@ pub fn create_configured_miner_factory(
         factory = factory.with_search_firmwares(firmwares.clone());
     }
 
+    // The concurrency limit is applied by the scanner, which polls the
+    // factory's lazy probe stream no more than `concurrency_limit()` IPs at
+    // a time
+
     Ok(factory)
 }
 
+/// Number of distinct hosts in a network range, or 0 if it is invalid.
+///
+/// Counts are cached, so repeated calls for the same range skip parsing it.
 pub fn estimate_ip_count(network_range: &str) -> usize {
-    match create_miner_factory(network_range) {
-        Ok(factory) => factory.hosts().len(),
-        Err(_) => 0,
+    if let Ok(mut cache) = ip_count_cache().lock()
+        && let Some(&count) = cache.get(network_range)
+    {
+        return count;
+    }
+
+    let count = validate_network_range(network_range)
+        .map(|info| info.ip_count)
+        .unwrap_or(0);
+    if let Ok(mut cache) = ip_count_cache().lock() {
+        cache.put(network_range.to_string(), count);
+    }
+    count
+}
+
+/// Forgets every cached IP count, so a test starts from an empty cache.
+#[cfg(test)]
+pub fn clear_ip_count_cache() {
+    if let Ok(mut cache) = ip_count_cache().lock() {
+        cache.clear();
+    }
+}
+
+/// Guesses the local /24 from the address the OS would use for outbound traffic.
+///
+/// Connecting a UDP socket sends no packets; it only selects a route, so this
+/// works without internet access as long as a default route exists.
+pub fn detect_local_subnet() -> Option<String> {
+    route_source_subnet("8.8.8.8:80")
+}
+
+/// Guesses every local /24 this computer can reach directly.
+///
+/// Asks the OS which interface it would route to the default gateway and to
+/// each private range; machines with several NICs or VLAN interfaces answer
+/// with a different source address per network. The default route comes first.
+pub fn detect_local_subnets() -> Vec<String> {
+    const ROUTE_PROBES: [&str; 4] = [
+        "8.8.8.8:80",
+        "10.255.255.254:80",
+        "172.31.255.254:80",
+        "192.168.255.254:80",
+    ];
+
+    let mut subnets: Vec<String> = Vec::new();
+    for subnet in ROUTE_PROBES.into_iter().filter_map(route_source_subnet) {
+        if !subnets.contains(&subnet) {
+            subnets.push(subnet);
+        }
+    }
+    subnets
+}
+
+/// The /24 of the local address the OS would send from to reach `target`.
+fn route_source_subnet(target: &str) -> Option<String> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect(target).ok()?;
+    match socket.local_addr().ok()?.ip() {
+        IpAddr::V4(ip) if !ip.is_loopback() && !ip.is_unspecified() => Some(subnet_24(ip)),
+        _ => None,
+    }
+}
+
+/// PTR hostname of `ip`, or `None` when it has no record (NXDOMAIN) or the
+/// lookup fails or times out.
+pub async fn reverse_lookup(ip: IpAddr) -> Option<String> {
+    let lookup = tokio::task::spawn_blocking(move || dns_lookup::lookup_addr(&ip));
+    let name = tokio::time::timeout(REVERSE_DNS_TIMEOUT, lookup)
+        .await
+        .ok()?
+        .ok()?
+        .ok()?;
+    ptr_name(ip, &name)
+}
+
+/// Reverse-resolves `ips` a few at a time, yielding each name as it resolves.
+pub fn reverse_lookup_all(ips: Vec<IpAddr>) -> impl Stream<Item = (IpAddr, Option<String>)> {
+    stream::iter(ips)
+        .map(|ip| async move { (ip, reverse_lookup(ip).await) })
+        .buffer_unordered(REVERSE_DNS_CONCURRENCY)
+}
+
+/// A resolved PTR name without its trailing dot; resolvers that echo the
+/// address back instead of failing count as no name.
+fn ptr_name(ip: IpAddr, name: &str) -> Option<String> {
+    let name = name.trim().trim_end_matches('.');
+    (!name.is_empty() && name.parse::<IpAddr>().ok() != Some(ip)).then(|| name.to_string())
+}
+
+/// The /24 CIDR containing `ip`, e.g. "192.168.1.0/24".
+fn subnet_24(ip: Ipv4Addr) -> String {
+    let [a, b, c, _] = ip.octets();
+    format!("{a}.{b}.{c}.0/24")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ptr_name() {
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5));
+        assert_eq!(
+            ptr_name(ip, "s19-rack2.farm.lan."),
+            Some("s19-rack2.farm.lan".to_string())
+        );
+        assert_eq!(ptr_name(ip, "10.0.0.5"), None);
+        assert_eq!(ptr_name(ip, ""), None);
+    }
+
+    #[test]
+    fn test_subnet_24() {
+        assert_eq!(subnet_24(Ipv4Addr::new(192, 168, 1, 57)), "192.168.1.0/24");
+        assert_eq!(subnet_24(Ipv4Addr::new(10, 20, 30, 0)), "10.20.30.0/24");
+    }
+
+    #[test]
+    fn test_parse_mixed_segments() {
+        let segments = parse_segments("192.168.1.0/24, 10.0.0.1-50,10.0.0.7 ,").unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                RangeSegment::Cidr("192.168.1.0/24".to_string()),
+                RangeSegment::Range("10.0.0.1-50".to_string()),
+                RangeSegment::Single("10.0.0.7".parse().unwrap()),
+            ]
+        );
+
+        assert_eq!(parse_segments(" , "), Err(NetworkRangeError::Empty));
+        assert_eq!(
+            parse_segments("192.168.1.0/24, farm"),
+            Err(NetworkRangeError::InvalidIp("farm".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_validate_network_range() {
+        let info = validate_network_range("192.168.1.0/24").unwrap();
+        assert_eq!(info.format, RangeFormat::Cidr);
+        assert_eq!(info.ip_count, 254);
+        assert_eq!(info.first_ip, "192.168.1.1".parse::<IpAddr>().unwrap());
+        assert_eq!(info.last_ip, "192.168.1.254".parse::<IpAddr>().unwrap());
+
+        let info = validate_network_range("10.0.0.1-50, 10.0.0.40-60, 10.0.0.100").unwrap();
+        assert_eq!(info.format, RangeFormat::List);
+        assert_eq!(info.ip_count, 61);
+        assert_eq!(info.last_ip, "10.0.0.100".parse::<IpAddr>().unwrap());
+
+        assert_eq!(validate_network_range("10.0.0.7/32").unwrap().ip_count, 1);
+        assert_eq!(
+            validate_network_range("10.0.0.0/8").unwrap().ip_count,
+            (1 << 24) - 2
+        );
+    }
+
+    #[test]
+    fn test_network_range_errors() {
+        let cidr_error = |range: &str| match validate_network_range(range) {
+            Err(NetworkRangeError::InvalidCidr { reason, .. }) => reason,
+            other => panic!("expected a CIDR error for {range}, got {other:?}"),
+        };
+        assert_eq!(
+            cidr_error("192.168.1/24"),
+            CidrError::InvalidAddress("192.168.1".to_string())
+        );
+        assert_eq!(
+            cidr_error("192.168.1.0/"),
+            CidrError::InvalidPrefix(String::new())
+        );
+        assert_eq!(
+            cidr_error("192.168.1.0/33"),
+            CidrError::PrefixTooLong {
+                prefix: 33,
+                max: 32
+            }
+        );
+
+        assert_eq!(validate_network_range(""), Err(NetworkRangeError::Empty));
+        assert_eq!(
+            validate_network_range("10.0.0.1-300"),
+            Err(NetworkRangeError::InvalidIp("10.0.0.300".to_string()))
+        );
+        assert_eq!(
+            validate_network_range("10.0.0.50-10"),
+            Err(NetworkRangeError::StartAfterEnd {
+                input: "10.0.0.50-10".to_string()
+            })
+        );
+        assert!(matches!(
+            validate_network_range("10.0.0.0/7"),
+            Err(NetworkRangeError::TooLarge { .. })
+        ));
+        assert!(matches!(
+            validate_network_range("10.0.0.0/9, 11.0.0.0/9, 12.0.0.0/9"),
+            Err(NetworkRangeError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_single_ips_are_deduplicated() {
+        let segments = parse_segments("10.0.0.7, 10.0.0.7, 10.0.0.8").unwrap();
+        assert_eq!(expand_segments(&segments).unwrap().len(), 2);
+        assert_eq!(
+            single_ips(&segments),
+            Some(vec![
+                "10.0.0.7".parse().unwrap(),
+                "10.0.0.8".parse().unwrap()
+            ])
+        );
+
+        let mixed = parse_segments("10.0.0.7, 10.0.0.10-12").unwrap();
+        assert_eq!(single_ips(&mixed), None);
+    }
+
+    #[test]
+    fn test_estimate_single_ips() {
+        assert_eq!(estimate_ip_count("192.168.1.1"), 1);
+        assert_eq!(estimate_ip_count("192.168.1.1,192.168.1.5"), 2);
+        assert_eq!(estimate_ip_count("192.168.1.1, 192.168.1.1"), 1);
+        assert_eq!(
+            validate_network_range("192.168.1.1,192.168.1.5")
+                .unwrap()
+                .format,
+            RangeFormat::IpList
+        );
+        assert_eq!(estimate_ip_count("192.168.1.1,farm"), 0);
+    }
+
+    #[test]
+    fn test_ip_count_cache() {
+        clear_ip_count_cache();
+        let range = "10.200.0.0/24";
+        assert_eq!(estimate_ip_count(range), 254);
+        assert_eq!(
+            ip_count_cache().lock().unwrap().peek(range).copied(),
+            Some(254)
+        );
+        // Hits return the same count
+        assert_eq!(estimate_ip_count(range), 254);
+
+        clear_ip_count_cache();
+        assert_eq!(ip_count_cache().lock().unwrap().peek(range), None);
+    }
+
+    #[test]
+    fn test_collapse_to_ranges() {
+        let segments =
+            parse_segments("10.0.0.3, 10.0.0.1, 10.0.0.2, 10.0.0.7, 10.0.1.4, 10.0.0.2").unwrap();
+        let hosts = expand_segments(&segments).unwrap();
+
+        assert_eq!(
+            collapse_to_ranges(&hosts),
+            vec![
+                RangeSegment::Range("10.0.0.1-3".to_string()),
+                RangeSegment::Single("10.0.0.7".parse().unwrap()),
+                RangeSegment::Single("10.0.1.4".parse().unwrap()),
+            ]
+        );
     }
 }
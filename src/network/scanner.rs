@@ -1,4 +1,7 @@
-use std::sync::{Arc, atomic::AtomicUsize};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+};
 use std::time::{Duration, Instant};
 
 use crate::errors::{ScannerError, ScannerResult};
@@ -10,15 +13,44 @@ use asic_rs::{
     miners::{backends::traits::GetMinerData, data::DataField},
 };
 use iced::{
-    futures::{SinkExt, StreamExt, future},
+    futures::{
+        FutureExt, SinkExt, StreamExt,
+        future::{self, BoxFuture},
+        stream::BoxStream,
+    },
     stream,
 };
+use std::net::IpAddr;
 // Tokio runtime is now shared via iced's tokio feature flag
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct ScanConfig {
     pub search_makes: Option<Vec<MinerMake>>,
     pub search_firmwares: Option<Vec<MinerFirmware>>,
+    /// Abort the group's scan after this many unresponsive IPs if no miner has
+    /// been found yet, which usually means a wrong range or a blocking firewall
+    #[serde(default)]
+    pub abort_after_failures: Option<usize>,
+    /// Most IPs probed at once; `None` uses `DEFAULT_MAX_CONCURRENCY`
+    #[serde(default, alias = "max_concurrent")]
+    pub max_concurrency: Option<usize>,
+    /// Seconds to wait for a miner's data before skipping it; `None` waits
+    /// as long as asic-rs does
+    #[serde(default)]
+    pub probe_timeout_secs: Option<u64>,
+}
+
+/// Probes in flight per group when the group sets no limit. Probing a
+/// large range all at once can exhaust the process's file descriptors.
+pub const DEFAULT_MAX_CONCURRENCY: usize = 256;
+
+impl ScanConfig {
+    /// The group's concurrent probe limit, never zero
+    pub fn concurrency_limit(&self) -> usize {
+        self.max_concurrency
+            .unwrap_or(DEFAULT_MAX_CONCURRENCY)
+            .max(1)
+    }
 }
 
 impl std::hash::Hash for ScanConfig {
@@ -59,6 +91,54 @@ const fn calculate_buffer_size(estimated_ips: usize) -> usize {
     }
 }
 
+/// Returns true once `failures` unresponsive IPs have been seen without a single
+/// discovery. Any discovery disarms the breaker for the rest of the scan.
+const fn should_abort_scan(failures: usize, discovered: usize, threshold: Option<usize>) -> bool {
+    match threshold {
+        Some(threshold) => discovered == 0 && failures >= threshold,
+        None => false,
+    }
+}
+
+/// Average scan throughput since the group's scan started.
+fn ips_per_second(scanned_count: usize, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs > 0.0 {
+        scanned_count as f64 / secs
+    } else {
+        0.0
+    }
+}
+
+/// Discoveries are held back for up to this long so a burst of miners
+/// answering at once reaches the UI as a few batches instead of a flood
+const DISCOVERY_BATCH_WINDOW: Duration = Duration::from_millis(50);
+
+/// A batch is sent early once it holds this many miners
+const DISCOVERY_BATCH_MAX: usize = 100;
+
+/// Empties `pending` into a message, or `None` if nothing is waiting.
+/// A lone discovery is sent as `MinerDiscovered`.
+fn take_discovery_batch(group_name: &str, pending: &mut Vec<MinerData>) -> Option<ScannerMessage> {
+    let mut miners = std::mem::take(pending);
+    let group_name = group_name.to_owned();
+    match miners.len() {
+        0 => None,
+        1 => miners
+            .pop()
+            .map(|miner| ScannerMessage::MinerDiscovered { group_name, miner }),
+        _ => Some(ScannerMessage::MinersDiscovered { group_name, miners }),
+    }
+}
+
+fn progress_message(progress: ThrottledProgress) -> ScannerMessage {
+    ScannerMessage::IpScanned {
+        group_name: progress.group_name,
+        total_ips: progress.total_ips,
+        scanned_count: progress.scanned_count,
+    }
+}
+
 async fn get_partial_data(miner: Box<dyn GetMinerData>) -> MinerData {
     let mut collector = miner.get_collector();
     let data = collector
@@ -68,29 +148,135 @@ async fn get_partial_data(miner: Box<dyn GetMinerData>) -> MinerData {
     miner.parse_data(data)
 }
 
+/// A probed IP and, if a miner answered, the future fetching its partial data
+pub type ProbeResult = (IpAddr, Option<BoxFuture<'static, MinerData>>);
+
+/// Hosts and probe results for one group's scan.
+///
+/// The real source wraps asic-rs's `MinerFactory`; tests substitute scripted
+/// results so the scan message contract can be checked without a network.
+pub trait MinerSource: Send {
+    /// Number of IPs the scan will probe
+    fn host_count(&self) -> usize;
+
+    /// Probes every host, yielding results as they complete.
+    fn scan(self: Box<Self>) -> BoxStream<'static, ProbeResult>;
+}
+
+/// Builds the source for a group's network range and filters
+pub type SourceFactory = fn(&str, &ScanConfig) -> ScannerResult<Box<dyn MinerSource>>;
+
+struct FactorySource(asic_rs::MinerFactory);
+
+impl MinerSource for FactorySource {
+    fn host_count(&self) -> usize {
+        self.0.hosts().len()
+    }
+
+    fn scan(self: Box<Self>) -> BoxStream<'static, ProbeResult> {
+        self.0
+            .scan_stream_with_ip()
+            .map(|(ip, miner)| (ip, miner.map(|miner| get_partial_data(miner).boxed())))
+            .boxed()
+    }
+}
+
+fn create_factory_source(
+    network_range: &str,
+    config: &ScanConfig,
+) -> ScannerResult<Box<dyn MinerSource>> {
+    let factory = super::create_configured_miner_factory(network_range, config)?;
+    Ok(Box::new(FactorySource(factory)))
+}
+
+/// Messages emitted while scanning.
+///
+/// Groups scan concurrently, so messages from different groups interleave
+/// arbitrarily. Within a group, messages arrive in the order they were sent,
+/// progress ends with the group's final count, and `GroupScanCompleted` is
+/// always the group's last message. `AllScansCompleted` comes last of all.
 #[derive(Debug, Clone)]
 pub enum ScannerMessage {
     MinerDiscovered {
         group_name: String,
         miner: MinerData,
     },
+    /// Several discoveries from the same group, coalesced over a short window
+    MinersDiscovered {
+        group_name: String,
+        miners: Vec<MinerData>,
+    },
     IpScanned {
         group_name: String,
         total_ips: usize,
         scanned_count: usize,
     },
+    /// Emitted once per second while a group scans; unlike `IpScanned` it is
+    /// not throttled, so it reflects every IP probed so far
+    ScanSpeed {
+        group_name: String,
+        ips_per_second: f64,
+    },
     GroupScanCompleted {
         group_name: String,
-        result: Result<(), String>,
+        result: Result<(), ScannerError>,
     },
     AllScansCompleted,
 }
 
+/// Stops one group's scan without touching the rest of the scan.
+///
+/// Clones share the flag. It takes no part in equality or hashing, so
+/// tripping it leaves the subscription a group belongs to unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<CancelState>);
+
+#[derive(Debug, Default)]
+struct CancelState {
+    cancelled: AtomicBool,
+    notify: tokio::sync::Notify,
+}
+
+impl CancelFlag {
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        self.0.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once `cancel` has been called.
+    async fn cancelled(&self) {
+        // Created before the check so a cancel in between still wakes it
+        let notified = self.0.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+}
+
+impl PartialEq for CancelFlag {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CancelFlag {}
+
+impl std::hash::Hash for CancelFlag {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ScanGroup {
     pub name: String,
     pub network_range: String,
     pub config: ScanConfig,
+    /// Tripped by "Stop" on the group's header
+    pub cancel: CancelFlag,
 }
 
 impl ScanGroup {
@@ -103,10 +289,20 @@ impl ScanGroup {
             name: name.into(),
             network_range: network_range.into(),
             config,
+            cancel: CancelFlag::default(),
         }
     }
 }
 
+/// Aborts a spawned task when dropped
+struct AbortOnDrop(tokio::task::AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
 pub struct Scanner;
 
 impl Scanner {
@@ -114,11 +310,15 @@ impl Scanner {
         iced::Subscription::run_with(groups, Self::scan_multiple_groups_stream)
     }
 
-    fn scan_multiple_groups_stream(
-        groups: &Vec<ScanGroup>,
-    ) -> iced::futures::stream::BoxStream<'static, ScannerMessage> {
-        use iced::futures::StreamExt;
-        let groups = groups.clone();
+    fn scan_multiple_groups_stream(groups: &Vec<ScanGroup>) -> BoxStream<'static, ScannerMessage> {
+        Self::scan_groups_with(groups.clone(), create_factory_source)
+    }
+
+    /// Scans every group concurrently, probing through sources from `create_source`.
+    fn scan_groups_with(
+        groups: Vec<ScanGroup>,
+        create_source: SourceFactory,
+    ) -> BoxStream<'static, ScannerMessage> {
         let total_estimated_ips: usize = groups
             .iter()
             .map(|group| super::estimate_ip_count(&group.network_range))
@@ -128,7 +328,7 @@ impl Scanner {
 
         stream::channel(
             buffer_size,
-            |mut output: iced::futures::channel::mpsc::Sender<ScannerMessage>| async move {
+            move |mut output: iced::futures::channel::mpsc::Sender<ScannerMessage>| async move {
                 use future::join_all;
 
                 let total_groups = groups.len();
@@ -149,9 +349,10 @@ impl Scanner {
                             &group.config,
                             &mut output_clone,
                             &group.name,
+                            &group.cancel,
+                            create_source,
                         )
-                        .await
-                        .map_err(|e| e.to_string());
+                        .await;
 
                         let _ = output_clone
                             .send(ScannerMessage::GroupScanCompleted { group_name, result })
@@ -174,6 +375,8 @@ impl Scanner {
         config: &ScanConfig,
         output: &mut iced::futures::channel::mpsc::Sender<ScannerMessage>,
         group_name: &str,
+        cancel: &CancelFlag,
+        create_source: SourceFactory,
     ) -> ScannerResult<()> {
         let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<MinerData>();
         let (progress_tx, mut progress_rx) =
@@ -184,6 +387,7 @@ impl Scanner {
         let config = config.clone();
         let group_name = group_name.to_string();
         let group_name_for_task = group_name.clone();
+        let cancel_for_task = cancel.clone();
 
         // Spawn scan task on shared tokio runtime
         // This runs concurrently without blocking the UI thread
@@ -194,62 +398,126 @@ impl Scanner {
                 tx,
                 progress_tx,
                 group_name_for_task,
+                &cancel_for_task,
+                create_source,
             )
             .await
         });
+        // Dropping the subscription drops this future; the probes go with it
+        let _abort_on_drop = AbortOnDrop(scan_handle.abort_handle());
 
         let mut last_progress_time = Instant::now();
         const PROGRESS_THROTTLE_MS: u64 = 100; // Throttle to every 100ms
 
+        const SPEED_INTERVAL: Duration = Duration::from_secs(1);
+        let scan_start = Instant::now();
+        let mut latest_scanned_count = 0;
+        // Latest progress held back by the throttle, flushed before completion
+        let mut unsent_progress: Option<ThrottledProgress> = None;
+        let mut speed_ticker =
+            tokio::time::interval_at(tokio::time::Instant::now() + SPEED_INTERVAL, SPEED_INTERVAL);
+        // Discoveries waiting for the next batch flush
+        let mut pending_miners: Vec<MinerData> = Vec::new();
+        let mut batch_ticker = tokio::time::interval_at(
+            tokio::time::Instant::now() + DISCOVERY_BATCH_WINDOW,
+            DISCOVERY_BATCH_WINDOW,
+        );
+
         loop {
             tokio::select! {
                 miner_opt = rx.recv() => {
-                    match miner_opt {
-                        Some(miner) => {
-                            if output
-                                .send(ScannerMessage::MinerDiscovered {
-                                    group_name: group_name.to_owned(),
-                                    miner,
-                                })
-                                .await
-                                .is_err()
-                            {
-                                return Err(ScannerError::ChannelClosed);
-                            }
+                    if let Some(miner) = miner_opt {
+                        pending_miners.push(miner);
+                        if pending_miners.len() >= DISCOVERY_BATCH_MAX
+                            && let Some(batch) = take_discovery_batch(&group_name, &mut pending_miners)
+                            && output.send(batch).await.is_err()
+                        {
+                            return Err(ScannerError::ChannelClosed);
                         }
-                        None => {}
+                    }
+                }
+
+                _ = batch_ticker.tick() => {
+                    if let Some(batch) = take_discovery_batch(&group_name, &mut pending_miners)
+                        && output.send(batch).await.is_err()
+                    {
+                        return Err(ScannerError::ChannelClosed);
                     }
                 }
 
                 progress_opt = progress_rx.recv() => {
                     match progress_opt {
                         Some(throttled_progress) => {
+                            latest_scanned_count = throttled_progress.scanned_count;
                             let now = Instant::now();
                             // Throttle progress updates to avoid UI flooding
                             if now.duration_since(last_progress_time) >= Duration::from_millis(PROGRESS_THROTTLE_MS) {
-                                let progress_msg = ScannerMessage::IpScanned {
-                                    group_name: throttled_progress.group_name,
-                                    total_ips: throttled_progress.total_ips,
-                                    scanned_count: throttled_progress.scanned_count,
-                                };
-
-                                if output.send(progress_msg).await.is_err() {
+                                unsent_progress = None;
+                                if output.send(progress_message(throttled_progress)).await.is_err() {
                                     return Err(ScannerError::ChannelClosed);
                                 }
                                 last_progress_time = now;
+                            } else {
+                                unsent_progress = Some(throttled_progress);
                             }
                         }
                         None => {
+                            // The scan task has finished; deliver discoveries
+                            // still queued before the progress channel closed
+                            while let Ok(miner) = rx.try_recv() {
+                                pending_miners.push(miner);
+                            }
+                            if let Some(batch) = take_discovery_batch(&group_name, &mut pending_miners)
+                                && output.send(batch).await.is_err()
+                            {
+                                return Err(ScannerError::ChannelClosed);
+                            }
+                            // ...and the final count, so progress never lags
+                            // behind the completion that follows
+                            if let Some(progress) = unsent_progress.take()
+                                && output.send(progress_message(progress)).await.is_err()
+                            {
+                                return Err(ScannerError::ChannelClosed);
+                            }
                             break;
                         }
                     }
                 }
+
+                _ = cancel.cancelled() => {
+                    scan_handle.abort();
+                    // Discoveries made before the stop still count
+                    while let Ok(miner) = rx.try_recv() {
+                        pending_miners.push(miner);
+                    }
+                    if let Some(batch) = take_discovery_batch(&group_name, &mut pending_miners)
+                        && output.send(batch).await.is_err()
+                    {
+                        return Err(ScannerError::ChannelClosed);
+                    }
+                    return Err(ScannerError::Cancelled);
+                }
+
+                _ = speed_ticker.tick() => {
+                    let speed_msg = ScannerMessage::ScanSpeed {
+                        group_name: group_name.to_owned(),
+                        ips_per_second: ips_per_second(latest_scanned_count, scan_start.elapsed()),
+                    };
+
+                    if output.send(speed_msg).await.is_err() {
+                        return Err(ScannerError::ChannelClosed);
+                    }
+                }
             }
         }
 
         // Wait for the background scan task to complete
         scan_handle.await.map_err(|e| {
-            ScannerError::ThreadError(format!("Background scan task failed: {}", e))
+            if e.is_cancelled() {
+                ScannerError::Cancelled
+            } else {
+                ScannerError::ThreadError(format!("Background scan task failed: {}", e))
+            }
         })??;
 
         Ok(())
@@ -261,25 +529,48 @@ impl Scanner {
         tx: tokio::sync::mpsc::UnboundedSender<MinerData>,
         progress_tx: tokio::sync::mpsc::UnboundedSender<ThrottledProgress>,
         group_name: String,
+        cancel: &CancelFlag,
+        create_source: SourceFactory,
     ) -> ScannerResult<()> {
-        let factory = super::create_configured_miner_factory(network_range, config)?;
-        let total_ips = factory.hosts().len();
+        let source = create_source(network_range, config)?;
+        let total_ips = source.host_count();
 
-        let stream = factory.scan_stream_with_ip();
+        let stream = source.scan();
 
         let scanned_count = Arc::new(AtomicUsize::new(0));
+        let failed_count = Arc::new(AtomicUsize::new(0));
+        let discovered_count = Arc::new(AtomicUsize::new(0));
+        let aborted = Arc::new(AtomicBool::new(false));
+        let abort_threshold = config.abort_after_failures;
+        let probe_timeout = config.probe_timeout_secs.map(Duration::from_secs);
 
-        // Scan all IPs concurrently with no limit
+        // Scan IPs concurrently up to the group's limit, stopping early if
+        // the breaker trips or the group is stopped
+        let aborted_check = aborted.clone();
+        let aborted_for_task = aborted.clone();
+        let failed_for_task = failed_count.clone();
+        let cancel_check = cancel.clone();
+        let cancel_for_task = cancel.clone();
         stream
-            .for_each_concurrent(None, move |(_ip, miner)| {
+            .take_while(move |_| {
+                future::ready(!aborted_check.load(Ordering::SeqCst) && !cancel_check.is_cancelled())
+            })
+            .for_each_concurrent(config.concurrency_limit(), move |(_ip, miner)| {
                 let tx = tx.clone(); // Much cheaper than Arc<Mutex>
                 let progress_tx = progress_tx.clone();
                 let scanned_count = scanned_count.clone();
+                let failed_count = failed_for_task.clone();
+                let discovered_count = discovered_count.clone();
+                let aborted = aborted_for_task.clone();
                 let group_name = group_name.clone();
+                let cancel = cancel_for_task.clone();
 
                 async move {
-                    let current_count =
-                        scanned_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                    if cancel.is_cancelled() {
+                        return;
+                    }
+
+                    let current_count = scanned_count.fetch_add(1, Ordering::SeqCst) + 1;
 
                     let _ = progress_tx.send(ThrottledProgress {
                         group_name: group_name.clone(),
@@ -287,14 +578,482 @@ impl Scanner {
                         scanned_count: current_count,
                     });
 
-                    if let Some(miner) = miner {
-                        let miner_data = get_partial_data(miner).await;
-                        let _ = tx.send(miner_data);
+                    let record_failure = || {
+                        let failures = failed_count.fetch_add(1, Ordering::SeqCst) + 1;
+                        let discovered = discovered_count.load(Ordering::SeqCst);
+                        if should_abort_scan(failures, discovered, abort_threshold) {
+                            aborted.store(true, Ordering::SeqCst);
+                        }
+                    };
+
+                    let Some(miner) = miner else {
+                        record_failure();
+                        return;
+                    };
+                    // Counted as soon as it's identified, so dead IPs that
+                    // fail while its data is still coming can't trip the breaker
+                    discovered_count.fetch_add(1, Ordering::SeqCst);
+
+                    // A host that stops answering is skipped once the probe
+                    // timeout passes, and counts as unresponsive
+                    let miner = async move {
+                        match probe_timeout {
+                            Some(limit) => tokio::time::timeout(limit, miner).await.ok(),
+                            None => Some(miner.await),
+                        }
+                    };
+                    // Fetches still in flight when the group stops are dropped
+                    tokio::select! {
+                        miner_data = miner => match miner_data {
+                            Some(miner_data) => {
+                                let _ = tx.send(miner_data);
+                            }
+                            None => record_failure(),
+                        },
+                        _ = cancel.cancelled() => {}
                     }
                 }
             })
             .await;
 
+        if cancel.is_cancelled() {
+            return Err(ScannerError::Cancelled);
+        }
+
+        if aborted.load(Ordering::SeqCst) {
+            return Err(ScannerError::TooManyFailures(
+                failed_count.load(Ordering::SeqCst),
+            ));
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_miner;
+    use std::collections::HashSet;
+
+    /// Scripted source: the "network range" is a comma-separated list of IPs
+    /// where a trailing `*` marks one that answers as a miner and a trailing
+    /// `?` one that is identified but never returns its data. A final `...`
+    /// leaves the scan running forever after the listed IPs.
+    struct MockSource(Vec<(IpAddr, Option<bool>)>, bool);
+
+    impl MinerSource for MockSource {
+        fn host_count(&self) -> usize {
+            self.0.len()
+        }
+
+        fn scan(self: Box<Self>) -> BoxStream<'static, ProbeResult> {
+            let results = iced::futures::stream::iter(self.0).map(|(ip, answers)| {
+                let miner = answers.map(|responds| {
+                    if responds {
+                        future::ready(test_miner(ip)).boxed()
+                    } else {
+                        future::pending().boxed()
+                    }
+                });
+                (ip, miner)
+            });
+            if self.1 {
+                results.chain(iced::futures::stream::pending()).boxed()
+            } else {
+                results.boxed()
+            }
+        }
+    }
+
+    fn mock_source(script: &str, _config: &ScanConfig) -> ScannerResult<Box<dyn MinerSource>> {
+        let (script, endless) = match script.strip_suffix(",...") {
+            Some(hosts) => (hosts, true),
+            None => (script, false),
+        };
+        let hosts = script
+            .split(',')
+            .map(|host| {
+                let answers = match host.chars().last() {
+                    Some('*') => Some(true),
+                    Some('?') => Some(false),
+                    _ => None,
+                };
+                host.trim_end_matches(['*', '?'])
+                    .parse()
+                    .map(|ip| (ip, answers))
+                    .map_err(|_| ScannerError::NetworkRangeInvalid(script.to_string()))
+            })
+            .collect::<ScannerResult<_>>()?;
+        Ok(Box::new(MockSource(hosts, endless)))
+    }
+
+    /// Runs a scan against scripted sources and collects messages up to `AllScansCompleted`.
+    async fn run_scan(groups: Vec<ScanGroup>) -> Vec<ScannerMessage> {
+        let mut stream = Scanner::scan_groups_with(groups, mock_source);
+        let mut messages = Vec::new();
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while let Some(message) = stream.next().await {
+                let done = matches!(message, ScannerMessage::AllScansCompleted);
+                messages.push(message);
+                if done {
+                    break;
+                }
+            }
+        })
+        .await
+        .expect("scan should complete");
+
+        messages
+    }
+
+    fn discovered(messages: &[ScannerMessage], group: &str) -> HashSet<String> {
+        messages
+            .iter()
+            .flat_map(|message| match message {
+                ScannerMessage::MinerDiscovered { group_name, miner } if group_name == group => {
+                    vec![miner.ip.to_string()]
+                }
+                ScannerMessage::MinersDiscovered { group_name, miners } if group_name == group => {
+                    miners.iter().map(|miner| miner.ip.to_string()).collect()
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    fn completion(messages: &[ScannerMessage], group: &str) -> (usize, Result<(), String>) {
+        messages
+            .iter()
+            .enumerate()
+            .find_map(|(idx, message)| match message {
+                ScannerMessage::GroupScanCompleted { group_name, result }
+                    if group_name == group =>
+                {
+                    Some((idx, result.clone().map_err(|e| e.to_string())))
+                }
+                _ => None,
+            })
+            .expect("group should report completion")
+    }
+
+    #[tokio::test]
+    async fn test_multi_group_scan_messages() {
+        let messages = run_scan(vec![
+            ScanGroup::new("A", "10.0.0.1*,10.0.0.2,10.0.0.3*", ScanConfig::default()),
+            ScanGroup::new("B", "10.0.1.1,10.0.1.2*", ScanConfig::default()),
+            ScanGroup::new("C", "not-an-ip", ScanConfig::default()),
+        ])
+        .await;
+
+        assert_eq!(
+            discovered(&messages, "A"),
+            HashSet::from(["10.0.0.1".to_string(), "10.0.0.3".to_string()])
+        );
+        assert_eq!(
+            discovered(&messages, "B"),
+            HashSet::from(["10.0.1.2".to_string()])
+        );
+        assert!(discovered(&messages, "C").is_empty());
+
+        // Every discovery precedes its group's completion
+        for group in ["A", "B"] {
+            let (completed_at, result) = completion(&messages, group);
+            assert_eq!(result, Ok(()));
+            assert!(messages[completed_at..].iter().all(|message| !matches!(
+                message,
+                ScannerMessage::MinerDiscovered { group_name, .. }
+                    | ScannerMessage::MinersDiscovered { group_name, .. } if group_name == group
+            )));
+        }
+        assert!(completion(&messages, "C").1.is_err());
+
+        let all_completed = messages
+            .iter()
+            .filter(|message| matches!(message, ScannerMessage::AllScansCompleted))
+            .count();
+        assert_eq!(all_completed, 1);
+        assert!(matches!(
+            messages.last(),
+            Some(ScannerMessage::AllScansCompleted)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_progress_is_throttled() {
+        let hosts: Vec<String> = (1..=250).map(|octet| format!("10.0.2.{octet}")).collect();
+        let messages = run_scan(vec![ScanGroup::new(
+            "Big",
+            hosts.join(","),
+            ScanConfig::default(),
+        )])
+        .await;
+
+        let progress: Vec<(usize, usize)> = messages
+            .iter()
+            .filter_map(|message| match message {
+                ScannerMessage::IpScanned {
+                    total_ips,
+                    scanned_count,
+                    ..
+                } => Some((*total_ips, *scanned_count)),
+                _ => None,
+            })
+            .collect();
+
+        // A scripted scan finishes well within one throttle window, but the
+        // final count is always delivered
+        assert!(progress.len() < hosts.len());
+        assert_eq!(progress.last(), Some(&(250, 250)));
+        assert!(
+            progress
+                .iter()
+                .all(|&(total, scanned)| total == 250 && scanned <= total)
+        );
+        assert_eq!(completion(&messages, "Big").1, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_completion_is_last_message_per_group() {
+        let hosts = |subnet: u8| {
+            (1..=100)
+                .map(|octet| {
+                    let marker = if octet % 10 == 0 { "*" } else { "" };
+                    format!("10.0.{subnet}.{octet}{marker}")
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let messages = run_scan(vec![
+            ScanGroup::new("A", hosts(4), ScanConfig::default()),
+            ScanGroup::new("B", hosts(5), ScanConfig::default()),
+        ])
+        .await;
+
+        for group in ["A", "B"] {
+            let (completed_at, _) = completion(&messages, group);
+            let late = messages[completed_at + 1..]
+                .iter()
+                .any(|message| match message {
+                    ScannerMessage::MinerDiscovered { group_name, .. }
+                    | ScannerMessage::MinersDiscovered { group_name, .. }
+                    | ScannerMessage::IpScanned { group_name, .. }
+                    | ScannerMessage::ScanSpeed { group_name, .. }
+                    | ScannerMessage::GroupScanCompleted { group_name, .. } => group_name == group,
+                    ScannerMessage::AllScansCompleted => false,
+                });
+            assert!(!late, "group {group} sent messages after completing");
+            assert_eq!(discovered(&messages, group).len(), 10);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_discovery_burst_is_batched() {
+        let hosts: Vec<String> = (1..=250).map(|octet| format!("10.0.6.{octet}*")).collect();
+        let messages = run_scan(vec![ScanGroup::new(
+            "Dense",
+            hosts.join(","),
+            ScanConfig::default(),
+        )])
+        .await;
+
+        let discovery_messages = messages
+            .iter()
+            .filter(|message| {
+                matches!(
+                    message,
+                    ScannerMessage::MinerDiscovered { .. }
+                        | ScannerMessage::MinersDiscovered { .. }
+                )
+            })
+            .count();
+
+        // 250 simultaneous answers reach the update loop as a handful of
+        // messages, none of them over the batch limit, and nothing is lost
+        assert!(discovery_messages <= 250 / DISCOVERY_BATCH_MAX + 2);
+        assert!(messages.iter().all(|message| match message {
+            ScannerMessage::MinersDiscovered { miners, .. } => miners.len() <= DISCOVERY_BATCH_MAX,
+            _ => true,
+        }));
+        assert_eq!(discovered(&messages, "Dense").len(), 250);
+        assert_eq!(completion(&messages, "Dense").1, Ok(()));
+    }
+
+    #[test]
+    fn test_take_discovery_batch() {
+        let ip = |octet| IpAddr::from([10, 0, 7, octet]);
+        let mut pending = Vec::new();
+        assert!(take_discovery_batch("A", &mut pending).is_none());
+
+        pending.push(test_miner(ip(1)));
+        assert!(matches!(
+            take_discovery_batch("A", &mut pending),
+            Some(ScannerMessage::MinerDiscovered { .. })
+        ));
+        assert!(pending.is_empty());
+
+        pending.extend([test_miner(ip(1)), test_miner(ip(2))]);
+        assert!(matches!(
+            take_discovery_batch("A", &mut pending),
+            Some(ScannerMessage::MinersDiscovered { miners, .. }) if miners.len() == 2
+        ));
+        assert!(pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_abort_threshold_fails_group() {
+        let config = ScanConfig {
+            abort_after_failures: Some(2),
+            ..ScanConfig::default()
+        };
+        let messages = run_scan(vec![ScanGroup::new(
+            "Dark",
+            "10.0.3.1,10.0.3.2,10.0.3.3,10.0.3.4",
+            config,
+        )])
+        .await;
+
+        assert!(completion(&messages, "Dark").1.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_stops_only_that_group() {
+        let stuck = ScanGroup::new("Stuck", "10.0.9.1*,10.0.9.2,...", ScanConfig::default());
+        let cancel = stuck.cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            cancel.cancel();
+        });
+        let messages = run_scan(vec![
+            stuck,
+            ScanGroup::new("Fine", "10.0.9.3*", ScanConfig::default()),
+        ])
+        .await;
+
+        assert_eq!(
+            completion(&messages, "Stuck").1,
+            Err(ScannerError::Cancelled.to_string())
+        );
+        assert_eq!(discovered(&messages, "Stuck").len(), 1);
+        assert_eq!(completion(&messages, "Fine").1, Ok(()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_probe_timeout_skips_silent_host() {
+        let config = ScanConfig {
+            probe_timeout_secs: Some(1),
+            ..ScanConfig::default()
+        };
+        let messages = run_scan(vec![ScanGroup::new(
+            "Firewalled",
+            "10.0.11.1*,10.0.11.2?",
+            config,
+        )])
+        .await;
+
+        assert_eq!(completion(&messages, "Firewalled").1, Ok(()));
+        assert_eq!(
+            discovered(&messages, "Firewalled"),
+            HashSet::from(["10.0.11.1".to_string()])
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_miner_disarms_breaker() {
+        // Dead IPs failing while an identified miner is still sending its
+        // data mustn't abort the group
+        let config = ScanConfig {
+            abort_after_failures: Some(2),
+            probe_timeout_secs: Some(1),
+            ..ScanConfig::default()
+        };
+        let messages = run_scan(vec![ScanGroup::new(
+            "Slow",
+            "10.0.12.1?,10.0.12.2,10.0.12.3,10.0.12.4,10.0.12.5,10.0.12.6*",
+            config,
+        )])
+        .await;
+
+        assert_eq!(completion(&messages, "Slow").1, Ok(()));
+        assert_eq!(
+            discovered(&messages, "Slow"),
+            HashSet::from(["10.0.12.6".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_scan_skips_probes() {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+        let cancel = CancelFlag::default();
+        cancel.cancel();
+
+        let result = Scanner::scan_network(
+            "10.0.10.1*,10.0.10.2*,10.0.10.3",
+            &ScanConfig::default(),
+            tx,
+            progress_tx,
+            "Stopped".to_string(),
+            &cancel,
+            mock_source,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ScannerError::Cancelled)));
+        assert!(rx.recv().await.is_none());
+        assert!(progress_rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_no_groups_completes_immediately() {
+        let messages = run_scan(Vec::new()).await;
+        assert!(matches!(
+            messages.as_slice(),
+            [ScannerMessage::AllScansCompleted]
+        ));
+    }
+
+    #[test]
+    fn test_should_abort_scan() {
+        assert!(!should_abort_scan(1000, 0, None));
+        assert!(!should_abort_scan(99, 0, Some(100)));
+        assert!(should_abort_scan(100, 0, Some(100)));
+        // A single discovery disarms the breaker
+        assert!(!should_abort_scan(5000, 1, Some(100)));
+    }
+
+    #[test]
+    fn test_ips_per_second() {
+        assert_eq!(ips_per_second(0, Duration::ZERO), 0.0);
+        assert_eq!(ips_per_second(500, Duration::ZERO), 0.0);
+        assert_eq!(ips_per_second(254, Duration::from_secs(2)), 127.0);
+    }
+
+    #[test]
+    fn test_scan_config_defaults_missing_threshold() {
+        let config: ScanConfig =
+            serde_json::from_str(r#"{"search_makes":null,"search_firmwares":null}"#).unwrap();
+        assert_eq!(config.abort_after_failures, None);
+        assert_eq!(config.max_concurrency, None);
+        assert_eq!(config.concurrency_limit(), DEFAULT_MAX_CONCURRENCY);
+    }
+
+    #[test]
+    fn test_max_concurrency_round_trips() {
+        let config = ScanConfig {
+            max_concurrency: Some(64),
+            ..ScanConfig::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let restored: ScanConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, config);
+        assert_eq!(restored.concurrency_limit(), 64);
+
+        let older: ScanConfig = serde_json::from_str(
+            r#"{"search_makes":null,"search_firmwares":null,"max_concurrent":20}"#,
+        )
+        .unwrap();
+        assert_eq!(older.max_concurrency, Some(20));
+    }
+}
@@ -1,10 +1,38 @@
-use crate::config::{AppConfig, ScanGroup};
-use crate::network::scanner::ScanConfig;
+use crate::config::{AppConfig, DeviceExpectation, ScanGroup, Trend, estimated_bandwidth_mb};
+use crate::formatters::format_megabytes;
+use crate::import::import_asicrs_config;
+use crate::network::latency::{LATENCY_SAMPLE_SIZE, LatencyReport};
+use crate::network::scanner::{DEFAULT_MAX_CONCURRENCY, ScanConfig};
+use crate::network::validate_network_range;
+use crate::power_schedule::PowerWindow;
 use crate::theme;
+use crate::ui_helpers::format_duration;
 use asic_rs::data::device::{MinerFirmware, MinerMake};
-use iced::widget::{Space, button, checkbox, column, container, row, scrollable, text_input};
+use iced::widget::{Space, button, checkbox, column, container, row, scrollable, text, text_input};
 use iced::{Element, Length};
 use std::collections::HashSet;
+use std::path::Path;
+
+/// Makes a group can be expected to hold, with their labels
+const EXPECTED_MAKES: [(MinerMake, &str); 6] = [
+    (MinerMake::AntMiner, "AntMiner (Bitmain)"),
+    (MinerMake::WhatsMiner, "WhatsMiner (MicroBT)"),
+    (MinerMake::AvalonMiner, "AvalonMiner (Canaan)"),
+    (MinerMake::Bitaxe, "BitAxe"),
+    (MinerMake::EPic, "ePIC"),
+    (MinerMake::Braiins, "Braiins"),
+];
+
+/// Firmwares a group can be expected to run, with their labels. Unlike the
+/// discovery filter this includes stock firmware, which racks often standardize on.
+const EXPECTED_FIRMWARES: [(MinerFirmware, &str); 6] = [
+    (MinerFirmware::Stock, "Stock"),
+    (MinerFirmware::BraiinsOS, "Braiins OS"),
+    (MinerFirmware::EPic, "ePIC UMC"),
+    (MinerFirmware::LuxOS, "Luxor OS"),
+    (MinerFirmware::VNish, "VNish"),
+    (MinerFirmware::Marathon, "Mara FW"),
+];
 
 #[derive(Clone, Debug)]
 pub struct NetworkConfig {
@@ -12,6 +40,15 @@ pub struct NetworkConfig {
     editing_group: Option<EditingGroup>,
     search_firmwares: HashSet<MinerFirmware>,
     search_makes: HashSet<MinerMake>,
+    paste_error: Option<String>,
+    /// Path typed into the asic-rs import row; `None` while the row is hidden
+    import_path: Option<String>,
+    import_error: Option<String>,
+    /// Groups renamed since the last save, as (old name, new name)
+    renamed_groups: Vec<(String, String)>,
+    /// Whether a reset keeps scan results; `None` while the reset
+    /// confirmation is hidden
+    reset_keep_results: Option<bool>,
 }
 
 #[derive(Clone, Debug)]
@@ -20,6 +57,23 @@ struct EditingGroup {
     name: String,
     network_range: String,
     enabled: bool,
+    abort_after_failures: String,
+    /// Concurrent probe limit as typed; empty uses the default
+    max_concurrency: String,
+    /// Seconds to wait for each miner's data; empty waits for asic-rs
+    probe_timeout_secs: String,
+    management_port: String,
+    /// Settings without a dedicated editor field, carried over unchanged on save
+    scan_config: ScanConfig,
+    power_schedule: Vec<PowerWindow>,
+    expected_makes: HashSet<MinerMake>,
+    /// Expected model as typed; empty accepts any model
+    expected_model: String,
+    expected_firmwares: HashSet<MinerFirmware>,
+    /// Set while "Profile Network" samples the range
+    profiling: bool,
+    /// Result of the last profile of the current range
+    latency_report: Option<LatencyReport>,
 }
 
 #[derive(Debug, Clone)]
@@ -32,11 +86,34 @@ pub enum NetworkConfigMessage {
     ToggleGroupEnabled(String, bool),
     SetGroupName(String),
     SetGroupNetworkRange(String),
+    /// Samples response times in the range being edited
+    ProfileNetwork,
+    /// Profile of the given range finished
+    NetworkProfiled(String, LatencyReport),
     SetGroupEnabled(bool),
+    SetAbortAfterFailures(String),
+    SetMaxConcurrency(String),
+    SetProbeTimeout(String),
+    SetManagementPort(String),
     SaveGroup,
     CancelGroupEdit,
     ToggleFirmware(MinerFirmware, bool),
     ToggleMake(MinerMake, bool),
+    ToggleExpectedMake(MinerMake, bool),
+    SetExpectedModel(String),
+    ToggleExpectedFirmware(MinerFirmware, bool),
+    CopyGroup(String),
+    PasteGroup,
+    GroupPasted(Option<String>),
+    ToggleImport,
+    SetImportPath(String),
+    ImportAsicRsConfig,
+    /// Asks to confirm replacing the whole config with the defaults
+    ShowResetConfirmation,
+    SetResetKeepResults(bool),
+    CancelReset,
+    /// Replaces the config with the defaults, keeping scan results if set
+    ConfirmReset(bool),
 }
 
 impl NetworkConfig {
@@ -46,17 +123,63 @@ impl NetworkConfig {
             editing_group: None,
             search_makes: HashSet::new(),
             search_firmwares: HashSet::new(),
+            paste_error: None,
+            import_path: None,
+            import_error: None,
+            renamed_groups: Vec::new(),
+            reset_keep_results: None,
         }
     }
 
     pub fn set_app_config(&mut self, config: AppConfig) {
         self.app_config = config;
+        self.editing_group = None;
+        self.paste_error = None;
+        self.import_path = None;
+        self.import_error = None;
+        self.renamed_groups.clear();
+        self.reset_keep_results = None;
+        self.reset_filters();
+    }
+
+    /// Takes the renames made since the last call, oldest first, so other
+    /// views can re-key state held under the old names.
+    pub fn take_renamed_groups(&mut self) -> Vec<(String, String)> {
+        std::mem::take(&mut self.renamed_groups)
     }
 
     pub fn get_app_config(&self) -> &AppConfig {
         &self.app_config
     }
 
+    pub fn app_config_mut(&mut self) -> &mut AppConfig {
+        &mut self.app_config
+    }
+
+    /// Returns the shareable JSON snippet for the named group.
+    pub fn group_snippet(&self, name: &str) -> Option<String> {
+        let group = self.app_config.get_group(name)?;
+        match group.to_snippet() {
+            Ok(snippet) => Some(snippet),
+            Err(e) => {
+                tracing::error!("Failed to serialize group {name}: {e}");
+                None
+            }
+        }
+    }
+
+    /// The range of the group being edited, for "Profile Network".
+    pub fn editing_range(&self) -> Option<String> {
+        self.editing_group
+            .as_ref()
+            .map(|editing| editing.network_range.clone())
+    }
+
+    /// Returns true if there are group edits that haven't been saved to `saved`.
+    pub fn has_unsaved_changes(&self, saved: &AppConfig) -> bool {
+        self.editing_group.is_some() || self.app_config.scan_groups != saved.scan_groups
+    }
+
     pub fn update(&mut self, msg: NetworkConfigMessage) {
         match msg {
             NetworkConfigMessage::AddNewGroup => {
@@ -65,6 +188,17 @@ impl NetworkConfig {
                     name: "New Group".to_string(),
                     network_range: "192.168.1.0/24".to_string(),
                     enabled: true,
+                    abort_after_failures: String::new(),
+                    max_concurrency: String::new(),
+                    probe_timeout_secs: String::new(),
+                    management_port: String::new(),
+                    scan_config: ScanConfig::default(),
+                    power_schedule: Vec::new(),
+                    expected_makes: HashSet::new(),
+                    expected_model: String::new(),
+                    expected_firmwares: HashSet::new(),
+                    profiling: false,
+                    latency_report: None,
                 });
                 self.reset_filters();
             }
@@ -75,6 +209,37 @@ impl NetworkConfig {
                         name: group.name.clone(),
                         network_range: group.network_range.clone(),
                         enabled: group.enabled,
+                        abort_after_failures: group
+                            .scan_config
+                            .abort_after_failures
+                            .map(|n| n.to_string())
+                            .unwrap_or_default(),
+                        max_concurrency: group
+                            .scan_config
+                            .max_concurrency
+                            .map(|n| n.to_string())
+                            .unwrap_or_default(),
+                        probe_timeout_secs: group
+                            .scan_config
+                            .probe_timeout_secs
+                            .map(|secs| secs.to_string())
+                            .unwrap_or_default(),
+                        management_port: group
+                            .management_port
+                            .map(|port| port.to_string())
+                            .unwrap_or_default(),
+                        scan_config: group.scan_config.clone(),
+                        power_schedule: group.power_schedule.clone(),
+                        expected_makes: group.expected_device.makes.iter().cloned().collect(),
+                        expected_model: group.expected_device.model.clone().unwrap_or_default(),
+                        expected_firmwares: group
+                            .expected_device
+                            .firmwares
+                            .iter()
+                            .cloned()
+                            .collect(),
+                        profiling: false,
+                        latency_report: None,
                     });
                     self.load_filters_from_group(&group.scan_config);
                 }
@@ -95,6 +260,22 @@ impl NetworkConfig {
             NetworkConfigMessage::SetGroupNetworkRange(range) => {
                 if let Some(ref mut editing) = self.editing_group {
                     editing.network_range = range;
+                    editing.latency_report = None;
+                }
+            }
+            NetworkConfigMessage::ProfileNetwork => {
+                if let Some(ref mut editing) = self.editing_group {
+                    editing.profiling = true;
+                    editing.latency_report = None;
+                }
+            }
+            NetworkConfigMessage::NetworkProfiled(range, report) => {
+                // Dropped if the range was edited while profiling
+                if let Some(ref mut editing) = self.editing_group
+                    && editing.network_range == range
+                {
+                    editing.profiling = false;
+                    editing.latency_report = Some(report);
                 }
             }
             NetworkConfigMessage::SetGroupEnabled(enabled) => {
@@ -102,18 +283,57 @@ impl NetworkConfig {
                     editing.enabled = enabled;
                 }
             }
+            NetworkConfigMessage::SetManagementPort(value) => {
+                if let Some(ref mut editing) = self.editing_group
+                    && value.len() <= 5
+                    && value.chars().all(|c| c.is_ascii_digit())
+                {
+                    editing.management_port = value;
+                }
+            }
+            NetworkConfigMessage::SetAbortAfterFailures(value) => {
+                if let Some(ref mut editing) = self.editing_group
+                    && value.chars().all(|c| c.is_ascii_digit())
+                {
+                    editing.abort_after_failures = value;
+                }
+            }
+            NetworkConfigMessage::SetMaxConcurrency(value) => {
+                if let Some(ref mut editing) = self.editing_group {
+                    editing.max_concurrency = value;
+                }
+            }
+            NetworkConfigMessage::SetProbeTimeout(value) => {
+                if let Some(ref mut editing) = self.editing_group {
+                    editing.probe_timeout_secs = value;
+                }
+            }
             NetworkConfigMessage::SaveGroup => {
-                if let Some(editing) = &self.editing_group {
-                    let scan_config = self.build_scan_config();
+                if let Some(editing) = &self.editing_group
+                    && let Ok(management_port) = parse_port(&editing.management_port)
+                    && parse_max_concurrency(&editing.max_concurrency).is_ok()
+                    && parse_probe_timeout(&editing.probe_timeout_secs).is_ok()
+                    && validate_network_range(&editing.network_range).is_ok()
+                {
+                    let scan_config = self.build_scan_config(editing);
 
                     let new_group = ScanGroup {
                         name: editing.name.clone(),
                         network_range: editing.network_range.clone(),
                         scan_config,
                         enabled: editing.enabled,
+                        management_port,
+                        power_schedule: editing.power_schedule.clone(),
+                        expected_device: expected_device(editing),
                     };
 
                     if let Some(ref original_name) = editing.original_name {
+                        if *original_name != new_group.name {
+                            self.app_config
+                                .rename_group_data(original_name, &new_group.name);
+                            self.renamed_groups
+                                .push((original_name.clone(), new_group.name.clone()));
+                        }
                         self.app_config.update_scan_group(original_name, new_group);
                     } else {
                         self.app_config.add_scan_group(new_group);
@@ -140,6 +360,84 @@ impl NetworkConfig {
                     self.search_makes.remove(&make);
                 }
             }
+            NetworkConfigMessage::ToggleExpectedMake(make, expect) => {
+                if let Some(ref mut editing) = self.editing_group {
+                    if expect {
+                        editing.expected_makes.insert(make);
+                    } else {
+                        editing.expected_makes.remove(&make);
+                    }
+                }
+            }
+            NetworkConfigMessage::SetExpectedModel(model) => {
+                if let Some(ref mut editing) = self.editing_group {
+                    editing.expected_model = model;
+                }
+            }
+            NetworkConfigMessage::ToggleExpectedFirmware(firmware, expect) => {
+                if let Some(ref mut editing) = self.editing_group {
+                    if expect {
+                        editing.expected_firmwares.insert(firmware);
+                    } else {
+                        editing.expected_firmwares.remove(&firmware);
+                    }
+                }
+            }
+            NetworkConfigMessage::GroupPasted(contents) => {
+                let result = contents
+                    .ok_or_else(|| "Clipboard is empty".to_string())
+                    .and_then(|snippet| {
+                        ScanGroup::from_snippet(&snippet).map_err(|e| e.to_string())
+                    });
+
+                match result {
+                    Ok(group) => {
+                        self.app_config.merge_scan_group(group);
+                        self.paste_error = None;
+                    }
+                    Err(error) => self.paste_error = Some(error),
+                }
+            }
+            NetworkConfigMessage::ToggleImport => {
+                self.import_path = match self.import_path {
+                    Some(_) => None,
+                    None => Some(String::new()),
+                };
+                self.import_error = None;
+            }
+            NetworkConfigMessage::SetImportPath(path) => {
+                self.import_path = Some(path);
+            }
+            NetworkConfigMessage::ImportAsicRsConfig => {
+                if let Some(path) = &self.import_path {
+                    // Imported groups stay unsaved so they can be reviewed first
+                    match import_asicrs_config(Path::new(path.trim())) {
+                        Ok(groups) => {
+                            for group in groups {
+                                self.app_config.merge_scan_group(group);
+                            }
+                            self.import_path = None;
+                            self.import_error = None;
+                        }
+                        Err(error) => self.import_error = Some(error.to_string()),
+                    }
+                }
+            }
+            NetworkConfigMessage::ShowResetConfirmation => {
+                self.reset_keep_results = Some(true);
+            }
+            NetworkConfigMessage::SetResetKeepResults(keep) => {
+                if self.reset_keep_results.is_some() {
+                    self.reset_keep_results = Some(keep);
+                }
+            }
+            NetworkConfigMessage::CancelReset => {
+                self.reset_keep_results = None;
+            }
+            // Replacing every config copy is handled at the BtcToolkit level
+            NetworkConfigMessage::ConfirmReset(_) => {}
+            // Clipboard access is a Task, so these are handled at the BtcToolkit level
+            NetworkConfigMessage::CopyGroup(_) | NetworkConfigMessage::PasteGroup => {}
             NetworkConfigMessage::Close | NetworkConfigMessage::Save => {}
         }
     }
@@ -161,14 +459,22 @@ impl NetworkConfig {
         }
     }
 
-    fn build_scan_config(&self) -> ScanConfig {
+    fn build_scan_config(&self, editing: &EditingGroup) -> ScanConfig {
         let makes: Vec<_> = self.search_makes.iter().cloned().collect();
         let firmwares: Vec<_> = self.search_firmwares.iter().cloned().collect();
 
-        ScanConfig {
-            search_makes: (!makes.is_empty()).then_some(makes),
-            search_firmwares: (!firmwares.is_empty()).then_some(firmwares),
-        }
+        let mut scan_config = editing.scan_config.clone();
+        scan_config.search_makes = (!makes.is_empty()).then_some(makes);
+        scan_config.search_firmwares = (!firmwares.is_empty()).then_some(firmwares);
+        scan_config.abort_after_failures =
+            editing.abort_after_failures.parse().ok().filter(|&n| n > 0);
+        scan_config.max_concurrency = parse_max_concurrency(&editing.max_concurrency)
+            .ok()
+            .flatten();
+        scan_config.probe_timeout_secs = parse_probe_timeout(&editing.probe_timeout_secs)
+            .ok()
+            .flatten();
+        scan_config
     }
 
     pub fn view(&self) -> Element<'_, NetworkConfigMessage> {
@@ -179,6 +485,90 @@ impl NetworkConfig {
         }
     }
 
+    /// Confirmation shown before "Reset to Defaults" replaces the config.
+    fn view_reset_row(&self) -> Element<'_, NetworkConfigMessage> {
+        let Some(keep_results) = self.reset_keep_results else {
+            return Space::new().height(0).into();
+        };
+
+        container(
+            row![
+                column![
+                    theme::typography::heading("Reset the configuration to defaults?"),
+                    theme::typography::small(
+                        "Every scan group and setting is replaced with the defaults. The current file is backed up first and can be restored from Settings."
+                    ),
+                    checkbox(keep_results)
+                        .label("Keep scan results and history")
+                        .on_toggle(NetworkConfigMessage::SetResetKeepResults),
+                ]
+                .spacing(theme::spacing::XS)
+                .width(Length::Fill),
+                button(theme::typography::body("Cancel"))
+                    .style(button::secondary)
+                    .padding(theme::padding::SM)
+                    .on_press(NetworkConfigMessage::CancelReset),
+                button(theme::typography::body("Reset"))
+                    .style(button::danger)
+                    .padding(theme::padding::SM)
+                    .on_press(NetworkConfigMessage::ConfirmReset(keep_results)),
+            ]
+            .spacing(theme::spacing::MD)
+            .align_y(iced::Alignment::Center),
+        )
+        .style(theme::containers::warning_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill)
+        .into()
+    }
+
+    fn view_import_row(&self) -> Element<'_, NetworkConfigMessage> {
+        let Some(path) = &self.import_path else {
+            return Space::new().height(0).into();
+        };
+
+        let mut items = column![
+            row![
+                text_input("Path to asic-rs config (JSON)", path)
+                    .on_input(NetworkConfigMessage::SetImportPath)
+                    .on_submit(NetworkConfigMessage::ImportAsicRsConfig)
+                    .padding(theme::padding::SM),
+                button(theme::typography::body("Import"))
+                    .style(button::primary)
+                    .padding(theme::padding::SM)
+                    .on_press_maybe(
+                        (!path.trim().is_empty())
+                            .then_some(NetworkConfigMessage::ImportAsicRsConfig)
+                    ),
+                button(theme::typography::body("Cancel"))
+                    .style(button::secondary)
+                    .padding(theme::padding::SM)
+                    .on_press(NetworkConfigMessage::ToggleImport),
+            ]
+            .spacing(theme::spacing::SM)
+            .align_y(iced::alignment::Vertical::Center),
+            theme::typography::small(
+                "Each IP list becomes a group scanning exactly those addresses. Review the groups, then Save."
+            ),
+        ]
+        .spacing(theme::spacing::XS);
+
+        if let Some(error) = &self.import_error {
+            items = items.push(
+                container(theme::typography::small(format!("Import failed: {error}")))
+                    .style(theme::containers::error_adaptive)
+                    .padding([theme::padding::XS, theme::padding::SM])
+                    .width(Length::Fill),
+            );
+        }
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
     fn view_groups_list(&self) -> Element<'_, NetworkConfigMessage> {
         let header = container(
             row![
@@ -188,6 +578,16 @@ impl NetworkConfig {
                 ]
                 .spacing(theme::spacing::XS),
                 Space::new().width(Length::Fill),
+                button(theme::typography::body("Import from asic-rs"))
+                    .style(button::secondary)
+                    .padding(theme::padding::SM)
+                    .on_press(NetworkConfigMessage::ToggleImport),
+                Space::new().width(theme::spacing::SM),
+                button(theme::typography::body("Paste Group"))
+                    .style(button::secondary)
+                    .padding(theme::padding::SM)
+                    .on_press(NetworkConfigMessage::PasteGroup),
+                Space::new().width(theme::spacing::SM),
                 button(
                     row![
                         theme::icons::icon_sm(theme::icons::ADD),
@@ -202,10 +602,23 @@ impl NetworkConfig {
             ]
             .align_y(iced::alignment::Vertical::Center),
         )
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::MD)
         .width(Length::Fill);
 
+        let paste_error: Element<'_, NetworkConfigMessage> = match &self.paste_error {
+            Some(error) => container(theme::typography::small(format!(
+                "Could not paste group: {error}"
+            )))
+            .style(theme::containers::error_adaptive)
+            .padding([theme::padding::XS, theme::padding::SM])
+            .width(Length::Fill)
+            .into(),
+            None => Space::new().height(0).into(),
+        };
+
+        let import_row = self.view_import_row();
+
         let groups_content = if self.app_config.scan_groups.is_empty() {
             container(
                 column![
@@ -242,7 +655,21 @@ impl NetworkConfig {
                     NetworkConfigMessage::ToggleGroupEnabled(group.name.clone(), enabled)
                 });
 
-                let filters_summary = self.format_filters_summary(&group.scan_config);
+                let mut filters_summary = self.format_filters_summary(&group.scan_config);
+                if let Some(port) = group.management_port {
+                    filters_summary.push_str(&format!(" | Port {port}"));
+                }
+                let expected = &group.expected_device;
+                if !expected.is_empty() {
+                    let parts: Vec<String> = expected
+                        .makes
+                        .iter()
+                        .map(|make| format!("{make:?}"))
+                        .chain(expected.model.clone())
+                        .chain(expected.firmwares.iter().map(|f| format!("{f:?}")))
+                        .collect();
+                    filters_summary.push_str(&format!(" | Expects {}", parts.join(", ")));
+                }
 
                 let group_card = container(
                     row![
@@ -257,14 +684,23 @@ impl NetworkConfig {
                                     "DISABLED"
                                 }))
                                 .style(if group.enabled {
-                                    theme::containers::success
+                                    theme::containers::success_adaptive
                                 } else {
-                                    theme::containers::card
+                                    theme::containers::card_adaptive
                                 })
                                 .padding([theme::padding::XS, theme::padding::SM])
                             ]
                             .align_y(iced::alignment::Vertical::Center),
-                            theme::typography::mono(&group.network_range),
+                            row![
+                                theme::typography::mono(&group.network_range),
+                                theme::typography::small(format_megabytes(
+                                    group.estimated_bandwidth_mb(
+                                        self.app_config.bytes_per_miner_probe
+                                    )
+                                )),
+                            ]
+                            .spacing(theme::spacing::SM)
+                            .align_y(iced::alignment::Vertical::Center),
                             theme::typography::small(filters_summary)
                         ]
                         .spacing(theme::spacing::XS)
@@ -279,6 +715,15 @@ impl NetworkConfig {
                             .padding(theme::padding::SM)
                             .width(Length::Fixed(120.0))
                             .on_press(NetworkConfigMessage::EditGroup(group.name.clone())),
+                            button(
+                                row![theme::typography::small("Copy")]
+                                    .spacing(theme::spacing::XS)
+                                    .align_y(iced::alignment::Vertical::Center)
+                            )
+                            .style(button::secondary)
+                            .padding(theme::padding::SM)
+                            .width(Length::Fixed(120.0))
+                            .on_press(NetworkConfigMessage::CopyGroup(group.name.clone())),
                             button(
                                 row![theme::typography::small("Delete")]
                                     .spacing(theme::spacing::XS)
@@ -294,7 +739,7 @@ impl NetworkConfig {
                     .spacing(theme::spacing::MD)
                     .align_y(iced::alignment::Vertical::Center),
                 )
-                .style(theme::containers::card)
+                .style(theme::containers::card_adaptive)
                 .padding(theme::padding::MD)
                 .width(Length::Fill);
 
@@ -318,6 +763,15 @@ impl NetworkConfig {
                 .style(button::secondary)
                 .padding(theme::padding::SM)
                 .on_press(NetworkConfigMessage::Close),
+                Space::new().width(theme::spacing::SM),
+                button(theme::typography::body("Reset to Defaults"))
+                    .style(button::danger)
+                    .padding(theme::padding::SM)
+                    .on_press_maybe(
+                        self.reset_keep_results
+                            .is_none()
+                            .then_some(NetworkConfigMessage::ShowResetConfirmation)
+                    ),
                 Space::new().width(Length::Fill),
                 button(
                     row![
@@ -334,11 +788,19 @@ impl NetworkConfig {
             ]
             .align_y(iced::alignment::Vertical::Center),
         )
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::MD)
         .width(Length::Fill);
 
-        let content = column![header, groups_content, action_buttons].spacing(0); // No spacing since containers have their own padding
+        let content = column![
+            header,
+            self.view_reset_row(),
+            paste_error,
+            import_row,
+            groups_content,
+            action_buttons
+        ]
+        .spacing(0); // No spacing since containers have their own padding
 
         container(content)
             .width(Length::Fill)
@@ -366,10 +828,13 @@ impl NetworkConfig {
             .spacing(theme::spacing::XS),
             Space::new().width(Length::Fill)
         ])
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::MD)
         .width(Length::Fill);
 
+        // Re-evaluated on every keystroke in the range input
+        let range_check = validate_network_range(&editing.network_range);
+
         let basic_config = container(
             column![
                 theme::typography::heading("Basic Configuration"),
@@ -384,7 +849,7 @@ impl NetworkConfig {
                     .spacing(theme::spacing::MD)
                     .align_y(iced::alignment::Vertical::Center)
                 )
-                .style(theme::containers::card)
+                .style(theme::containers::card_adaptive)
                 .padding(theme::padding::MD)
                 .width(Length::Fill),
                 container(column![
@@ -398,11 +863,32 @@ impl NetworkConfig {
                     .spacing(theme::spacing::MD)
                     .align_y(iced::alignment::Vertical::Center),
                     Space::new().height(Length::Fixed(theme::spacing::MD)),
+                    match &range_check {
+                        Ok(info) if info.ip_count == 1 => theme::typography::small(format!(
+                            "{}: 1 host, {}",
+                            info.format, info.first_ip
+                        )),
+                        Ok(info) => theme::typography::small(format!(
+                            "{}: {} hosts, {} to {}",
+                            info.format, info.ip_count, info.first_ip, info.last_ip
+                        )),
+                        Err(error) => theme::typography::small(error.to_string())
+                            .color(theme::colors::DANGER),
+                    },
+                    range_check.as_ref().ok().map(|info| theme::typography::small(format!(
+                        "Estimated scan traffic: {}",
+                        format_megabytes(estimated_bandwidth_mb(
+                            info.ip_count,
+                            self.app_config.bytes_per_miner_probe
+                        ))
+                    ))),
                     theme::typography::small(
-                        "Supports CIDR notation (192.168.1.0/24) or IP ranges (192.168.1.1-100)"
-                    )
+                        "Supports CIDR notation (192.168.1.0/24), IP ranges (192.168.1.1-100) and single IPs, comma-separated to combine them"
+                    ),
+                    Space::new().height(Length::Fixed(theme::spacing::SM)),
+                    view_latency_profile(editing, range_check.is_ok()),
                 ])
-                .style(theme::containers::card)
+                .style(theme::containers::card_adaptive)
                 .padding(theme::padding::MD)
                 .width(Length::Fill),
                 container(
@@ -413,13 +899,97 @@ impl NetworkConfig {
                     ]
                     .spacing(theme::spacing::MD),
                 )
-                .style(theme::containers::card)
+                .style(theme::containers::card_adaptive)
+                .padding(theme::padding::MD)
+                .width(Length::Fill),
+                container(column![
+                    row![
+                        theme::typography::body("Management Port:"),
+                        text_input("Default", &editing.management_port)
+                            .on_input(NetworkConfigMessage::SetManagementPort)
+                            .padding(theme::padding::SM)
+                            .width(Length::Fixed(120.0)),
+                    ]
+                    .spacing(theme::spacing::MD)
+                    .align_y(iced::alignment::Vertical::Center),
+                    Space::new().height(Length::Fixed(theme::spacing::MD)),
+                    match parse_port(&editing.management_port) {
+                        Ok(_) => theme::typography::small(
+                            "Port of the miners' web interface, e.g. 8080. Leave empty for the default."
+                        ),
+                        Err(error) => theme::typography::small(error).color(theme::colors::DANGER),
+                    }
+                ])
+                .style(theme::containers::card_adaptive)
+                .padding(theme::padding::MD)
+                .width(Length::Fill),
+                container(column![
+                    row![
+                        theme::typography::body("Abort After:"),
+                        text_input("Never", &editing.abort_after_failures)
+                            .on_input(NetworkConfigMessage::SetAbortAfterFailures)
+                            .padding(theme::padding::SM)
+                            .width(Length::Fixed(120.0)),
+                        theme::typography::body("unresponsive IPs"),
+                    ]
+                    .spacing(theme::spacing::MD)
+                    .align_y(iced::alignment::Vertical::Center),
+                    Space::new().height(Length::Fixed(theme::spacing::MD)),
+                    theme::typography::small(
+                        "Stops the scan early when no miner has been found yet, e.g. for a wrong range or a blocking firewall. Leave empty to always scan the full range."
+                    )
+                ])
+                .style(theme::containers::card_adaptive)
+                .padding(theme::padding::MD)
+                .width(Length::Fill),
+                container(column![
+                    row![
+                        theme::typography::body("Max Concurrency:"),
+                        text_input(&DEFAULT_MAX_CONCURRENCY.to_string(), &editing.max_concurrency)
+                            .on_input(NetworkConfigMessage::SetMaxConcurrency)
+                            .padding(theme::padding::SM)
+                            .width(Length::Fixed(120.0)),
+                        theme::typography::body("IPs at once"),
+                    ]
+                    .spacing(theme::spacing::MD)
+                    .align_y(iced::alignment::Vertical::Center),
+                    Space::new().height(Length::Fixed(theme::spacing::MD)),
+                    match parse_max_concurrency(&editing.max_concurrency) {
+                        Ok(_) => theme::typography::small(
+                            "Limits how many IPs are probed in parallel, e.g. 20 on a subnet shared with routers. Leave empty for the default."
+                        ),
+                        Err(error) => theme::typography::small(error).color(theme::colors::DANGER),
+                    }
+                ])
+                .style(theme::containers::card_adaptive)
+                .padding(theme::padding::MD)
+                .width(Length::Fill),
+                container(column![
+                    row![
+                        theme::typography::body("Probe Timeout:"),
+                        text_input("None", &editing.probe_timeout_secs)
+                            .on_input(NetworkConfigMessage::SetProbeTimeout)
+                            .padding(theme::padding::SM)
+                            .width(Length::Fixed(120.0)),
+                        theme::typography::body("seconds"),
+                    ]
+                    .spacing(theme::spacing::MD)
+                    .align_y(iced::alignment::Vertical::Center),
+                    Space::new().height(Length::Fixed(theme::spacing::MD)),
+                    match parse_probe_timeout(&editing.probe_timeout_secs) {
+                        Ok(_) => theme::typography::small(
+                            "Skips a miner that hasn't returned its data in time, e.g. behind a firewall that drops packets. Leave empty to wait as long as the miner library does."
+                        ),
+                        Err(error) => theme::typography::small(error).color(theme::colors::DANGER),
+                    }
+                ])
+                .style(theme::containers::card_adaptive)
                 .padding(theme::padding::MD)
                 .width(Length::Fill),
             ]
             .spacing(theme::spacing::MD),
         )
-        .style(theme::containers::card)
+        .style(theme::containers::card_adaptive)
         .padding(theme::padding::XL)
         .width(Length::Fill);
 
@@ -488,13 +1058,13 @@ impl NetworkConfig {
                     ]
                     .spacing(theme::spacing::LG)
                 )
-                .style(theme::containers::card)
+                .style(theme::containers::card_adaptive)
                 .padding(theme::padding::MD)
 
             ]
                 .spacing(theme::spacing::SM)
         )
-            .style(theme::containers::card)
+            .style(theme::containers::card_adaptive)
             .padding(theme::padding::XL)
         .width(Length::Fill);
 
@@ -528,19 +1098,30 @@ impl NetworkConfig {
                 )
                 .style(button::primary)
                 .padding(theme::padding::SM)
-                .on_press(NetworkConfigMessage::SaveGroup)
+                .on_press_maybe(
+                    (parse_port(&editing.management_port).is_ok()
+                        && parse_max_concurrency(&editing.max_concurrency).is_ok()
+                        && parse_probe_timeout(&editing.probe_timeout_secs).is_ok()
+                        && validate_network_range(&editing.network_range).is_ok())
+                    .then_some(NetworkConfigMessage::SaveGroup)
+                )
             ]
             .align_y(iced::alignment::Vertical::Center),
         )
-        .style(theme::containers::header)
+        .style(theme::containers::header_adaptive)
         .padding(theme::padding::MD)
         .width(Length::Fill);
 
-        let main_content =
-            container(column![basic_config, filter_config].spacing(theme::spacing::LG))
-                .width(Length::Fill)
-                .center_x(Length::Fill)
-                .padding(theme::padding::MD);
+        let mut sections = column![basic_config, filter_config, view_expected_device(editing)]
+            .spacing(theme::spacing::LG);
+        if let Some(ref name) = editing.original_name {
+            sections = sections.push(self.view_scan_history(name));
+        }
+
+        let main_content = container(sections)
+            .width(Length::Fill)
+            .center_x(Length::Fill)
+            .padding(theme::padding::MD);
 
         let content = column![
             header,
@@ -555,6 +1136,62 @@ impl NetworkConfig {
             .into()
     }
 
+    /// Last `SCAN_HISTORY_ROWS` scans of a group, newest first, with miner count trends.
+    fn view_scan_history(&self, group_name: &str) -> Element<'_, NetworkConfigMessage> {
+        const SCAN_HISTORY_ROWS: usize = 10;
+
+        let history = self.app_config.scan_history(group_name);
+        let mut items =
+            column![theme::typography::heading("Scan History")].spacing(theme::spacing::XS);
+
+        if history.is_empty() {
+            items = items.push(theme::typography::small(
+                "This group hasn't been scanned yet",
+            ));
+        } else {
+            items = items.push(row![
+                theme::typography::small("When").width(Length::FillPortion(3)),
+                theme::typography::small("Miners").width(Length::FillPortion(2)),
+                theme::typography::small("Duration").width(Length::FillPortion(2)),
+                theme::typography::small("Result").width(Length::FillPortion(4)),
+            ]);
+        }
+
+        for (idx, entry) in history.iter().enumerate().rev().take(SCAN_HISTORY_ROWS) {
+            let trend = match idx
+                .checked_sub(1)
+                .map(|prev| Trend::between(history[prev].miners_found, entry.miners_found))
+            {
+                Some(Trend::Up(n)) => text(format!(" ▲{n}")).color(theme::colors::SUCCESS),
+                Some(Trend::Down(n)) => text(format!(" ▼{n}")).color(theme::colors::DANGER),
+                Some(Trend::Flat) | None => text(""),
+            };
+
+            let when = chrono::DateTime::<chrono::Local>::from(entry.timestamp)
+                .format("%Y-%m-%d %H:%M")
+                .to_string();
+
+            items = items.push(row![
+                theme::typography::mono(when).width(Length::FillPortion(3)),
+                row![
+                    theme::typography::mono(entry.miners_found.to_string()),
+                    trend.size(theme::typography::SMALL_SIZE)
+                ]
+                .width(Length::FillPortion(2)),
+                theme::typography::mono(format_duration(entry.duration_seconds))
+                    .width(Length::FillPortion(2)),
+                theme::typography::small(entry.error.as_deref().unwrap_or("OK"))
+                    .width(Length::FillPortion(4)),
+            ]);
+        }
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::XL)
+            .width(Length::Fill)
+            .into()
+    }
+
     fn format_filters_summary(&self, scan_config: &ScanConfig) -> String {
         let mut parts = Vec::new();
 
@@ -573,6 +1210,14 @@ impl NetworkConfig {
             }
         }
 
+        if let Some(limit) = scan_config.max_concurrency {
+            parts.push(format!("Max {limit} concurrent"));
+        }
+
+        if let Some(secs) = scan_config.probe_timeout_secs {
+            parts.push(format!("Probe timeout: {secs}s"));
+        }
+
         if parts.is_empty() {
             "No filters (scan all)".to_string()
         } else {
@@ -580,3 +1225,281 @@ impl NetworkConfig {
         }
     }
 }
+
+/// "Profile Network" button and the latest latency report of the range.
+fn view_latency_profile(
+    editing: &EditingGroup,
+    range_valid: bool,
+) -> Element<'static, NetworkConfigMessage> {
+    let profile = button(theme::typography::small(if editing.profiling {
+        "Profiling..."
+    } else {
+        "Profile Network"
+    }))
+    .style(button::secondary)
+    .padding([theme::padding::XS, theme::padding::SM])
+    .on_press_maybe(
+        (range_valid && !editing.profiling).then_some(NetworkConfigMessage::ProfileNetwork),
+    );
+
+    let report: Element<'static, NetworkConfigMessage> = match &editing.latency_report {
+        None => theme::typography::small(format!(
+            "Times a connection to {LATENCY_SAMPLE_SIZE} IPs in the range before a long scan"
+        ))
+        .into(),
+        Some(report) if report.p50_ms.is_none() => theme::typography::small(format!(
+            "None of the {} sampled IPs answered; check the range and firewall",
+            report.sampled
+        ))
+        .color(theme::colors::WARNING)
+        .into(),
+        Some(report) => {
+            // Estimate at the concurrency the group will actually scan with
+            let concurrency = ScanConfig {
+                max_concurrency: parse_max_concurrency(&editing.max_concurrency)
+                    .ok()
+                    .flatten(),
+                ..editing.scan_config.clone()
+            }
+            .concurrency_limit();
+            let estimate = theme::typography::small(format!(
+                "Estimated scan time {} at {concurrency} IPs at once",
+                format_duration((report.estimated_scan_minutes(concurrency) * 60.0).ceil() as u64)
+            ));
+            let suggestion: Element<'static, NetworkConfigMessage> =
+                match report.suggested_probe_timeout_secs() {
+                    Some(secs) if editing.probe_timeout_secs.trim() != secs.to_string() => row![
+                        theme::typography::small(format!("Suggested probe timeout {secs} s")),
+                        button(theme::typography::small("Use"))
+                            .style(button::secondary)
+                            .padding([theme::padding::XS, theme::padding::SM])
+                            .on_press(NetworkConfigMessage::SetProbeTimeout(secs.to_string())),
+                    ]
+                    .spacing(theme::spacing::SM)
+                    .align_y(iced::alignment::Vertical::Center)
+                    .into(),
+                    _ => Space::new().into(),
+                };
+            column![
+                theme::typography::small(format!(
+                    "p50 {} ms, p95 {} ms, {} of {} sampled IPs didn't answer",
+                    report.p50_ms.unwrap_or(0),
+                    report.p95_ms.unwrap_or(0),
+                    report.failed_count,
+                    report.sampled
+                )),
+                estimate,
+                suggestion,
+            ]
+            .spacing(theme::spacing::XS)
+            .into()
+        }
+    };
+
+    row![profile, report]
+        .spacing(theme::spacing::MD)
+        .align_y(iced::alignment::Vertical::Center)
+        .into()
+}
+
+/// The expectation being edited, listed in the editor's order so saving an
+/// unchanged group leaves it equal to the stored one.
+fn expected_device(editing: &EditingGroup) -> DeviceExpectation {
+    let model = editing.expected_model.trim();
+    DeviceExpectation {
+        makes: EXPECTED_MAKES
+            .iter()
+            .map(|(make, _)| *make)
+            .filter(|make| editing.expected_makes.contains(make))
+            .collect(),
+        model: (!model.is_empty()).then(|| model.to_string()),
+        firmwares: EXPECTED_FIRMWARES
+            .iter()
+            .map(|(firmware, _)| *firmware)
+            .filter(|firmware| editing.expected_firmwares.contains(firmware))
+            .collect(),
+    }
+}
+
+fn view_expected_device<'a>(editing: &EditingGroup) -> Element<'a, NetworkConfigMessage> {
+    let makes = EXPECTED_MAKES.iter().fold(
+        column![theme::typography::body("Makes:")].spacing(theme::spacing::SM),
+        |column, (make, label)| {
+            let make = *make;
+            column.push(
+                checkbox(editing.expected_makes.contains(&make))
+                    .label(*label)
+                    .on_toggle(move |value| NetworkConfigMessage::ToggleExpectedMake(make, value)),
+            )
+        },
+    );
+    let firmwares = EXPECTED_FIRMWARES.iter().fold(
+        column![theme::typography::body("Firmware:")].spacing(theme::spacing::SM),
+        |column, (firmware, label)| {
+            let firmware = *firmware;
+            column.push(
+                checkbox(editing.expected_firmwares.contains(&firmware))
+                    .label(*label)
+                    .on_toggle(move |value| {
+                        NetworkConfigMessage::ToggleExpectedFirmware(firmware, value)
+                    }),
+            )
+        },
+    );
+
+    container(
+        column![
+            theme::typography::heading("Expected Device"),
+            theme::typography::small(
+                "Miners found in this group that differ from what's checked here get an \"Unexpected device\" badge, e.g. a swapped or misplaced unit. Leave everything empty to accept any device."
+            ),
+            text_input("Any model, e.g. S19 Pro", &editing.expected_model)
+                .on_input(NetworkConfigMessage::SetExpectedModel)
+                .padding(theme::padding::SM),
+            container(
+                row![
+                    makes.width(Length::FillPortion(1)),
+                    firmwares.width(Length::FillPortion(1)),
+                ]
+                .spacing(theme::spacing::LG),
+            )
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD),
+        ]
+        .spacing(theme::spacing::SM),
+    )
+    .style(theme::containers::card_adaptive)
+    .padding(theme::padding::XL)
+    .width(Length::Fill)
+    .into()
+}
+
+/// Parses the management port field: empty means "use the default".
+fn parse_port(value: &str) -> Result<Option<u16>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    match value.parse::<u16>() {
+        Ok(port) if port > 0 => Ok(Some(port)),
+        _ => Err("Port must be between 1 and 65535".to_string()),
+    }
+}
+
+/// Parses the concurrent probe limit, where empty means the default.
+fn parse_max_concurrency(value: &str) -> Result<Option<usize>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    match value.parse::<usize>() {
+        Ok(limit) if limit > 0 => Ok(Some(limit)),
+        _ => Err("Max concurrency must be a positive whole number".to_string()),
+    }
+}
+
+/// Parses the probe timeout in seconds, where empty means no timeout.
+fn parse_probe_timeout(value: &str) -> Result<Option<u64>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+
+    match value.parse::<u64>() {
+        Ok(secs) if secs > 0 => Ok(Some(secs)),
+        _ => Err("Probe timeout must be a positive number of seconds".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_port() {
+        assert_eq!(parse_port(""), Ok(None));
+        assert_eq!(parse_port("8080"), Ok(Some(8080)));
+        assert_eq!(parse_port("65535"), Ok(Some(65535)));
+        assert!(parse_port("0").is_err());
+        assert!(parse_port("70000").is_err());
+    }
+
+    #[test]
+    fn test_parse_max_concurrency() {
+        assert_eq!(parse_max_concurrency(""), Ok(None));
+        assert_eq!(parse_max_concurrency(" 20 "), Ok(Some(20)));
+        assert!(parse_max_concurrency("0").is_err());
+        assert!(parse_max_concurrency("-5").is_err());
+        assert!(parse_max_concurrency("ten").is_err());
+    }
+
+    #[test]
+    fn test_parse_probe_timeout() {
+        assert_eq!(parse_probe_timeout(""), Ok(None));
+        assert_eq!(parse_probe_timeout(" 5 "), Ok(Some(5)));
+        assert!(parse_probe_timeout("0").is_err());
+        assert!(parse_probe_timeout("1.5").is_err());
+    }
+
+    #[test]
+    fn test_save_group_keeps_max_concurrency() {
+        let mut view = NetworkConfig::new();
+        view.update(NetworkConfigMessage::AddNewGroup);
+        view.update(NetworkConfigMessage::SetGroupName("Farm D".to_string()));
+        view.update(NetworkConfigMessage::SetMaxConcurrency("abc".to_string()));
+        view.update(NetworkConfigMessage::SaveGroup);
+        assert!(view.get_app_config().get_group("Farm D").is_none());
+
+        view.update(NetworkConfigMessage::SetMaxConcurrency("20".to_string()));
+        view.update(NetworkConfigMessage::SaveGroup);
+        let group = view.get_app_config().get_group("Farm D").unwrap();
+        assert_eq!(group.scan_config.max_concurrency, Some(20));
+    }
+
+    #[test]
+    fn test_save_group_keeps_management_port() {
+        let mut view = NetworkConfig::new();
+        view.update(NetworkConfigMessage::AddNewGroup);
+        view.update(NetworkConfigMessage::SetGroupName("Farm B".to_string()));
+        view.update(NetworkConfigMessage::SetManagementPort("8080".to_string()));
+        view.update(NetworkConfigMessage::SaveGroup);
+
+        let group = view.get_app_config().get_group("Farm B").unwrap();
+        assert_eq!(group.management_port, Some(8080));
+    }
+
+    #[test]
+    fn test_save_group_rejects_invalid_range() {
+        let mut view = NetworkConfig::new();
+        view.update(NetworkConfigMessage::AddNewGroup);
+        view.update(NetworkConfigMessage::SetGroupName("Farm C".to_string()));
+        view.update(NetworkConfigMessage::SetGroupNetworkRange(
+            "10.0.0.50-10".to_string(),
+        ));
+        view.update(NetworkConfigMessage::SaveGroup);
+
+        assert!(view.get_app_config().get_group("Farm C").is_none());
+    }
+
+    #[test]
+    fn test_rename_moves_results() {
+        let mut view = NetworkConfig::new();
+        view.app_config_mut()
+            .store_scan_results("Default", Vec::new());
+        view.update(NetworkConfigMessage::EditGroup("Default".to_string()));
+        view.update(NetworkConfigMessage::SetGroupName("Farm A".to_string()));
+        view.update(NetworkConfigMessage::SaveGroup);
+
+        let config = view.get_app_config();
+        assert!(config.get_group("Farm A").is_some());
+        assert!(config.get_all_scan_results().contains_key("Farm A"));
+        assert!(!config.get_all_scan_results().contains_key("Default"));
+        assert_eq!(
+            view.take_renamed_groups(),
+            vec![("Default".to_string(), "Farm A".to_string())]
+        );
+        assert!(view.take_renamed_groups().is_empty());
+    }
+}
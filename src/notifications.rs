@@ -0,0 +1,91 @@
+use crate::theme;
+use iced::widget::{Space, button, column, container, row};
+use iced::{Element, Length};
+
+/// Maximum number of notifications kept on screen; older ones are dropped first
+const MAX_NOTIFICATIONS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub level: NotificationLevel,
+    pub message: String,
+}
+
+impl Notification {
+    pub fn new(level: NotificationLevel, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            message: message.into(),
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Error, message)
+    }
+}
+
+/// Pushes a notification, dropping the oldest ones beyond `MAX_NOTIFICATIONS`.
+pub fn push(notifications: &mut Vec<Notification>, notification: Notification) {
+    notifications.push(notification);
+    if notifications.len() > MAX_NOTIFICATIONS {
+        let overflow = notifications.len() - MAX_NOTIFICATIONS;
+        notifications.drain(..overflow);
+    }
+}
+
+/// Renders the notification stack as dismissible banners.
+pub fn view<'a, Message: Clone + 'static>(
+    notifications: &'a [Notification],
+    on_dismiss: impl Fn(usize) -> Message,
+) -> Element<'a, Message> {
+    let mut banners = column![].spacing(theme::spacing::XS);
+
+    for (idx, notification) in notifications.iter().enumerate() {
+        let style = match notification.level {
+            NotificationLevel::Info => theme::containers::badge_adaptive,
+            NotificationLevel::Success => theme::containers::success_adaptive,
+            NotificationLevel::Warning => theme::containers::warning_adaptive,
+            NotificationLevel::Error => theme::containers::error_adaptive,
+        };
+
+        let banner = container(
+            row![
+                theme::typography::body(&notification.message),
+                Space::new().width(Length::Fill),
+                button(theme::typography::small("Dismiss"))
+                    .style(button::text)
+                    .padding(0)
+                    .on_press(on_dismiss(idx)),
+            ]
+            .spacing(theme::spacing::SM)
+            .align_y(iced::alignment::Vertical::Center),
+        )
+        .style(style)
+        .padding([theme::padding::XS, theme::padding::SM])
+        .width(Length::Fill);
+
+        banners = banners.push(banner);
+    }
+
+    banners.into()
+}
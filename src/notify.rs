@@ -0,0 +1,198 @@
+use crate::formatters::TempUnit;
+use crate::health::{HealthReport, HealthStatus, HealthThresholds};
+use crate::network::full_fetch::http_client;
+use asic_rs::data::miner::MinerData;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+/// Webhook receivers that don't answer within this are given up on
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Post-scan commands still running after this are killed
+const POST_SCAN_COMMAND_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// JSON body posted to `completion_webhook` after a scan completes
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScanSummary {
+    pub miners_found: usize,
+    /// Miners with at least one critical health issue
+    pub critical_count: usize,
+    /// Per-group results, sorted by group name
+    pub groups: Vec<GroupSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GroupSummary {
+    pub name: String,
+    pub miners_found: usize,
+    pub critical_count: usize,
+}
+
+impl ScanSummary {
+    pub fn from_results(
+        results: &HashMap<String, Vec<MinerData>>,
+        thresholds: &HealthThresholds,
+    ) -> Self {
+        let mut groups: Vec<GroupSummary> = results
+            .iter()
+            .map(|(name, miners)| GroupSummary {
+                name: name.clone(),
+                miners_found: miners.len(),
+                critical_count: miners
+                    .iter()
+                    .filter(|miner| {
+                        HealthReport::from_miner_data(miner, thresholds, TempUnit::default()).status
+                            == HealthStatus::Critical
+                    })
+                    .count(),
+            })
+            .collect();
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            miners_found: groups.iter().map(|group| group.miners_found).sum(),
+            critical_count: groups.iter().map(|group| group.critical_count).sum(),
+            groups,
+        }
+    }
+}
+
+/// POSTs `summary` as JSON to `url`, treating any non-2xx answer as a failure.
+pub async fn post_summary(
+    url: String,
+    user_agent: String,
+    proxy: Option<String>,
+    summary: ScanSummary,
+) -> Result<(), String> {
+    let client =
+        http_client(&user_agent, WEBHOOK_TIMEOUT, proxy.as_deref()).map_err(|e| e.to_string())?;
+
+    client
+        .post(&url)
+        .json(&summary)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map(|_| ())
+        .map_err(|e| format!("{url}: {e}"))
+}
+
+/// Runs the user's post-scan `command` with the scan results JSON on stdin.
+/// The command line is split on whitespace and run without a shell. Returns
+/// its trimmed stdout and stderr, and fails on a non-zero exit.
+pub async fn run_post_scan_command(
+    command: String,
+    results_json: String,
+) -> Result<String, String> {
+    let mut parts = command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or("Post-scan command is empty")?
+        .to_string();
+    let mut child = tokio::process::Command::new(&program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("{program}: {e}"))?;
+
+    // Written alongside reading the output, so a chatty command can't block
+    // on a full pipe. Closing stdin afterwards marks the end of the JSON.
+    if let Some(mut stdin) = child.stdin.take() {
+        tokio::spawn(async move {
+            // A command that doesn't read its input may close it early
+            let _ = stdin.write_all(results_json.as_bytes()).await;
+        });
+    }
+
+    let output = tokio::time::timeout(POST_SCAN_COMMAND_TIMEOUT, child.wait_with_output())
+        .await
+        .map_err(|_| {
+            format!(
+                "{program} didn't finish within {}s",
+                POST_SCAN_COMMAND_TIMEOUT.as_secs()
+            )
+        })?
+        .map_err(|e| format!("{program}: {e}"))?;
+
+    let text = [output.stdout, output.stderr]
+        .iter()
+        .map(|bytes| String::from_utf8_lossy(bytes).trim().to_string())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if output.status.success() {
+        Ok(text)
+    } else {
+        Err(format!("{program} exited with {}: {text}", output.status))
+    }
+}
+
+/// Shows a notification on the desktop, outside the app window. Blocks
+/// until the notification server has taken it.
+pub fn show_desktop_notification(summary: &str, body: &str) -> Result<(), String> {
+    notify_rust::Notification::new()
+        .appname("BTC Toolkit")
+        .summary(summary)
+        .body(body)
+        .show()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{terahash, test_miner};
+
+    #[test]
+    fn test_summary_json_shape() {
+        let mut healthy = test_miner("10.0.1.5".parse().unwrap());
+        healthy.hashrate = Some(terahash(100.0));
+        let mut critical = test_miner("10.0.1.6".parse().unwrap());
+        critical.hashrate = Some(terahash(0.0));
+        critical.is_mining = false;
+
+        let results = HashMap::from([
+            ("Farm B".to_string(), Vec::new()),
+            ("Farm A".to_string(), vec![healthy, critical]),
+        ]);
+        let summary = ScanSummary::from_results(&results, &HealthThresholds::default());
+
+        assert_eq!(summary.miners_found, 2);
+        assert_eq!(
+            serde_json::to_value(&summary).unwrap(),
+            serde_json::json!({
+                "miners_found": 2,
+                "critical_count": 1,
+                "groups": [
+                    { "name": "Farm A", "miners_found": 2, "critical_count": 1 },
+                    { "name": "Farm B", "miners_found": 0, "critical_count": 0 },
+                ]
+            })
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_post_scan_command() {
+        let echoed = run_post_scan_command("cat".to_string(), "{\"groups\": []}".to_string()).await;
+        assert_eq!(echoed, Ok("{\"groups\": []}".to_string()));
+
+        assert!(
+            run_post_scan_command("false".to_string(), String::new())
+                .await
+                .is_err()
+        );
+        assert!(
+            run_post_scan_command("  ".to_string(), String::new())
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,331 @@
+use crate::config::ScanGroup;
+use crate::errors::ScheduleError;
+use chrono::{DateTime, Datelike, Duration, DurationRound, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Longest stretch of minutes checked in one evaluation, so a clock jump or a
+/// long sleep can't replay days of boundaries
+const MAX_LOOKBACK_MINUTES: i64 = 24 * 60;
+
+/// What a power window does to a group's miners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PowerAction {
+    Stop,
+    Resume,
+    /// Power limit in watts
+    SetPower(u32),
+}
+
+impl PowerAction {
+    /// The action that undoes this one when its window ends. A power limit
+    /// stays until another window changes it, as the previous limit isn't known.
+    pub fn reversal(self) -> Option<PowerAction> {
+        match self {
+            PowerAction::Stop => Some(PowerAction::Resume),
+            PowerAction::Resume => Some(PowerAction::Stop),
+            PowerAction::SetPower(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for PowerAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerAction::Stop => write!(f, "Stop mining"),
+            PowerAction::Resume => write!(f, "Resume mining"),
+            PowerAction::SetPower(watts) => write!(f, "Set power limit to {watts} W"),
+        }
+    }
+}
+
+/// A window during which `action` applies, bounded by two 5-field cron
+/// expressions evaluated in UTC, e.g. "0 17 * * 1-5" to "0 21 * * 1-5" for
+/// weekday evening peaks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PowerWindow {
+    pub cron_start: String,
+    pub cron_end: String,
+    pub action: PowerAction,
+}
+
+/// A parsed "minute hour day-of-month month day-of-week" expression.
+///
+/// Fields accept `*`, numbers, ranges (`1-5`), steps (`*/15`, `0-30/10`) and
+/// comma-separated lists of those. Day of week runs 0-7 with both 0 and 7
+/// meaning Sunday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Day-of-month was `*`; cron ORs the two day fields only when both are set
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self, ScheduleError> {
+        let invalid = |reason: String| ScheduleError::InvalidCron {
+            expr: expr.to_string(),
+            reason,
+        };
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields.as_slice() else {
+            return Err(invalid(format!(
+                "expected 5 fields, found {}",
+                fields.len()
+            )));
+        };
+
+        let mut weekdays = parse_field(weekday, 0, 7).map_err(invalid)?;
+        // Sunday is both 0 and 7
+        if weekdays & (1 << 7) != 0 {
+            weekdays |= 1;
+        }
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59).map_err(invalid)?,
+            hours: parse_field(hour, 0, 23).map_err(invalid)?,
+            days: parse_field(day, 1, 31).map_err(invalid)?,
+            months: parse_field(month, 1, 12).map_err(invalid)?,
+            weekdays,
+            any_day: *day == "*",
+            any_weekday: *weekday == "*",
+        })
+    }
+
+    pub fn matches(&self, time: DateTime<Utc>) -> bool {
+        let has = |mask: u64, value: u32| mask & (1 << value) != 0;
+        let day = has(self.days, time.day());
+        let weekday = has(self.weekdays, time.weekday().num_days_from_sunday());
+        let day_matches = match (self.any_day, self.any_weekday) {
+            (false, false) => day || weekday,
+            _ => day && weekday,
+        };
+
+        has(self.minutes, time.minute())
+            && has(self.hours, time.hour())
+            && has(self.months, time.month())
+            && day_matches
+    }
+}
+
+/// Bitmask of the values a cron field allows, bit `n` for value `n`.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, String> {
+    let number = |value: &str| -> Result<u32, String> {
+        match value.parse() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n),
+            _ => Err(format!("'{value}' is not a number from {min} to {max}")),
+        }
+    };
+
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => match step.parse::<u32>() {
+                Ok(step) if step > 0 => (range, step),
+                _ => return Err(format!("'{step}' is not a valid step")),
+            },
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (number(start)?, number(end)?),
+                None => {
+                    let n = number(range)?;
+                    // "5/10" means every 10 starting at 5
+                    (n, if step > 1 { max } else { n })
+                }
+            },
+        };
+        if start > end {
+            return Err(format!("'{range}' starts after it ends"));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+/// A window boundary that has come due.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueAction {
+    pub group: String,
+    /// Identifies the boundary in `AppConfig::power_schedule_fired`
+    pub key: String,
+    /// Unix time of the minute the boundary matched
+    pub minute: i64,
+    pub action: PowerAction,
+}
+
+/// Window boundaries whose cron matched a minute in `(since, now]` and that
+/// haven't already fired for that minute according to `fired`.
+///
+/// Windows with an invalid expression never fire. When several minutes in the
+/// span match, only the latest counts, and when both boundaries of a window
+/// matched only the later one fires, so a window never stops and resumes the
+/// same miners at once. A power limit has no end action, so its start still
+/// fires after the window closed.
+pub fn due_actions(
+    groups: &[ScanGroup],
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+    fired: &HashMap<String, i64>,
+) -> Vec<DueAction> {
+    let minute = Duration::minutes(1);
+    let now = now.duration_trunc(minute).unwrap_or(now);
+    let since = since
+        .duration_trunc(minute)
+        .unwrap_or(since)
+        .max(now - Duration::minutes(MAX_LOOKBACK_MINUTES));
+
+    let mut due = Vec::new();
+    for group in groups {
+        for (idx, window) in group.power_schedule.iter().enumerate() {
+            let boundaries = [
+                ("start", &window.cron_start, Some(window.action)),
+                ("end", &window.cron_end, window.action.reversal()),
+            ];
+            let latest = boundaries
+                .into_iter()
+                .filter_map(|(boundary, expr, action)| {
+                    let action = action?;
+                    let schedule = CronSchedule::parse(expr).ok()?;
+                    let time = std::iter::successors(Some(now), |time| Some(*time - minute))
+                        .take_while(|time| *time > since)
+                        .find(|time| schedule.matches(*time))?;
+                    Some((time, boundary, action))
+                })
+                // On a tie the end wins, as if the window opened and closed
+                .max_by_key(|(time, boundary, _)| (*time, *boundary == "end"));
+
+            let Some((time, boundary, action)) = latest else {
+                continue;
+            };
+            let key = format!("{}#{idx}:{boundary}", group.name);
+            if fired.get(&key) != Some(&time.timestamp()) {
+                due.push(DueAction {
+                    group: group.name.clone(),
+                    key,
+                    minute: time.timestamp(),
+                    action,
+                });
+            }
+        }
+    }
+    due
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        // March 2025 starts on a Saturday
+        Utc.with_ymd_and_hms(2025, 3, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_cron_matching() {
+        let weekday_evenings = CronSchedule::parse("0 17 * * 1-5").unwrap();
+        assert!(weekday_evenings.matches(at(3, 17, 0)));
+        assert!(!weekday_evenings.matches(at(3, 17, 1)));
+        assert!(!weekday_evenings.matches(at(1, 17, 0)));
+
+        let quarter_hours = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert!(quarter_hours.matches(at(5, 9, 45)));
+        assert!(!quarter_hours.matches(at(5, 9, 50)));
+
+        // Both day fields set: either may match
+        let first_or_sunday = CronSchedule::parse("30 6 1 * 7").unwrap();
+        assert!(first_or_sunday.matches(at(1, 6, 30)));
+        assert!(first_or_sunday.matches(at(2, 6, 30)));
+        assert!(!first_or_sunday.matches(at(3, 6, 30)));
+
+        let list = CronSchedule::parse("0,30 8-10/2 * 3 *").unwrap();
+        assert!(list.matches(at(4, 10, 30)));
+        assert!(!list.matches(at(4, 9, 30)));
+    }
+
+    #[test]
+    fn test_invalid_cron() {
+        for expr in [
+            "",
+            "0 17 * *",
+            "60 * * * *",
+            "0 17 * * 8",
+            "*/0 * * * *",
+            "5-1 * * * *",
+        ] {
+            assert!(
+                CronSchedule::parse(expr).is_err(),
+                "{expr} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_due_actions() {
+        let mut group = ScanGroup::new("Farm".to_string(), "10.0.0.0/24".to_string());
+        group.power_schedule = vec![PowerWindow {
+            cron_start: "0 17 * * *".to_string(),
+            cron_end: "0 21 * * *".to_string(),
+            action: PowerAction::Stop,
+        }];
+        let groups = [group];
+        let mut fired = HashMap::new();
+
+        assert!(due_actions(&groups, at(3, 16, 58), at(3, 16, 59), &fired).is_empty());
+
+        // A tick that slipped past the boundary minute still catches it
+        let due = due_actions(&groups, at(3, 16, 59), at(3, 17, 1), &fired);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].action, PowerAction::Stop);
+        assert_eq!(due[0].minute, at(3, 17, 0).timestamp());
+
+        // Already fired, e.g. before a restart
+        fired.insert(due[0].key.clone(), due[0].minute);
+        assert!(due_actions(&groups, at(3, 16, 45), at(3, 17, 5), &fired).is_empty());
+
+        let due = due_actions(&groups, at(3, 20, 59), at(3, 21, 0), &fired);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].action, PowerAction::Resume);
+    }
+
+    #[test]
+    fn test_due_actions_fires_only_latest_boundary() {
+        let mut group = ScanGroup::new("Farm".to_string(), "10.0.0.0/24".to_string());
+        group.power_schedule = vec![PowerWindow {
+            cron_start: "0 17 * * *".to_string(),
+            cron_end: "0 21 * * *".to_string(),
+            action: PowerAction::Stop,
+        }];
+        let groups = [group];
+        let fired = HashMap::new();
+
+        // A span covering the whole window only resumes, the state it ended in
+        let due = due_actions(&groups, at(3, 12, 0), at(3, 22, 0), &fired);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].action, PowerAction::Resume);
+        assert_eq!(due[0].minute, at(3, 21, 0).timestamp());
+
+        // Inside the window only the start is due
+        let due = due_actions(&groups, at(3, 12, 0), at(3, 18, 0), &fired);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].action, PowerAction::Stop);
+
+        // A power limit outlives its window, so its start still applies
+        let mut limited = groups[0].clone();
+        limited.power_schedule[0].action = PowerAction::SetPower(2500);
+        let due = due_actions(&[limited], at(3, 12, 0), at(3, 22, 0), &fired);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].action, PowerAction::SetPower(2500));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::theme;
+use crate::ui_helpers::{danger_button, format_duration, secondary_button};
+use asic_rs::data::miner::MinerData;
+use iced::widget::{Space, column, container, row, scrollable, text_input};
+use iced::{Element, Length};
+use std::collections::BTreeSet;
+use std::net::IpAddr;
+
+/// Text the user must type before a fleet-wide restart is unlocked
+pub const CONFIRMATION_PHRASE: &str = "RESTART ALL";
+
+#[derive(Debug, Clone)]
+pub enum RestartConfirmationMessage {
+    ConfirmationChanged(String),
+    Confirm,
+    Cancel,
+}
+
+/// Impact preview and typed confirmation for restarting many miners at once
+pub struct RestartConfirmationView {
+    /// Affected miners as (IP, model)
+    miners: Vec<(IpAddr, String)>,
+    pools: Vec<String>,
+    average_restart_seconds: u64,
+    confirmation: String,
+}
+
+impl RestartConfirmationView {
+    pub fn new<'a>(
+        miners: impl IntoIterator<Item = &'a MinerData>,
+        average_restart_seconds: u64,
+    ) -> Self {
+        let miners: Vec<&MinerData> = miners.into_iter().collect();
+        let mut targets: Vec<(IpAddr, String)> = miners
+            .iter()
+            .map(|m| (m.ip, m.device_info.model.to_string()))
+            .collect();
+        targets.sort_by_key(|(ip, _)| *ip);
+
+        Self {
+            pools: affected_pools(&miners),
+            miners: targets,
+            average_restart_seconds,
+            confirmation: String::new(),
+        }
+    }
+
+    pub fn targets(&self) -> Vec<IpAddr> {
+        self.miners.iter().map(|(ip, _)| *ip).collect()
+    }
+
+    pub fn set_confirmation(&mut self, confirmation: String) {
+        self.confirmation = confirmation;
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmation.trim() == CONFIRMATION_PHRASE
+    }
+
+    /// Combined downtime across the fleet: miner count × average restart time.
+    fn estimated_downtime_seconds(&self) -> u64 {
+        self.miners.len() as u64 * self.average_restart_seconds
+    }
+
+    pub fn view(&self) -> Element<'_, RestartConfirmationMessage> {
+        let header = container(
+            column![
+                theme::typography::title(format!("Restart {} Miners", self.miners.len())),
+                theme::typography::small(
+                    "Every listed miner stops hashing while it reboots and reconnects to its pools"
+                ),
+            ]
+            .spacing(theme::spacing::XS),
+        )
+        .style(theme::containers::header_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill);
+
+        let impact = container(
+            column![
+                theme::typography::heading("Impact"),
+                theme::typography::body(format!(
+                    "Estimated combined downtime: {} ({} miners × {} average restart)",
+                    format_duration(self.estimated_downtime_seconds()),
+                    self.miners.len(),
+                    format_duration(self.average_restart_seconds)
+                )),
+                theme::typography::body(if self.pools.is_empty() {
+                    "Affected pools: unknown (pool data is only collected by a full fetch)"
+                        .to_string()
+                } else {
+                    format!("Affected pools: {}", self.pools.join(", "))
+                }),
+            ]
+            .spacing(theme::spacing::SM),
+        )
+        .style(theme::containers::warning_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill);
+
+        let miner_list = self.miners.iter().fold(
+            column![theme::typography::heading("Affected Miners")].spacing(theme::spacing::XS),
+            |list, (ip, model)| {
+                list.push(
+                    row![
+                        theme::typography::mono(ip.to_string()).width(Length::Fixed(180.0)),
+                        theme::typography::body(model.clone()),
+                    ]
+                    .spacing(theme::spacing::MD),
+                )
+            },
+        );
+
+        let confirmation = container(
+            column![
+                theme::typography::body(format!("Type {CONFIRMATION_PHRASE} to confirm")),
+                text_input(CONFIRMATION_PHRASE, &self.confirmation)
+                    .on_input(RestartConfirmationMessage::ConfirmationChanged)
+                    .padding(theme::padding::SM),
+            ]
+            .spacing(theme::spacing::SM),
+        )
+        .style(theme::containers::card_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill);
+
+        let content = column![
+            impact,
+            container(miner_list)
+                .style(theme::containers::card_adaptive)
+                .padding(theme::padding::MD)
+                .width(Length::Fill),
+            confirmation,
+        ]
+        .spacing(theme::spacing::MD)
+        .padding(theme::padding::MD);
+
+        let action_buttons = container(
+            row![
+                secondary_button("Cancel", None, Some(RestartConfirmationMessage::Cancel)),
+                Space::new().width(Length::Fill),
+                danger_button(
+                    "Restart All",
+                    Some(theme::icons::refresh().into()),
+                    self.is_confirmed()
+                        .then_some(RestartConfirmationMessage::Confirm),
+                ),
+            ]
+            .align_y(iced::alignment::Vertical::Center),
+        )
+        .style(theme::containers::header_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill);
+
+        container(column![
+            header,
+            scrollable(content).height(Length::Fill),
+            action_buttons
+        ])
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+}
+
+/// Distinct pool URLs configured on the miners, sorted.
+fn affected_pools(miners: &[&MinerData]) -> Vec<String> {
+    miners
+        .iter()
+        .flat_map(|miner| &miner.pools)
+        .filter_map(|pool| pool.url.as_ref().map(ToString::to_string))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirmation_phrase_required() {
+        let mut view = RestartConfirmationView::new([], 180);
+        assert!(!view.is_confirmed());
+
+        view.set_confirmation("restart all".to_string());
+        assert!(!view.is_confirmed());
+
+        view.set_confirmation(" RESTART ALL ".to_string());
+        assert!(view.is_confirmed());
+    }
+}
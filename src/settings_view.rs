@@ -0,0 +1,715 @@
+use crate::config::{AppConfig, CONFIG_FILE, ConfigBackup, DEFAULT_USER_AGENT};
+use crate::formatters::TempUnit;
+use crate::network::full_fetch::validate_proxy_url;
+use crate::theme;
+use crate::ui_helpers::{danger_button, primary_button, secondary_button};
+use iced::widget::{Space, checkbox, column, container, radio, row, scrollable, text_input};
+use iced::{Element, Length};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub enum SettingsMessage {
+    Close,
+    ReloadConfig,
+    SetCompactResults(bool),
+    SetPersistResults(bool),
+    SetBackupOnSave(bool),
+    SetExpandProblemGroups(bool),
+    SetCollapseHealthyGroups(bool),
+    SetReverseDns(bool),
+    SetNotifyFirstDiscovery(bool),
+    SetSchemaUpgradeNotify(bool),
+    SetTemperatureUnit(TempUnit),
+    BrowserCommandChanged(String),
+    SaveBrowserCommand,
+    EolFirmwaresChanged(String),
+    SaveEolFirmwares,
+    AlertCooldownChanged(String),
+    FetchTimeoutChanged(String),
+    SaveFetchTimeout,
+    SaveAlertCooldown,
+    CompletionWebhookChanged(String),
+    SaveCompletionWebhook,
+    PostScanCommandChanged(String),
+    SavePostScanCommand,
+    UserAgentChanged(String),
+    SaveUserAgent,
+    ProxyChanged(String),
+    SaveProxy,
+    /// Shows or hides the ignored serial number list
+    SetManageIgnoredOpen(bool),
+    IgnoredSerialChanged(String),
+    AddIgnoredSerial,
+    RemoveIgnoredSerial(String),
+    RestoreBackup(PathBuf),
+    /// Write the asset register CSV next to the config file
+    ExportAssetRegister,
+}
+
+pub struct SettingsView {
+    unsaved_network_changes: bool,
+    compact_results: bool,
+    persist_results: bool,
+    backup_on_save: bool,
+    temperature_unit: TempUnit,
+    expand_problem_groups: bool,
+    collapse_healthy_groups: bool,
+    reverse_dns: bool,
+    notify_first_discovery: bool,
+    schema_upgrade_notify: bool,
+    /// Browser command as typed; saved to the config on Apply
+    browser_command: String,
+    /// EOL firmware versions as typed, comma-separated; saved to the config on Apply
+    eol_firmwares: String,
+    /// Alert cooldown in minutes as typed; saved to the config on Apply
+    alert_cooldown_minutes: String,
+    /// Detail fetch timeout in seconds, as typed
+    fetch_timeout_seconds: String,
+    /// Completion webhook URL as typed; saved to the config on Apply
+    completion_webhook: String,
+    /// Post-scan command as typed; saved to the config on Apply
+    post_scan_command: String,
+    /// Custom user agent as typed; saved to the config on Apply
+    user_agent: String,
+    /// Proxy URL as typed
+    proxy: String,
+    /// Ignored serial numbers, sorted for display
+    ignored_serials: Vec<String>,
+    manage_ignored_open: bool,
+    /// Serial number being typed into the ignore list
+    ignored_serial_input: String,
+    backups: Vec<ConfigBackup>,
+}
+
+impl SettingsView {
+    pub fn new() -> Self {
+        Self {
+            unsaved_network_changes: false,
+            compact_results: false,
+            persist_results: true,
+            backup_on_save: false,
+            temperature_unit: TempUnit::default(),
+            expand_problem_groups: false,
+            collapse_healthy_groups: false,
+            reverse_dns: false,
+            notify_first_discovery: false,
+            schema_upgrade_notify: true,
+            browser_command: String::new(),
+            eol_firmwares: String::new(),
+            alert_cooldown_minutes: String::new(),
+            fetch_timeout_seconds: String::new(),
+            completion_webhook: String::new(),
+            post_scan_command: String::new(),
+            user_agent: String::new(),
+            proxy: String::new(),
+            ignored_serials: Vec::new(),
+            manage_ignored_open: false,
+            ignored_serial_input: String::new(),
+            backups: Vec::new(),
+        }
+    }
+
+    /// Syncs the displayed preferences with the active config.
+    pub fn set_app_config(&mut self, config: &AppConfig) {
+        self.compact_results = config.compact_results;
+        self.persist_results = config.persist_results;
+        self.backup_on_save = config.backup_on_save;
+        self.temperature_unit = config.temperature_unit;
+        self.expand_problem_groups = config.expand_problem_groups;
+        self.collapse_healthy_groups = config.collapse_healthy_groups;
+        self.reverse_dns = config.reverse_dns;
+        self.notify_first_discovery = config.notify_first_discovery;
+        self.schema_upgrade_notify = config.schema_upgrade_notify;
+        self.browser_command = config.browser_command.clone().unwrap_or_default();
+        self.eol_firmwares = config.eol_firmwares.join(", ");
+        self.alert_cooldown_minutes = (config.alert_cooldown_seconds / 60).to_string();
+        self.fetch_timeout_seconds = config.detail_fetch_timeout_seconds.to_string();
+        self.completion_webhook = config.completion_webhook.clone().unwrap_or_default();
+        self.post_scan_command = config.post_scan_command.clone().unwrap_or_default();
+        self.user_agent = config.custom_user_agent.clone().unwrap_or_default();
+        self.proxy = config.proxy.clone().unwrap_or_default();
+        self.ignored_serials = config.ignored_serial_numbers.iter().cloned().collect();
+        self.ignored_serials.sort();
+    }
+
+    pub fn set_manage_ignored_open(&mut self, open: bool) {
+        self.manage_ignored_open = open;
+    }
+
+    pub fn set_ignored_serial_input(&mut self, serial: String) {
+        self.ignored_serial_input = serial;
+    }
+
+    /// Takes the typed serial number for adding to the ignore list, if any.
+    pub fn take_ignored_serial(&mut self) -> Option<String> {
+        let serial = std::mem::take(&mut self.ignored_serial_input);
+        let serial = serial.trim();
+        (!serial.is_empty()).then(|| serial.to_string())
+    }
+
+    pub fn set_browser_command(&mut self, command: String) {
+        self.browser_command = command;
+    }
+
+    /// The typed browser command, or `None` to use the system opener.
+    pub fn browser_command(&self) -> Option<String> {
+        let command = self.browser_command.trim();
+        (!command.is_empty()).then(|| command.to_string())
+    }
+
+    pub fn set_eol_firmwares(&mut self, versions: String) {
+        self.eol_firmwares = versions;
+    }
+
+    /// The typed EOL firmware versions, without blanks or duplicates.
+    pub fn eol_firmwares(&self) -> Vec<String> {
+        let mut versions: Vec<String> = Vec::new();
+        for version in self.eol_firmwares.split(',').map(str::trim) {
+            if !version.is_empty() && !versions.iter().any(|v| v == version) {
+                versions.push(version.to_string());
+            }
+        }
+        versions
+    }
+
+    pub fn set_alert_cooldown_minutes(&mut self, minutes: String) {
+        if minutes.chars().all(|c| c.is_ascii_digit()) {
+            self.alert_cooldown_minutes = minutes;
+        }
+    }
+
+    /// The typed alert cooldown converted to seconds, or `None` if it isn't a number.
+    pub fn alert_cooldown_seconds(&self) -> Option<u64> {
+        self.alert_cooldown_minutes
+            .parse::<u64>()
+            .ok()
+            .map(|minutes| minutes.saturating_mul(60))
+    }
+
+    pub fn set_completion_webhook(&mut self, url: String) {
+        self.completion_webhook = url;
+    }
+
+    /// The typed webhook URL, or `None` to turn the webhook off.
+    pub fn completion_webhook(&self) -> Option<String> {
+        let url = self.completion_webhook.trim();
+        (!url.is_empty()).then(|| url.to_string())
+    }
+
+    pub fn set_post_scan_command(&mut self, command: String) {
+        self.post_scan_command = command;
+    }
+
+    /// The typed post-scan command, or `None` to turn it off.
+    pub fn post_scan_command(&self) -> Option<String> {
+        let command = self.post_scan_command.trim();
+        (!command.is_empty()).then(|| command.to_string())
+    }
+
+    pub fn set_fetch_timeout_seconds(&mut self, seconds: String) {
+        if seconds.chars().all(|c| c.is_ascii_digit()) {
+            self.fetch_timeout_seconds = seconds;
+        }
+    }
+
+    /// The typed detail fetch timeout, or `None` unless it's a whole number of
+    /// seconds above zero.
+    pub fn fetch_timeout_seconds(&self) -> Option<u64> {
+        self.fetch_timeout_seconds
+            .parse::<u64>()
+            .ok()
+            .filter(|&seconds| seconds > 0)
+    }
+
+    pub fn set_user_agent(&mut self, agent: String) {
+        self.user_agent = agent;
+    }
+
+    /// The typed user agent, or `None` to use `DEFAULT_USER_AGENT`.
+    pub fn user_agent(&self) -> Option<String> {
+        let agent = self.user_agent.trim();
+        (!agent.is_empty()).then(|| agent.to_string())
+    }
+
+    pub fn set_proxy(&mut self, proxy: String) {
+        self.proxy = proxy;
+    }
+
+    /// The typed proxy URL, or `None` to connect directly.
+    pub fn proxy(&self) -> Option<String> {
+        let proxy = self.proxy.trim();
+        (!proxy.is_empty()).then(|| proxy.to_string())
+    }
+
+    /// Re-lists the config backups on disk.
+    pub fn refresh_backups(&mut self) {
+        self.backups = AppConfig::list_backups(CONFIG_FILE);
+    }
+
+    /// Flags whether the Network Configuration page holds edits that a reload would discard.
+    pub fn set_unsaved_network_changes(&mut self, unsaved: bool) {
+        self.unsaved_network_changes = unsaved;
+    }
+
+    pub fn view(&self) -> Element<'_, SettingsMessage> {
+        let header = container(
+            row![
+                column![
+                    theme::typography::title("Settings"),
+                    theme::typography::small("Application preferences and configuration file")
+                ]
+                .spacing(theme::spacing::XS),
+                Space::new().width(Length::Fill),
+            ]
+            .align_y(iced::alignment::Vertical::Center),
+        )
+        .style(theme::containers::header_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill);
+
+        let content = column![
+            self.view_config_file_section(),
+            self.view_display_section(),
+            self.view_alerts_section(),
+            self.view_network_section(),
+            self.view_ignored_section(),
+            self.view_storage_section(),
+            self.view_backups_section()
+        ]
+        .spacing(theme::spacing::MD)
+        .padding(theme::padding::MD);
+
+        let action_buttons = container(
+            row![
+                secondary_button("Close", None, Some(SettingsMessage::Close)),
+                Space::new().width(Length::Fill),
+            ]
+            .align_y(iced::alignment::Vertical::Center),
+        )
+        .style(theme::containers::header_adaptive)
+        .padding(theme::padding::MD)
+        .width(Length::Fill);
+
+        container(column![
+            header,
+            scrollable(content).height(Length::Fill),
+            action_buttons
+        ])
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_config_file_section(&self) -> Element<'_, SettingsMessage> {
+        let mut items = column![
+            theme::typography::heading("Configuration File"),
+            theme::typography::mono(CONFIG_FILE),
+            theme::typography::small(
+                "Re-read the file from disk to pick up changes made externally or by another instance"
+            ),
+        ]
+        .spacing(theme::spacing::SM);
+
+        if self.unsaved_network_changes {
+            items = items.push(
+                container(theme::typography::small(
+                    "Unsaved Network Configuration edits will be discarded by a reload",
+                ))
+                .style(theme::containers::warning_adaptive)
+                .padding([theme::padding::XS, theme::padding::SM]),
+            );
+        }
+
+        items = items.push(
+            checkbox(self.schema_upgrade_notify)
+                .label("Tell me what changed when an update upgrades this file")
+                .on_toggle(SettingsMessage::SetSchemaUpgradeNotify),
+        );
+
+        items = items.push(primary_button(
+            "Reload from Disk",
+            Some(theme::icons::refresh().into()),
+            Some(SettingsMessage::ReloadConfig),
+        ));
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
+    fn view_display_section(&self) -> Element<'_, SettingsMessage> {
+        let units = TempUnit::ALL.into_iter().fold(
+            row![theme::typography::body("Temperature unit")].spacing(theme::spacing::MD),
+            |units, unit| {
+                units.push(radio(
+                    unit.symbol(),
+                    unit,
+                    Some(self.temperature_unit),
+                    SettingsMessage::SetTemperatureUnit,
+                ))
+            },
+        );
+
+        let browser_command = row![
+            text_input("System default", &self.browser_command)
+                .on_input(SettingsMessage::BrowserCommandChanged)
+                .on_submit(SettingsMessage::SaveBrowserCommand)
+                .padding(theme::padding::SM),
+            secondary_button("Apply", None, Some(SettingsMessage::SaveBrowserCommand)),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let items = column![
+            theme::typography::heading("Display"),
+            units,
+            theme::typography::small(
+                "Health thresholds are always evaluated in Celsius; this only changes how temperatures are shown."
+            ),
+            theme::typography::body("Browser command"),
+            browser_command,
+            theme::typography::small(
+                "Used to open miner web UIs instead of the system default, e.g. \"wslview %u\" on WSL. %u is replaced with the URL."
+            ),
+            checkbox(self.expand_problem_groups)
+                .label("Expand groups with critical miners after a scan")
+                .on_toggle(SettingsMessage::SetExpandProblemGroups),
+            checkbox(self.collapse_healthy_groups)
+                .label("Collapse fully healthy groups after a scan")
+                .on_toggle(SettingsMessage::SetCollapseHealthyGroups),
+        ]
+        .spacing(theme::spacing::SM);
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
+    fn view_alerts_section(&self) -> Element<'_, SettingsMessage> {
+        let cooldown = row![
+            theme::typography::body("Alert cooldown"),
+            text_input("60", &self.alert_cooldown_minutes)
+                .on_input(SettingsMessage::AlertCooldownChanged)
+                .on_submit(SettingsMessage::SaveAlertCooldown)
+                .padding(theme::padding::SM)
+                .width(Length::Fixed(80.0)),
+            theme::typography::body("minutes"),
+            secondary_button(
+                "Apply",
+                None,
+                self.alert_cooldown_seconds()
+                    .map(|_| SettingsMessage::SaveAlertCooldown),
+            ),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let webhook = row![
+            text_input("Off", &self.completion_webhook)
+                .on_input(SettingsMessage::CompletionWebhookChanged)
+                .on_submit(SettingsMessage::SaveCompletionWebhook)
+                .padding(theme::padding::SM),
+            secondary_button("Apply", None, Some(SettingsMessage::SaveCompletionWebhook)),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let post_scan_command = row![
+            text_input("Off", &self.post_scan_command)
+                .on_input(SettingsMessage::PostScanCommandChanged)
+                .on_submit(SettingsMessage::SavePostScanCommand)
+                .padding(theme::padding::SM),
+            secondary_button("Apply", None, Some(SettingsMessage::SavePostScanCommand)),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let eol_firmwares = row![
+            text_input("None", &self.eol_firmwares)
+                .on_input(SettingsMessage::EolFirmwaresChanged)
+                .on_submit(SettingsMessage::SaveEolFirmwares)
+                .padding(theme::padding::SM),
+            secondary_button("Apply", None, Some(SettingsMessage::SaveEolFirmwares)),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let items = column![
+            theme::typography::heading("Alerts"),
+            cooldown,
+            theme::typography::small(
+                "Critical issues found when a miner's details are fetched raise a notification. A miner that keeps flapping won't alert again for the same issue until the cooldown has passed."
+            ),
+            checkbox(self.notify_first_discovery)
+                .label("Notify when a scan finds its first miner")
+                .on_toggle(SettingsMessage::SetNotifyFirstDiscovery),
+            theme::typography::small(
+                "Shows a notification in the app and on the desktop, so a long scan of a sparse network can run in the background."
+            ),
+            theme::typography::body("Scan completion webhook"),
+            webhook,
+            theme::typography::small(
+                "Optional. After every completed scan, a JSON summary with the miners found, the number with critical issues and per-group counts is POSTed to this URL, e.g. a Slack or Discord incoming webhook relay. Leave empty to turn it off."
+            ),
+            theme::typography::body("Post-scan command"),
+            post_scan_command,
+            theme::typography::small(
+                "Optional. After every completed scan this command runs with the results as JSON on its standard input, for scripts such as inventory sync or ticketing. Arguments are split on spaces and no shell is used. Its output and failures are written to the log. Leave empty to turn it off."
+            ),
+            theme::typography::small(
+                "Warning: this runs any program you enter, with your user's permissions. Only use commands you trust."
+            )
+            .color(theme::colors::WARNING),
+            theme::typography::body("End-of-life firmware versions"),
+            eol_firmwares,
+            theme::typography::small(
+                "Comma-separated. Miners running these versions, or releases under them (\"2022.1\" also covers \"2022.1.3\"), get an EOL badge and are listed under EOL Firmware on the dashboard."
+            ),
+        ]
+        .spacing(theme::spacing::SM);
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
+    fn view_network_section(&self) -> Element<'_, SettingsMessage> {
+        let user_agent = row![
+            text_input(DEFAULT_USER_AGENT, &self.user_agent)
+                .on_input(SettingsMessage::UserAgentChanged)
+                .on_submit(SettingsMessage::SaveUserAgent)
+                .padding(theme::padding::SM),
+            secondary_button("Apply", None, Some(SettingsMessage::SaveUserAgent)),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let fetch_timeout = row![
+            theme::typography::body("Detail fetch timeout"),
+            text_input("15", &self.fetch_timeout_seconds)
+                .on_input(SettingsMessage::FetchTimeoutChanged)
+                .on_submit(SettingsMessage::SaveFetchTimeout)
+                .padding(theme::padding::SM)
+                .width(Length::Fixed(80.0)),
+            theme::typography::body("seconds"),
+            secondary_button(
+                "Apply",
+                None,
+                self.fetch_timeout_seconds()
+                    .map(|_| SettingsMessage::SaveFetchTimeout),
+            ),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+
+        let proxy = row![
+            text_input("Off", &self.proxy)
+                .on_input(SettingsMessage::ProxyChanged)
+                .on_submit(SettingsMessage::SaveProxy)
+                .padding(theme::padding::SM),
+            secondary_button("Apply", None, Some(SettingsMessage::SaveProxy)),
+        ]
+        .spacing(theme::spacing::SM)
+        .align_y(iced::alignment::Vertical::Center);
+        let proxy_error = self
+            .proxy()
+            .and_then(|proxy| validate_proxy_url(&proxy).err())
+            .map(|e| theme::typography::small(e).color(theme::colors::DANGER));
+
+        let items = column![
+            theme::typography::heading("Network"),
+            theme::typography::body("User agent"),
+            user_agent,
+            theme::typography::small(
+                "Sent with HTTP requests made by the app, for firewalls that block unknown clients. Leave empty to use the default. Some firmware versions identify clients by user agent, so changing it may affect miner detection."
+            ),
+            fetch_timeout,
+            theme::typography::small(
+                "How long the device detail view waits for a miner's full data before showing an error."
+            ),
+            theme::typography::body("Proxy"),
+            proxy,
+        ]
+        .push(proxy_error)
+        .push(theme::typography::small(
+            "HTTP or SOCKS5 proxy for the app's own HTTP requests, such as the completion webhook, e.g. socks5://jump-host:1080. Scans and miner controls connect directly; the miner library doesn't support proxies yet.",
+        ))
+        .push(
+            checkbox(self.reverse_dns)
+                .label("Look up reverse DNS names of discovered miners")
+                .on_toggle(SettingsMessage::SetReverseDns),
+        )
+        .push(theme::typography::small(
+            "Shows each miner's PTR name next to its IP, which can differ from the hostname the miner reports. Adds a DNS query per miner after each scan.",
+        ))
+        .spacing(theme::spacing::SM);
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
+    fn view_ignored_section(&self) -> Element<'_, SettingsMessage> {
+        let summary = match self.ignored_serials.len() {
+            0 => "No miners are ignored".to_string(),
+            1 => "1 serial number is ignored".to_string(),
+            count => format!("{count} serial numbers are ignored"),
+        };
+
+        let toggle = if self.manage_ignored_open {
+            secondary_button(
+                "Done",
+                None,
+                Some(SettingsMessage::SetManageIgnoredOpen(false)),
+            )
+        } else {
+            secondary_button(
+                "Manage Ignored",
+                None,
+                Some(SettingsMessage::SetManageIgnoredOpen(true)),
+            )
+        };
+
+        let mut items = column![
+            theme::typography::heading("Ignored Miners"),
+            row![
+                theme::typography::body(summary),
+                Space::new().width(Length::Fill),
+                toggle,
+            ]
+            .align_y(iced::alignment::Vertical::Center),
+            theme::typography::small(
+                "Miners with these serial numbers, such as test or demo units, are left out of the dashboard totals and listed separately under Ignored Miners."
+            ),
+        ]
+        .spacing(theme::spacing::SM);
+
+        if self.manage_ignored_open {
+            for serial in &self.ignored_serials {
+                items = items.push(
+                    row![
+                        theme::typography::mono(serial),
+                        Space::new().width(Length::Fill),
+                        danger_button(
+                            "Remove",
+                            None,
+                            Some(SettingsMessage::RemoveIgnoredSerial(serial.clone())),
+                        ),
+                    ]
+                    .spacing(theme::spacing::MD)
+                    .align_y(iced::alignment::Vertical::Center),
+                );
+            }
+
+            let has_input = !self.ignored_serial_input.trim().is_empty();
+            items = items.push(
+                row![
+                    text_input("Serial number", &self.ignored_serial_input)
+                        .on_input(SettingsMessage::IgnoredSerialChanged)
+                        .on_submit(SettingsMessage::AddIgnoredSerial)
+                        .padding(theme::padding::SM),
+                    secondary_button(
+                        "Add",
+                        None,
+                        has_input.then_some(SettingsMessage::AddIgnoredSerial),
+                    ),
+                ]
+                .spacing(theme::spacing::SM)
+                .align_y(iced::alignment::Vertical::Center),
+            );
+        }
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
+    fn view_storage_section(&self) -> Element<'_, SettingsMessage> {
+        let items = column![
+            theme::typography::heading("Storage"),
+            checkbox(self.persist_results)
+                .label("Save scan results to disk")
+                .on_toggle(SettingsMessage::SetPersistResults),
+            theme::typography::small(
+                "When off, results, scan history and miner API versions are kept in memory for this session only and cleared from the config file. Scan groups and settings are still saved."
+            ),
+            checkbox(self.compact_results)
+                .label("Write scan results as compact JSON")
+                .on_toggle(SettingsMessage::SetCompactResults),
+            theme::typography::small(
+                "Reduces file size and save time for large farms. Scan groups stay pretty-printed for hand editing."
+            ),
+            secondary_button(
+                "Export Asset Register",
+                None,
+                Some(SettingsMessage::ExportAssetRegister),
+            ),
+            theme::typography::small(
+                "Writes a CSV inventory of every miner from the last scan, with identity, health, averages and first and last seen dates, for audits."
+            ),
+        ]
+        .spacing(theme::spacing::SM);
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+
+    fn view_backups_section(&self) -> Element<'_, SettingsMessage> {
+        let mut items = column![
+            theme::typography::heading("Backups"),
+            checkbox(self.backup_on_save)
+                .label("Keep rolling backups when saving")
+                .on_toggle(SettingsMessage::SetBackupOnSave),
+        ]
+        .spacing(theme::spacing::SM);
+
+        if self.backups.is_empty() {
+            items = items.push(theme::typography::small("No backups found"));
+        }
+
+        for backup in &self.backups {
+            let modified = backup
+                .modified
+                .map(|time| {
+                    chrono::DateTime::<chrono::Local>::from(time)
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_else(|| "Unknown time".to_string());
+
+            items = items.push(
+                row![
+                    theme::typography::mono(format!("#{}", backup.index)),
+                    theme::typography::body(modified),
+                    Space::new().width(Length::Fill),
+                    secondary_button(
+                        "Restore",
+                        None,
+                        Some(SettingsMessage::RestoreBackup(backup.path.clone())),
+                    ),
+                ]
+                .spacing(theme::spacing::MD)
+                .align_y(iced::alignment::Vertical::Center),
+            );
+        }
+
+        container(items)
+            .style(theme::containers::card_adaptive)
+            .padding(theme::padding::MD)
+            .width(Length::Fill)
+            .into()
+    }
+}
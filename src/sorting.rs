@@ -1,7 +1,11 @@
+use crate::config::ScanGroup;
+use crate::health::HealthStatus;
 use asic_rs::data::miner::MinerData;
-use std::cmp::Ordering;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SortDirection {
     Ascending,
     Descending,
@@ -18,13 +22,73 @@ impl SortDirection {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SortColumn {
     IpAddress,
     Model,
     Make,
     Firmware,
     FirmwareVersion,
+    Algorithm,
+}
+
+/// Order in which scan groups are listed on the dashboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupSortKey {
+    /// The order groups were created in
+    #[default]
+    Config,
+    Name,
+    /// Most miners first
+    MinerCount,
+    /// Worst health first
+    Health,
+}
+
+impl GroupSortKey {
+    pub const ALL: [Self; 4] = [Self::Config, Self::Name, Self::MinerCount, Self::Health];
+}
+
+impl std::fmt::Display for GroupSortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Config => "Config order",
+            Self::Name => "Name",
+            Self::MinerCount => "Miner count",
+            Self::Health => "Health",
+        })
+    }
+}
+
+/// Returns the groups in display order without reordering the config itself.
+/// Ties keep config order.
+pub fn sort_groups_by_field<'a>(
+    groups: &'a [ScanGroup],
+    results: &HashMap<String, Vec<MinerData>>,
+    key: GroupSortKey,
+) -> Vec<&'a ScanGroup> {
+    let mut sorted: Vec<&ScanGroup> = groups.iter().collect();
+    let miners = |group: &ScanGroup| results.get(&group.name).map(Vec::as_slice).unwrap_or(&[]);
+
+    match key {
+        GroupSortKey::Config => {}
+        GroupSortKey::Name => {
+            sorted.sort_by_cached_key(|group| group.name.to_lowercase());
+        }
+        GroupSortKey::MinerCount => {
+            sorted.sort_by_key(|group| Reverse(miners(group).len()));
+        }
+        GroupSortKey::Health => {
+            sorted.sort_by_cached_key(|group| {
+                Reverse(
+                    HealthStatus::worst(miners(group).iter().map(HealthStatus::from_miner_data))
+                        .rank(),
+                )
+            });
+        }
+    }
+
+    sorted
 }
 
 /// Sorts a slice of miners by the specified column and direction.
@@ -60,6 +124,13 @@ pub fn sort_miners_by_column(
                 compare_with_direction(a_firmware, b_firmware, direction)
             });
         }
+        SortColumn::Algorithm => {
+            miners.sort_by(|a, b| {
+                let a_algo = format!("{}", a.device_info.algo);
+                let b_algo = format!("{}", b.device_info.algo);
+                compare_with_direction(a_algo, b_algo, direction)
+            });
+        }
         SortColumn::FirmwareVersion => {
             miners.sort_by(|a, b| {
                 let a_version = a.firmware_version.as_deref().unwrap_or("");
@@ -76,3 +147,32 @@ fn compare_with_direction<T: Ord>(a: T, b: T, direction: SortDirection) -> Order
         SortDirection::Descending => b.cmp(&a),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_groups_keeps_config_order() {
+        let groups = vec![
+            ScanGroup::new("farm b".to_string(), "10.0.1.0/24".to_string()),
+            ScanGroup::new("Farm A".to_string(), "10.0.0.0/24".to_string()),
+            ScanGroup::new("Farm C".to_string(), "10.0.2.0/24".to_string()),
+        ];
+        let names = |key| {
+            sort_groups_by_field(&groups, &HashMap::new(), key)
+                .iter()
+                .map(|group| group.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(GroupSortKey::Config), ["farm b", "Farm A", "Farm C"]);
+        assert_eq!(names(GroupSortKey::Name), ["Farm A", "farm b", "Farm C"]);
+        // Without results every group ties, so config order is kept
+        assert_eq!(
+            names(GroupSortKey::MinerCount),
+            ["farm b", "Farm A", "Farm C"]
+        );
+        assert_eq!(groups[0].name, "farm b");
+    }
+}
@@ -0,0 +1,114 @@
+use asic_rs::data::device::MinerMake;
+use asic_rs::data::miner::MinerData;
+use std::collections::HashMap;
+
+/// Counts miners per label, sorted by count (descending) then label.
+pub fn breakdown_by<'a>(
+    miners: impl IntoIterator<Item = &'a MinerData>,
+    label: impl Fn(&MinerData) -> String,
+) -> Vec<(String, usize)> {
+    sorted_counts(miners.into_iter().map(label))
+}
+
+/// Miner counts per hashing algorithm, e.g. `[("SHA256", 40), ("Scrypt", 3)]`.
+pub fn algorithm_breakdown<'a>(
+    miners: impl IntoIterator<Item = &'a MinerData>,
+) -> Vec<(String, usize)> {
+    breakdown_by(miners, |miner| miner.device_info.algo.to_string())
+}
+
+/// Miner counts per make, sorted by count (descending) then make name.
+pub fn make_breakdown<'a>(
+    miners: impl IntoIterator<Item = &'a MinerData>,
+) -> Vec<(MinerMake, usize)> {
+    tally_makes(miners.into_iter().map(|miner| miner.device_info.make))
+}
+
+fn tally_makes(makes: impl Iterator<Item = MinerMake>) -> Vec<(MinerMake, usize)> {
+    let mut counts: HashMap<MinerMake, usize> = HashMap::new();
+    for make in makes {
+        *counts.entry(make).or_default() += 1;
+    }
+
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|(a_make, a_count), (b_make, b_count)| {
+        b_count
+            .cmp(a_count)
+            .then_with(|| a_make.to_string().cmp(&b_make.to_string()))
+    });
+    counts
+}
+
+/// Formats a breakdown as "SHA256: 40 · Scrypt: 3".
+pub fn format_breakdown(breakdown: &[(String, usize)]) -> String {
+    breakdown
+        .iter()
+        .map(|(label, count)| format!("{label}: {count}"))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+fn sorted_counts(labels: impl Iterator<Item = String>) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for label in labels {
+        *counts.entry(label).or_default() += 1;
+    }
+
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|(a_label, a_count), (b_label, b_count)| {
+        b_count.cmp(a_count).then_with(|| a_label.cmp(b_label))
+    });
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_counts_and_format() {
+        let labels = ["Scrypt", "SHA256", "SHA256", "X11", "SHA256", "Scrypt"];
+        let counts = sorted_counts(labels.iter().map(|s| s.to_string()));
+
+        assert_eq!(
+            counts,
+            vec![
+                ("SHA256".to_string(), 3),
+                ("Scrypt".to_string(), 2),
+                ("X11".to_string(), 1)
+            ]
+        );
+        assert_eq!(format_breakdown(&counts), "SHA256: 3 · Scrypt: 2 · X11: 1");
+        assert_eq!(format_breakdown(&[]), "");
+    }
+
+    #[test]
+    fn test_tally_makes() {
+        let makes = [
+            MinerMake::WhatsMiner,
+            MinerMake::AntMiner,
+            MinerMake::AntMiner,
+            MinerMake::Bitaxe,
+            MinerMake::AntMiner,
+            MinerMake::WhatsMiner,
+            MinerMake::AvalonMiner,
+        ];
+
+        let counts = tally_makes(makes.into_iter());
+        assert_eq!(counts[0], (MinerMake::AntMiner, 3));
+        assert_eq!(counts[1], (MinerMake::WhatsMiner, 2));
+        // Ties are ordered by name
+        let singles: Vec<String> = counts[2..]
+            .iter()
+            .map(|(make, _)| make.to_string())
+            .collect();
+        let mut sorted = singles.clone();
+        sorted.sort();
+        assert_eq!(singles, sorted);
+        assert_eq!(
+            counts.iter().map(|(_, count)| count).sum::<usize>(),
+            makes.len()
+        );
+        assert!(tally_makes(std::iter::empty()).is_empty());
+    }
+}
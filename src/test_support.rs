@@ -0,0 +1,62 @@
+//! Fixtures shared by the unit tests.
+
+use asic_rs::data::device::{
+    DeviceInfo, HashAlgorithm, MinerFirmware, MinerHardware, MinerMake, MinerModel,
+};
+use asic_rs::data::hashrate::{HashRate, HashRateUnit};
+use asic_rs::data::miner::MinerData;
+use std::net::IpAddr;
+
+/// A SHA-256 hashrate in TH/s.
+pub fn terahash(value: f64) -> HashRate {
+    HashRate {
+        value,
+        unit: HashRateUnit::TeraHash,
+        algo: "SHA256".to_string(),
+    }
+}
+
+/// A mining S19 at `ip` with no readings.
+pub fn test_miner(ip: IpAddr) -> MinerData {
+    MinerData {
+        schema_version: String::new(),
+        timestamp: 0,
+        ip,
+        mac: None,
+        device_info: DeviceInfo {
+            make: MinerMake::AntMiner,
+            model: MinerModel::S19,
+            hardware: MinerHardware {
+                chips: None,
+                fans: None,
+                boards: None,
+            },
+            firmware: MinerFirmware::Stock,
+            algo: HashAlgorithm::SHA256,
+        },
+        serial_number: None,
+        hostname: None,
+        api_version: None,
+        firmware_version: None,
+        control_board_version: None,
+        expected_hashboards: None,
+        hashboards: Vec::new(),
+        hashrate: None,
+        expected_hashrate: None,
+        expected_chips: None,
+        total_chips: None,
+        expected_fans: None,
+        fans: Vec::new(),
+        psu_fans: Vec::new(),
+        average_temperature: None,
+        fluid_temperature: None,
+        wattage: None,
+        wattage_limit: None,
+        efficiency: None,
+        light_flashing: None,
+        messages: Vec::new(),
+        uptime: None,
+        is_mining: true,
+        pools: Vec::new(),
+    }
+}
@@ -46,6 +46,20 @@ pub const BORDER_DEFAULT: Color = Color::from_rgba(1.0, 1.0, 1.0, 0.12);
 pub const BORDER_STRONG: Color = Color::from_rgba(1.0, 1.0, 1.0, 0.18);
 pub const BORDER_FOCUS: Color = PRIMARY;
 
+// Light surfaces - used by the adaptive container styles when a light theme is active
+pub const LIGHT_BACKGROUND_ELEVATED: Color = Color::from_rgb(0.96, 0.96, 0.97); // #F5F5F7
+pub const LIGHT_BACKGROUND_CARD: Color = Color::from_rgb(1.0, 1.0, 1.0); // #FFFFFF
+pub const LIGHT_BACKGROUND_INPUT: Color = Color::from_rgb(0.93, 0.94, 0.95); // #EDEFF2
+pub const LIGHT_SURFACE_OVERLAY: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.04);
+pub const LIGHT_TEXT_PRIMARY: Color = Color::from_rgb(0.11, 0.12, 0.13); // #1C1F21
+pub const LIGHT_BORDER_SUBTLE: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.06);
+pub const LIGHT_BORDER_DEFAULT: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.12);
+pub const LIGHT_BORDER_STRONG: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.18);
+// Status tints need more opacity to read against white
+pub const LIGHT_SUCCESS_DIM: Color = Color::from_rgba(0.0, 0.8, 0.4, 0.22);
+pub const LIGHT_WARNING_DIM: Color = Color::from_rgba(1.0, 0.65, 0.0, 0.22);
+pub const LIGHT_DANGER_DIM: Color = Color::from_rgba(0.95, 0.26, 0.21, 0.22);
+
 // Data Visualization (for hashrate, temp, etc.)
 pub const DATA_BLUE: Color = Color::from_rgb(0.25, 0.62, 0.90); // #3F9FE6
 pub const DATA_CYAN: Color = Color::from_rgb(0.0, 0.82, 0.92); // #00D1EA
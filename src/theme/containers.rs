@@ -179,3 +179,260 @@ pub fn tooltip(_theme: &Theme) -> container::Style {
         ..Default::default()
     }
 }
+
+/// Topology grid cell - dark slot for an address in the scanned range
+pub fn grid_cell(_theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(colors::BACKGROUND_INPUT)),
+        border: Border {
+            radius: 3.0.into(),
+            width: 1.0,
+            color: colors::BORDER_SUBTLE,
+        },
+        ..Default::default()
+    }
+}
+
+/// Topology grid cell outside the scanned range
+pub fn grid_cell_outside(_theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(colors::SURFACE_OVERLAY_10)),
+        border: Border {
+            radius: 3.0.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        ..Default::default()
+    }
+}
+
+// Adaptive variants - pick the dark style above or a light counterpart from
+// the active theme's palette, so containers stay legible in light themes
+
+/// Light counterpart of a dark style: same shape and accents, with the
+/// surface, border and text swapped for ones readable on a light background.
+fn on_light(
+    dark: container::Style,
+    surface: Option<Color>,
+    border: Option<Color>,
+) -> container::Style {
+    container::Style {
+        background: surface.map(Background::Color).or(dark.background),
+        border: Border {
+            color: border.unwrap_or(dark.border.color),
+            ..dark.border
+        },
+        shadow: Shadow {
+            color: colors::SHADOW_LIGHT,
+            ..dark.shadow
+        },
+        text_color: dark.text_color.map(|_| colors::LIGHT_TEXT_PRIMARY),
+        ..dark
+    }
+}
+
+fn is_dark(theme: &Theme) -> bool {
+    theme.extended_palette().is_dark
+}
+
+pub fn card_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return card(theme);
+    }
+    container::Style {
+        border: Border {
+            width: 1.0,
+            ..card(theme).border
+        },
+        ..on_light(
+            card(theme),
+            Some(colors::LIGHT_BACKGROUND_CARD),
+            Some(colors::LIGHT_BORDER_DEFAULT),
+        )
+    }
+}
+
+pub fn header_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return header(theme);
+    }
+    on_light(header(theme), Some(colors::LIGHT_BACKGROUND_ELEVATED), None)
+}
+
+pub fn success_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return success(theme);
+    }
+    on_light(success(theme), Some(colors::LIGHT_SUCCESS_DIM), None)
+}
+
+pub fn error_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return error(theme);
+    }
+    on_light(error(theme), Some(colors::LIGHT_DANGER_DIM), None)
+}
+
+pub fn warning_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return warning(theme);
+    }
+    on_light(warning(theme), Some(colors::LIGHT_WARNING_DIM), None)
+}
+
+pub fn badge_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return badge(theme);
+    }
+    on_light(
+        badge(theme),
+        Some(colors::LIGHT_SURFACE_OVERLAY),
+        Some(colors::LIGHT_BORDER_DEFAULT),
+    )
+}
+
+pub fn tooltip_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return tooltip(theme);
+    }
+    on_light(
+        tooltip(theme),
+        Some(colors::LIGHT_BACKGROUND_CARD),
+        Some(colors::LIGHT_BORDER_STRONG),
+    )
+}
+
+pub fn grid_cell_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return grid_cell(theme);
+    }
+    on_light(
+        grid_cell(theme),
+        Some(colors::LIGHT_BACKGROUND_INPUT),
+        Some(colors::LIGHT_BORDER_SUBTLE),
+    )
+}
+
+pub fn grid_cell_outside_adaptive(theme: &Theme) -> container::Style {
+    if is_dark(theme) {
+        return grid_cell_outside(theme);
+    }
+    on_light(
+        grid_cell_outside(theme),
+        Some(colors::LIGHT_SURFACE_OVERLAY),
+        None,
+    )
+}
+
+// Scan status indicator - a pill distinct from the generic status boxes, so
+// the live scan state reads differently from alerts and badges
+
+/// One full fade of the scanning indicator's border
+const SCAN_PULSE_PERIOD_MS: u128 = 1600;
+
+/// Scanning indicator - accent pill whose border fades in and out. The pulse
+/// follows the clock, so it only animates while something keeps redrawing.
+pub fn scanning_in_progress(theme: &Theme) -> container::Style {
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or(0);
+    let phase = (millis % SCAN_PULSE_PERIOD_MS) as f32 / SCAN_PULSE_PERIOD_MS as f32;
+    scanning_pulse(theme, phase)
+}
+
+/// Scanning indicator at `phase` (0.0-1.0) of its pulse
+fn scanning_pulse(theme: &Theme, phase: f32) -> container::Style {
+    // 0 at the start and end of the cycle, 1 halfway
+    let strength = 0.5 - 0.5 * (phase * std::f32::consts::TAU).cos();
+    let border_color = Color {
+        a: 0.35 + 0.65 * strength,
+        ..colors::ACCENT
+    };
+    let dark = container::Style {
+        background: Some(Background::Color(colors::ACCENT_DIM)),
+        border: Border {
+            radius: 12.0.into(),
+            width: 2.0,
+            color: border_color,
+        },
+        shadow: Shadow {
+            color: Color {
+                a: 0.3 * strength,
+                ..colors::ACCENT
+            },
+            offset: Vector::new(0.0, 0.0),
+            blur_radius: 10.0,
+        },
+        text_color: Some(colors::TEXT_PRIMARY),
+        ..Default::default()
+    };
+    if is_dark(theme) {
+        dark
+    } else {
+        on_light(dark, None, None)
+    }
+}
+
+/// Scan complete indicator - flat success pill, without the glow of `success`
+pub fn scan_complete(theme: &Theme) -> container::Style {
+    let dark = container::Style {
+        background: Some(Background::Color(colors::SUCCESS_DIM)),
+        border: Border {
+            radius: 12.0.into(),
+            width: 2.0,
+            color: colors::SUCCESS,
+        },
+        text_color: Some(colors::TEXT_PRIMARY),
+        ..Default::default()
+    };
+    if is_dark(theme) {
+        dark
+    } else {
+        on_light(dark, Some(colors::LIGHT_SUCCESS_DIM), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_styles_follow_theme() {
+        let dark = crate::theme::theme();
+        assert_eq!(card_adaptive(&dark), card(&dark));
+        assert_eq!(warning_adaptive(&dark), warning(&dark));
+
+        let light = Theme::Light;
+        let card = card_adaptive(&light);
+        assert_eq!(
+            card.background,
+            Some(Background::Color(colors::LIGHT_BACKGROUND_CARD))
+        );
+        assert_eq!(card.text_color, Some(colors::LIGHT_TEXT_PRIMARY));
+        // Status accents are kept; only the tint and text change
+        let warning = warning_adaptive(&light);
+        assert_eq!(warning.border.color, colors::WARNING);
+        assert_eq!(warning.text_color, Some(colors::LIGHT_TEXT_PRIMARY));
+    }
+
+    #[test]
+    fn test_scan_indicator_styles() {
+        let dark = crate::theme::theme();
+        let faint = scanning_pulse(&dark, 0.0).border.color.a;
+        let bright = scanning_pulse(&dark, 0.5).border.color.a;
+        assert!(faint < bright);
+        assert!((scanning_pulse(&dark, 1.0).border.color.a - faint).abs() < 1e-4);
+
+        // Told apart from the generic status boxes by shape and weight
+        assert_ne!(scan_complete(&dark), success(&dark));
+        assert_ne!(
+            scan_complete(&dark).border.radius,
+            warning(&dark).border.radius
+        );
+        assert_eq!(
+            scan_complete(&Theme::Light).text_color,
+            Some(colors::LIGHT_TEXT_PRIMARY)
+        );
+    }
+}
@@ -26,5 +26,7 @@ pub mod colors;
 pub mod containers;
 pub mod icons;
 pub mod padding;
+pub mod progress_bars;
 pub mod spacing;
 pub mod typography;
+pub mod widgets;
@@ -0,0 +1,124 @@
+use super::colors;
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
+use iced::widget::progress_bar;
+use iced::{Background, Border, Color, Element, Length, Point, Rectangle, Renderer, Theme, mouse};
+
+/// Height of the inline detail bars (chip ratio, PSU load) in pixels
+pub const CHIP_BAR_HEIGHT: f32 = 8.0;
+
+/// Scan progress bar style - orange on the dark base, whatever the theme.
+/// Also used for other long-running operations such as firmware upgrades.
+pub fn scanning(_theme: &Theme) -> progress_bar::Style {
+    progress_bar::Style {
+        background: Background::Color(colors::BACKGROUND_BASE),
+        ..filled(colors::DATA_ORANGE)
+    }
+}
+
+/// Bar style for a value in its healthy range
+pub fn health_good(_theme: &Theme) -> progress_bar::Style {
+    filled(colors::SUCCESS)
+}
+
+/// Bar style for a value that needs attention
+pub fn health_warning(_theme: &Theme) -> progress_bar::Style {
+    filled(colors::WARNING)
+}
+
+/// Bar style for a value at or past its limit
+pub fn health_critical(_theme: &Theme) -> progress_bar::Style {
+    filled(colors::DANGER)
+}
+
+/// Working/expected chip ratio at or above which a board counts as healthy
+const CHIP_RATIO_GOOD: f32 = 0.95;
+/// Ratio below which a board is losing enough chips to need attention
+const CHIP_RATIO_FAIR: f32 = 0.90;
+
+/// Chip bar style - green above 95% working, yellow from 90%, red below
+pub fn chip_ratio(ratio: f32) -> impl Fn(&Theme) -> progress_bar::Style {
+    let color = if ratio > CHIP_RATIO_GOOD {
+        colors::CHIP_GOOD
+    } else if ratio >= CHIP_RATIO_FAIR {
+        colors::CHIP_FAIR
+    } else {
+        colors::CHIP_CRITICAL
+    };
+    move |_theme| filled(color)
+}
+
+/// Power draw above this share of the PSU limit leaves little headroom
+const PSU_LOAD_HIGH: f32 = 0.85;
+/// Power draw above this share of the PSU limit is at or past its rating
+const PSU_LOAD_CRITICAL: f32 = 0.95;
+
+/// PSU load bar style - green with headroom, yellow above 85%, red above 95%
+pub fn psu_load(fraction: f32) -> impl Fn(&Theme) -> progress_bar::Style {
+    let style: fn(&Theme) -> progress_bar::Style = if fraction > PSU_LOAD_CRITICAL {
+        health_critical
+    } else if fraction > PSU_LOAD_HIGH {
+        health_warning
+    } else {
+        health_good
+    };
+    style
+}
+
+fn filled(color: Color) -> progress_bar::Style {
+    progress_bar::Style {
+        background: Background::Color(colors::SURFACE_OVERLAY_20),
+        bar: Background::Color(color),
+        border: Border {
+            radius: 2.0.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+    }
+}
+
+/// Diagonally striped bar for values that aren't known, e.g. a board that
+/// doesn't report its expected chip count
+pub fn indeterminate<'a, Message: 'a>(height: f32) -> Element<'a, Message> {
+    Canvas::new(Stripes)
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .into()
+}
+
+struct Stripes;
+
+/// Horizontal distance between stripes in pixels
+const STRIPE_SPACING: f32 = 8.0;
+
+impl<Message> canvas::Program<Message> for Stripes {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        frame.fill_rectangle(Point::ORIGIN, bounds.size(), colors::SURFACE_OVERLAY_20);
+
+        // Start one bar-height early so the left edge is covered by the slant
+        let stripes = Path::new(|path| {
+            let mut x = -bounds.height;
+            while x < bounds.width {
+                path.move_to(Point::new(x, bounds.height));
+                path.line_to(Point::new(x + bounds.height, 0.0));
+                x += STRIPE_SPACING;
+            }
+        });
+        frame.stroke(
+            &stripes,
+            Stroke::default()
+                .with_color(colors::BORDER_STRONG)
+                .with_width(2.0),
+        );
+        vec![frame.into_geometry()]
+    }
+}
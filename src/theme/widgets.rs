@@ -0,0 +1,79 @@
+use super::colors;
+use crate::health::HealthStatus;
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path};
+use iced::{Color, Element, Length, Point, Rectangle, Renderer, Size, Theme, mouse};
+
+/// Diameter of the status dot in pixels
+pub const STATUS_DOT_SIZE: f32 = 10.0;
+
+/// Small filled circle colored by health status, for "online/offline" style indicators
+pub fn status_dot<Message: 'static>(status: HealthStatus) -> Element<'static, Message> {
+    Canvas::new(StatusDot { status })
+        .width(Length::Fixed(STATUS_DOT_SIZE))
+        .height(Length::Fixed(STATUS_DOT_SIZE))
+        .into()
+}
+
+struct StatusDot {
+    status: HealthStatus,
+}
+
+impl<Message> canvas::Program<Message> for StatusDot {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let dot = Path::circle(Point::new(radius, radius), radius);
+        frame.fill(&dot, self.status.color());
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Height of the inline gauge bar in pixels
+pub const GAUGE_BAR_HEIGHT: f32 = 3.0;
+
+/// Thin horizontal bar filled to `fraction` (clamped to 0..=1) over a dim track
+pub fn gauge_bar<'a, Message: 'a>(fraction: f32, color: Color) -> Element<'a, Message> {
+    Canvas::new(GaugeBar {
+        fraction: fraction.clamp(0.0, 1.0),
+        color,
+    })
+    .width(Length::Fill)
+    .height(Length::Fixed(GAUGE_BAR_HEIGHT))
+    .into()
+}
+
+struct GaugeBar {
+    fraction: f32,
+    color: Color,
+}
+
+impl<Message> canvas::Program<Message> for GaugeBar {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        frame.fill_rectangle(Point::ORIGIN, bounds.size(), colors::SURFACE_OVERLAY_20);
+        frame.fill_rectangle(
+            Point::ORIGIN,
+            Size::new(bounds.width * self.fraction, bounds.height),
+            self.color,
+        );
+        vec![frame.into_geometry()]
+    }
+}
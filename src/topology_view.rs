@@ -0,0 +1,292 @@
+use crate::config::ScanGroup;
+use crate::health::HealthStatus;
+use crate::network::ipv4_spans;
+use crate::theme;
+use crate::ui_helpers::secondary_button;
+use asic_rs::data::miner::MinerData;
+use iced::widget::{Space, button, center, column, container, pick_list, row, scrollable, tooltip};
+use iced::{Element, Length};
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, Ipv4Addr};
+use std::ops::RangeInclusive;
+
+/// Cells per row and column; 16×16 covers every last octet of a /24
+const GRID_SIZE: usize = 16;
+const CELL_SIZE: f32 = 28.0;
+
+#[derive(Debug, Clone)]
+pub enum TopologyMessage {
+    Close,
+    SelectGroup(String),
+    OpenDeviceDetail(IpAddr),
+}
+
+/// Last-octet runs of a range bucketed by /24, keyed by the first three octets
+type Subnets = BTreeMap<[u8; 3], Vec<RangeInclusive<u8>>>;
+
+/// Grid of a group's /24 showing which last-octet slots hold discovered miners
+pub struct TopologyView {
+    selected_group: Option<String>,
+    subnets: Result<Subnets, String>,
+}
+
+impl TopologyView {
+    pub fn new() -> Self {
+        Self {
+            selected_group: None,
+            subnets: Ok(Subnets::new()),
+        }
+    }
+
+    pub fn selected_group(&self) -> Option<&str> {
+        self.selected_group.as_deref()
+    }
+
+    /// Selects a group and buckets its range by /24. Done once here rather
+    /// than per frame, and from the range's spans rather than its hosts, since
+    /// a /8 holds millions of addresses.
+    pub fn select_group(&mut self, group: &ScanGroup) {
+        self.selected_group = Some(group.name.clone());
+        self.subnets = ipv4_spans(&group.network_range)
+            .map(|spans| bucket_by_subnet(&spans))
+            .map_err(|e| e.to_string());
+    }
+
+    pub fn view<'a>(
+        &'a self,
+        groups: &'a [ScanGroup],
+        results: &'a HashMap<String, Vec<MinerData>>,
+    ) -> Element<'a, TopologyMessage> {
+        let group_names: Vec<String> = groups.iter().map(|g| g.name.clone()).collect();
+
+        let header = container(
+            row![
+                secondary_button(
+                    "Back",
+                    Some(theme::icons::back().into()),
+                    Some(TopologyMessage::Close),
+                ),
+                Space::new().width(theme::spacing::SM),
+                theme::typography::title("Topology"),
+                Space::new().width(Length::Fill),
+                pick_list(
+                    group_names,
+                    self.selected_group.clone(),
+                    TopologyMessage::SelectGroup,
+                )
+                .placeholder("Select group"),
+            ]
+            .align_y(iced::Alignment::Center),
+        )
+        .style(theme::containers::header_adaptive)
+        .padding(theme::padding::SM)
+        .width(Length::Fill);
+
+        let miners = self
+            .selected_group
+            .as_ref()
+            .and_then(|name| results.get(name))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let body = match &self.subnets {
+            _ if self.selected_group.is_none() => {
+                center(theme::typography::body("Select a scan group to view")).into()
+            }
+            Err(error) => center(theme::typography::danger(error.clone())).into(),
+            Ok(subnets) => self.view_subnet(subnets, miners),
+        };
+
+        container(
+            column![header, body]
+                .spacing(theme::spacing::SM)
+                .padding(theme::padding::SM),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_subnet<'a>(
+        &'a self,
+        subnets: &Subnets,
+        miners: &'a [MinerData],
+    ) -> Element<'a, TopologyMessage> {
+        let Some(subnet) = busiest_subnet(subnets, miners) else {
+            return center(theme::typography::body(
+                "This range has no IPv4 addresses to show",
+            ))
+            .into();
+        };
+        let in_range = &subnets[&subnet];
+        let [a, b, c] = subnet;
+
+        let by_octet: HashMap<u8, &MinerData> = miners
+            .iter()
+            .filter_map(|miner| match miner.ip {
+                IpAddr::V4(ip) if ip.octets()[..3] == subnet => Some((ip.octets()[3], miner)),
+                _ => None,
+            })
+            .collect();
+
+        let mut info = column![theme::typography::small(format!(
+            "{a}.{b}.{c}.0/24 · {} of {} addresses hold miners",
+            by_octet.len(),
+            in_range.iter().map(|run| run.len()).sum::<usize>()
+        ))]
+        .spacing(theme::spacing::XS);
+
+        if subnets.len() > 1 {
+            info = info.push(
+                container(theme::typography::small(format!(
+                    "This range spans {} /24 subnets; showing a sample of the one with the most miners",
+                    subnets.len()
+                )))
+                .style(theme::containers::warning_adaptive)
+                .padding([theme::padding::XS, theme::padding::SM]),
+            );
+        }
+
+        let grid = (0..GRID_SIZE).fold(column![].spacing(2), |grid, grid_row| {
+            let cells = (0..GRID_SIZE).fold(row![].spacing(2), |cells, grid_col| {
+                let octet = (grid_row * GRID_SIZE + grid_col) as u8;
+                cells.push(view_cell(
+                    Ipv4Addr::new(a, b, c, octet),
+                    in_range.iter().any(|run| run.contains(&octet)),
+                    by_octet.get(&octet).copied(),
+                ))
+            });
+            grid.push(cells)
+        });
+
+        scrollable(
+            column![info, grid]
+                .spacing(theme::spacing::MD)
+                .padding(theme::padding::SM),
+        )
+        .height(Length::Fill)
+        .into()
+    }
+}
+
+fn view_cell<'a>(
+    ip: Ipv4Addr,
+    in_range: bool,
+    miner: Option<&'a MinerData>,
+) -> Element<'a, TopologyMessage> {
+    let Some(miner) = miner else {
+        let style = if in_range {
+            theme::containers::grid_cell_adaptive
+        } else {
+            theme::containers::grid_cell_outside_adaptive
+        };
+        return with_tooltip(
+            container(Space::new())
+                .style(style)
+                .width(CELL_SIZE)
+                .height(CELL_SIZE)
+                .into(),
+            ip.to_string(),
+        );
+    };
+
+    let health = HealthStatus::from_miner_data(miner);
+    let cell = button(center(theme::widgets::status_dot(health)))
+        .style(theme::buttons::table_row)
+        .padding(0)
+        .width(CELL_SIZE)
+        .height(CELL_SIZE)
+        .on_press(TopologyMessage::OpenDeviceDetail(miner.ip));
+
+    with_tooltip(
+        cell.into(),
+        format!(
+            "{ip}\n{}\nHealth: {}",
+            miner.device_info.model,
+            health.label()
+        ),
+    )
+}
+
+fn with_tooltip<'a>(
+    content: Element<'a, TopologyMessage>,
+    tip: String,
+) -> Element<'a, TopologyMessage> {
+    let tip: Element<'a, TopologyMessage> = container(theme::typography::small(tip))
+        .style(theme::containers::tooltip_adaptive)
+        .padding(theme::padding::SM)
+        .into();
+
+    tooltip(content, tip, tooltip::Position::Top).into()
+}
+
+/// Splits sorted, disjoint IPv4 spans at /24 boundaries.
+fn bucket_by_subnet(spans: &[(Ipv4Addr, Ipv4Addr)]) -> Subnets {
+    let mut subnets = Subnets::new();
+    for &(start, end) in spans {
+        let (start, end) = (u32::from(start), u32::from(end));
+        for block in (start >> 8)..=(end >> 8) {
+            let first = if block == start >> 8 { start as u8 } else { 0 };
+            let last = if block == end >> 8 {
+                end as u8
+            } else {
+                u8::MAX
+            };
+            let [_, a, b, c] = block.to_be_bytes();
+            subnets.entry([a, b, c]).or_default().push(first..=last);
+        }
+    }
+    subnets
+}
+
+/// The /24 holding the most miners; ties and empty results pick the lowest subnet.
+fn busiest_subnet(subnets: &Subnets, miners: &[MinerData]) -> Option<[u8; 3]> {
+    let mut counts: HashMap<[u8; 3], usize> = HashMap::new();
+    for miner in miners {
+        if let IpAddr::V4(ip) = miner.ip {
+            let [a, b, c, _] = ip.octets();
+            *counts.entry([a, b, c]).or_default() += 1;
+        }
+    }
+
+    subnets
+        .keys()
+        .rev()
+        .max_by_key(|subnet| counts.get(*subnet).copied().unwrap_or(0))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subnets(range: &str) -> Subnets {
+        bucket_by_subnet(&ipv4_spans(range).unwrap())
+    }
+
+    #[test]
+    fn test_bucket_by_subnet() {
+        let subnets = subnets("10.0.0.1-2,10.0.0.9,10.0.1.7,::1");
+
+        assert_eq!(subnets.len(), 2);
+        assert_eq!(subnets[&[10, 0, 0]], vec![1..=2, 9..=9]);
+        assert_eq!(subnets[&[10, 0, 1]], vec![7..=7]);
+    }
+
+    #[test]
+    fn test_bucket_large_range_by_span() {
+        let subnets = subnets("10.0.0.0/8");
+
+        assert_eq!(subnets.len(), 65536);
+        assert_eq!(subnets[&[10, 0, 0]], vec![1..=255]);
+        assert_eq!(subnets[&[10, 128, 7]], vec![0..=255]);
+        assert_eq!(subnets[&[10, 255, 255]], vec![0..=254]);
+    }
+
+    #[test]
+    fn test_busiest_subnet_without_miners_is_lowest() {
+        let subnets = subnets("10.0.2.1,10.0.1.1,10.0.3.1");
+        assert_eq!(busiest_subnet(&subnets, &[]), Some([10, 0, 1]));
+        assert_eq!(busiest_subnet(&Subnets::new(), &[]), None);
+    }
+}
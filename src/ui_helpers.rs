@@ -1,6 +1,11 @@
 use crate::theme;
-use iced::widget::{button, row, text};
+use iced::widget::{button, container, row, text, tooltip};
 use iced::{Element, alignment};
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+/// How long to wait for a miner's web UI port before reporting it unreachable
+const REACHABILITY_TIMEOUT: Duration = Duration::from_secs(2);
 
 pub fn create_button<'a, Message: Clone + 'a>(
     label: &'a str,
@@ -51,6 +56,79 @@ pub fn danger_button<'a, Message: Clone + 'a>(
     create_button(label, icon, iced::widget::button::danger, message)
 }
 
+/// Builds the web UI URL for a miner, bracketing IPv6 addresses as required by RFC 3986.
+pub fn miner_url(ip: IpAddr, port: Option<u16>) -> String {
+    let host = match ip {
+        IpAddr::V4(v4) => v4.to_string(),
+        IpAddr::V6(v6) => format!("[{v6}]"),
+    };
+
+    match port {
+        Some(port) => format!("http://{host}:{port}"),
+        None => format!("http://{host}"),
+    }
+}
+
+/// Opens a miner's web UI in the browser.
+///
+/// The port is checked first so an offline miner is reported as such rather
+/// than leaving a browser tab spinning. Errors are ready for a notification.
+pub async fn open_miner_url(
+    ip: IpAddr,
+    port: Option<u16>,
+    browser_command: Option<String>,
+) -> Result<(), String> {
+    let url = miner_url(ip, port);
+    let address = SocketAddr::new(ip, port.unwrap_or(80));
+
+    match tokio::time::timeout(
+        REACHABILITY_TIMEOUT,
+        tokio::net::TcpStream::connect(address),
+    )
+    .await
+    {
+        Ok(Ok(_)) => open_url(&url, browser_command.as_deref()),
+        Ok(Err(e)) => Err(format!("{url} is not reachable: {e}")),
+        Err(_) => Err(format!(
+            "{url} did not respond within {}s",
+            REACHABILITY_TIMEOUT.as_secs()
+        )),
+    }
+}
+
+/// Placeholder for the URL in a custom browser command template
+const URL_PLACEHOLDER: &str = "%u";
+
+/// Opens a URL with the system default handler, or with `browser_command` when set.
+///
+/// The command is a template such as `wslview %u`; the URL replaces `%u`, or is
+/// appended if the template has no placeholder. It is spawned without a shell.
+pub fn open_url(url: &str, browser_command: Option<&str>) -> Result<(), String> {
+    match browser_command.map(str::trim).filter(|c| !c.is_empty()) {
+        Some(template) => {
+            let args = browser_command_args(template, url);
+            let (program, args) = args.split_first().expect("template is non-empty");
+            std::process::Command::new(program)
+                .args(args)
+                .spawn()
+                .map(drop)
+                .map_err(|e| format!("Failed to run \"{template}\": {e}"))
+        }
+        None => opener::open(url).map_err(|e| format!("Failed to open URL {url}: {e}")),
+    }
+}
+
+fn browser_command_args(template: &str, url: &str) -> Vec<String> {
+    let mut args: Vec<String> = template
+        .split_whitespace()
+        .map(|arg| arg.replace(URL_PLACEHOLDER, url))
+        .collect();
+    if !template.contains(URL_PLACEHOLDER) {
+        args.push(url.to_string());
+    }
+    args
+}
+
 /// Calculates progress as a value between 0.0 and 1.0.
 ///
 /// Returns 0.0 if total is 0, otherwise returns completed/total clamped to [0.0, 1.0].
@@ -71,6 +149,37 @@ pub fn calculate_progress(completed: usize, total: usize) -> f32 {
     (completed as f32 / total as f32).clamp(0.0, 1.0)
 }
 
+/// Shortens `s` to at most `max` characters, ending in an ellipsis when cut.
+pub fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let kept: String = s.chars().take(max.saturating_sub(1)).collect();
+    format!("{}…", kept.trim_end())
+}
+
+/// Text cut to `max` characters, with the full value in a tooltip when it
+/// didn't fit. `style` is a typography function such as `theme::typography::mono`.
+pub fn truncated_text<Message: 'static>(
+    value: &str,
+    max: usize,
+    style: fn(String) -> text::Text<'static>,
+) -> Element<'static, Message> {
+    let shortened = truncate(value, max);
+    if shortened == value {
+        return style(shortened).into();
+    }
+
+    tooltip(
+        style(shortened),
+        container(theme::typography::small(value))
+            .style(theme::containers::tooltip_adaptive)
+            .padding(theme::padding::SM),
+        tooltip::Position::Top,
+    )
+    .into()
+}
+
 /// Formats a duration in seconds to a human-readable string.
 ///
 /// # Examples
@@ -95,3 +204,34 @@ pub fn format_duration(seconds: u64) -> String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("S19", 10), "S19");
+        assert_eq!(truncate("exactly10!", 10), "exactly10!");
+        assert_eq!(truncate("Antminer S19 XP Hydro", 10), "Antminer…");
+        // Counts characters, not bytes
+        assert_eq!(truncate("Größenordnung", 5), "Größ…");
+        assert_eq!(truncate("abc", 0), "…");
+    }
+
+    #[test]
+    fn test_browser_command_args() {
+        assert_eq!(
+            browser_command_args("wslview %u", "http://10.0.0.5"),
+            ["wslview", "http://10.0.0.5"]
+        );
+        assert_eq!(
+            browser_command_args("  firefox --new-tab  ", "http://[fe80::1]"),
+            ["firefox", "--new-tab", "http://[fe80::1]"]
+        );
+        assert_eq!(
+            browser_command_args("open-url --target=%u", "http://h"),
+            ["open-url", "--target=http://h"]
+        );
+    }
+}
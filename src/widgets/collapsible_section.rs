@@ -0,0 +1,69 @@
+use crate::theme;
+use iced::widget::{button, column, row};
+use iced::{Element, Length};
+
+/// A header with a chevron that shows or hides the body below it.
+///
+/// The section doesn't own its open state: the caller passes `open` in and
+/// handles `toggle`, which is sent when the header is clicked.
+pub struct CollapsibleSection<'a, Message> {
+    header: Element<'a, Message>,
+    body: Element<'a, Message>,
+    trailing: Option<Element<'a, Message>>,
+    open: bool,
+    toggle: Message,
+}
+
+impl<'a, Message: Clone + 'a> CollapsibleSection<'a, Message> {
+    // `header` has its own lifetime so 'static text such as headings doesn't
+    // force the whole section to be 'static
+    pub fn new<'h: 'a>(
+        header: impl Into<Element<'h, Message>>,
+        body: impl Into<Element<'a, Message>>,
+        open: bool,
+        toggle: Message,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            body: body.into(),
+            trailing: None,
+            open,
+            toggle,
+        }
+    }
+
+    /// Widgets right of the header that keep their own interactions, such as
+    /// section actions. Clicking them doesn't toggle the section.
+    pub fn trailing(mut self, trailing: impl Into<Element<'a, Message>>) -> Self {
+        self.trailing = Some(trailing.into());
+        self
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<CollapsibleSection<'a, Message>> for Element<'a, Message> {
+    fn from(section: CollapsibleSection<'a, Message>) -> Self {
+        let chevron = if section.open { "▼" } else { "▶" };
+        let header = button(
+            row![theme::typography::body(chevron), section.header]
+                .spacing(theme::spacing::SM)
+                .align_y(iced::Alignment::Center),
+        )
+        .style(button::text)
+        .padding(0)
+        .on_press(section.toggle)
+        .width(Length::Fill);
+
+        let header = row![header]
+            .push(section.trailing)
+            .spacing(theme::spacing::SM)
+            .align_y(iced::Alignment::Center);
+
+        if section.open {
+            column![header, section.body]
+                .spacing(theme::spacing::XS)
+                .into()
+        } else {
+            header.into()
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod collapsible_section;
+pub mod sparkline;
+
+pub use collapsible_section::CollapsibleSection;
+pub use sparkline::Sparkline;
@@ -0,0 +1,104 @@
+use iced::widget::canvas::{self, Canvas, Frame, Geometry, Path, Stroke};
+use iced::{Color, Element, Length, Point, Rectangle, Renderer, Theme, mouse};
+
+/// Size of a sparkline in pixels
+pub const SPARKLINE_WIDTH: f32 = 60.0;
+pub const SPARKLINE_HEIGHT: f32 = 16.0;
+
+/// A tiny line chart of recent values, oldest first, for trends beside a label.
+///
+/// An optional reference value is drawn as a faint horizontal line, so values
+/// on it read as expected and values under it as a shortfall.
+pub struct Sparkline {
+    values: Vec<f32>,
+    reference: Option<f32>,
+    color: Color,
+}
+
+impl Sparkline {
+    pub fn new(values: impl IntoIterator<Item = f32>, color: Color) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+            reference: None,
+            color,
+        }
+    }
+
+    pub fn reference(mut self, value: f32) -> Self {
+        self.reference = Some(value);
+        self
+    }
+
+    /// Vertical position of `value` within `height`, with the lowest and
+    /// highest plotted values at the bottom and top edges. A flat series sits
+    /// in the middle.
+    fn y(&self, value: f32, height: f32) -> f32 {
+        let plotted = self.values.iter().chain(&self.reference);
+        let min = plotted.clone().copied().fold(f32::INFINITY, f32::min);
+        let max = plotted.copied().fold(f32::NEG_INFINITY, f32::max);
+        // Half the stroke width stays clear at each edge
+        let inset = 1.0;
+        if max - min <= f32::EPSILON {
+            return height / 2.0;
+        }
+        let fraction = (value - min) / (max - min);
+        inset + (1.0 - fraction) * (height - 2.0 * inset)
+    }
+}
+
+impl<Message> canvas::Program<Message> for Sparkline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        if let Some(reference) = self.reference {
+            let y = self.y(reference, bounds.height);
+            frame.stroke(
+                &Path::line(Point::new(0.0, y), Point::new(bounds.width, y)),
+                Stroke::default()
+                    .with_color(Color {
+                        a: 0.35,
+                        ..self.color
+                    })
+                    .with_width(1.0),
+            );
+        }
+
+        if self.values.len() > 1 {
+            let step = bounds.width / (self.values.len() - 1) as f32;
+            let line = Path::new(|path| {
+                for (i, &value) in self.values.iter().enumerate() {
+                    let point = Point::new(i as f32 * step, self.y(value, bounds.height));
+                    if i == 0 {
+                        path.move_to(point);
+                    } else {
+                        path.line_to(point);
+                    }
+                }
+            });
+            frame.stroke(
+                &line,
+                Stroke::default().with_color(self.color).with_width(1.5),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+impl<'a, Message: 'a> From<Sparkline> for Element<'a, Message> {
+    fn from(sparkline: Sparkline) -> Self {
+        Canvas::new(sparkline)
+            .width(Length::Fixed(SPARKLINE_WIDTH))
+            .height(Length::Fixed(SPARKLINE_HEIGHT))
+            .into()
+    }
+}